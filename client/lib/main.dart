@@ -3497,7 +3497,7 @@ class _HomeStatusBar extends StatelessWidget {
     final theme = Theme.of(context);
     return Obx(() {
       final strategy = controller.activeStrategy.value;
-      final name = strategy?.id.value ?? 'Unknown';
+      final name = strategy?.id ?? 'Unknown';
       final focusList = strategy?.focus ?? const <int>[];
       final focus = focusList.isEmpty
           ? 'none'
@@ -12,7 +12,10 @@ class StrategySheet extends StatefulWidget {
 }
 
 class _StrategySheetState extends State<StrategySheet> {
-  late robot_farm_api.Strategy _selectedStrategy;
+  static const _defaultStrategy = 'PLANNING';
+
+  String _selectedStrategy = _defaultStrategy;
+  List<String> _strategies = const <String>[_defaultStrategy];
   Set<int> _focusedGroups = <int>{};
   List<robot_farm_api.TaskGroup> _taskGroups =
       const <robot_farm_api.TaskGroup>[];
@@ -24,13 +27,11 @@ class _StrategySheetState extends State<StrategySheet> {
   @override
   void initState() {
     super.initState();
-    _selectedStrategy = robot_farm_api.Strategy.PLANNING;
     _loadInitial();
   }
 
   bool get _requiresFocusSelection =>
-      _selectedStrategy == robot_farm_api.Strategy.MODERATE ||
-      _selectedStrategy == robot_farm_api.Strategy.ECONOMICAL;
+      _selectedStrategy == 'MODERATE' || _selectedStrategy == 'ECONOMICAL';
 
   bool get _canSubmit {
     if (_requiresFocusSelection) {
@@ -65,9 +66,17 @@ class _StrategySheetState extends State<StrategySheet> {
       final active = await _api!.getActiveStrategy();
       final groups =
           await _api!.listTaskGroups() ?? const <robot_farm_api.TaskGroup>[];
+      final definitions = await _api!.listStrategies() ??
+          const <robot_farm_api.StrategyDefinition>[];
+      final selected = active?.id ?? _defaultStrategy;
+      final names = definitions.map((definition) => definition.name).toList();
+      if (!names.contains(selected)) {
+        names.add(selected);
+      }
       setState(() {
         _taskGroups = groups;
-        _selectedStrategy = active?.id ?? robot_farm_api.Strategy.PLANNING;
+        _strategies = names;
+        _selectedStrategy = selected;
         _focusedGroups = active == null
             ? groups.map((group) => group.id).toSet()
             : active.focus.toSet();
@@ -178,14 +187,14 @@ class _StrategySheetState extends State<StrategySheet> {
                   border: OutlineInputBorder(),
                 ),
                 child: DropdownButtonHideUnderline(
-                  child: DropdownButton<robot_farm_api.Strategy>(
+                  child: DropdownButton<String>(
                     value: _selectedStrategy,
                     isExpanded: true,
-                    items: robot_farm_api.Strategy.values
+                    items: _strategies
                         .map(
                           (strategy) => DropdownMenuItem(
                             value: strategy,
-                            child: Text(strategy.value),
+                            child: Text(strategy),
                           ),
                         )
                         .toList(),
@@ -358,7 +358,7 @@ class TasksController extends GetxController {
   }
 
   Future<void> setStrategyForGroup(
-    robot_farm_api.Strategy strategy,
+    String strategy,
     int groupId,
   ) async {
     final api = _apiOrThrow();
@@ -371,7 +371,7 @@ class TasksController extends GetxController {
 
   Future<void> enqueueOrchestratorSeed(
     String groupTitle,
-    robot_farm_api.Strategy strategy,
+    String strategy,
   ) async {
     final api = _apiOrNull(setGroupError: true);
     if (api == null) return;
@@ -380,7 +380,7 @@ class TasksController extends GetxController {
       'from': 'System',
       'to': 'Orchestrator',
       'message':
-          'Strategy set to ${strategy.value}; new task added in group "$groupTitle".',
+          'Strategy set to $strategy; new task added in group "$groupTitle".',
     };
     await client.invokeAPI(
       '/message_queue',
@@ -109,12 +109,8 @@ class TasksScreen extends GetView<TasksController> {
     }
     final groupId = group.id;
 
-    final strategies = const [
-      robot_farm_api.Strategy.AGGRESSIVE,
-      robot_farm_api.Strategy.MODERATE,
-      robot_farm_api.Strategy.ECONOMICAL,
-    ];
-    robot_farm_api.Strategy selected = strategies.first;
+    const strategies = ['AGGRESSIVE', 'MODERATE', 'ECONOMICAL'];
+    var selected = strategies.first;
     var sendSeedMessage = false;
 
     final confirmed = await showDialog<bool>(
@@ -132,12 +128,12 @@ class TasksScreen extends GetView<TasksController> {
                   'Would you like to switch strategy and focus on this group?',
                 ),
                 const SizedBox(height: 12),
-                DropdownButton<robot_farm_api.Strategy>(
+                DropdownButton<String>(
                   value: selected,
                   isExpanded: true,
                   items: strategies
                       .map(
-                        (s) => DropdownMenuItem(value: s, child: Text(s.value)),
+                        (s) => DropdownMenuItem(value: s, child: Text(s)),
                       )
                       .toList(),
                   onChanged: (value) {
@@ -56,7 +56,7 @@ part 'model/message.dart';
 part 'model/message_enqueue_input.dart';
 part 'model/queue_state.dart';
 part 'model/reasoning_effort.dart';
-part 'model/strategy.dart';
+part 'model/strategy_definition.dart';
 part 'model/task.dart';
 part 'model/task_create_input.dart';
 part 'model/task_dependency.dart';
@@ -1797,6 +1797,53 @@ class DefaultApi {
     return null;
   }
 
+  /// List available strategies
+  ///
+  /// Note: This method returns the HTTP [Response].
+  Future<Response> listStrategiesWithHttpInfo() async {
+    // ignore: prefer_const_declarations
+    final path = r'/strategies';
+
+    // ignore: prefer_final_locals
+    Object? postBody;
+
+    final queryParams = <QueryParam>[];
+    final headerParams = <String, String>{};
+    final formParams = <String, String>{};
+
+    const contentTypes = <String>[];
+
+
+    return apiClient.invokeAPI(
+      path,
+      'GET',
+      queryParams,
+      postBody,
+      headerParams,
+      formParams,
+      contentTypes.isEmpty ? null : contentTypes.first,
+    );
+  }
+
+  /// List available strategies
+  Future<List<StrategyDefinition>?> listStrategies() async {
+    final response = await listStrategiesWithHttpInfo();
+    if (response.statusCode >= HttpStatus.badRequest) {
+      throw ApiException(response.statusCode, await _decodeBodyBytes(response));
+    }
+    // When a remote server returns no body with a status of 204, we shall not decode it.
+    // At the time of writing this, `dart:convert` will throw an "Unexpected end of input"
+    // FormatException when trying to decode an empty string.
+    if (response.body.isNotEmpty && response.statusCode != HttpStatus.noContent) {
+      final responseBody = await _decodeBodyBytes(response);
+      return (await apiClient.deserializeAsync(responseBody, 'List<StrategyDefinition>') as List)
+        .cast<StrategyDefinition>()
+        .toList(growable: false);
+
+    }
+    return null;
+  }
+
   /// List task groups
   ///
   /// Note: This method returns the HTTP [Response].
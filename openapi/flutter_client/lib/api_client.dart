@@ -234,8 +234,8 @@ class ApiClient {
           return QueueState.fromJson(value);
         case 'ReasoningEffort':
           return ReasoningEffortTypeTransformer().decode(value);
-        case 'Strategy':
-          return StrategyTypeTransformer().decode(value);
+        case 'StrategyDefinition':
+          return StrategyDefinition.fromJson(value);
         case 'Task':
           return Task.fromJson(value);
         case 'TaskCreateInput':
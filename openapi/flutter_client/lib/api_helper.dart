@@ -64,9 +64,6 @@ String parameterToString(dynamic value) {
   if (value is ReasoningEffort) {
     return ReasoningEffortTypeTransformer().encode(value).toString();
   }
-  if (value is Strategy) {
-    return StrategyTypeTransformer().encode(value).toString();
-  }
   if (value is TaskGroupStatus) {
     return TaskGroupStatusTypeTransformer().encode(value).toString();
   }
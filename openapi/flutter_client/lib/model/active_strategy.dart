@@ -17,7 +17,8 @@ class ActiveStrategy {
     this.focus = const [],
   });
 
-  Strategy id;
+  /// Strategy identifier: a built-in preset (AGGRESSIVE, MODERATE, ECONOMICAL, BUG_SMASH, HOTFIX_SWARM, MAINTENANCE, PLANNING, WIND_DOWN) or a name defined under `strategies` in the workspace config.
+  String id;
 
   /// Optional list of task group identifiers receiving additional focus.
   List<int> focus;
@@ -62,7 +63,7 @@ class ActiveStrategy {
       }());
 
       return ActiveStrategy(
-        id: Strategy.fromJson(json[r'id'])!,
+        id: mapValueOfType<String>(json, r'id')!,
         focus: json[r'focus'] is Iterable
             ? (json[r'focus'] as Iterable).cast<int>().toList(growable: false)
             : const [],
@@ -0,0 +1,187 @@
+//
+// AUTO-GENERATED FILE, DO NOT MODIFY!
+//
+// @dart=2.18
+
+// ignore_for_file: unused_element, unused_import
+// ignore_for_file: always_put_required_named_parameters_first
+// ignore_for_file: constant_identifier_names
+// ignore_for_file: lines_longer_than_80_chars
+
+part of openapi.api;
+
+class StrategyDefinition {
+  /// Returns a new [StrategyDefinition] instance.
+  StrategyDefinition({
+    required this.name,
+    required this.hint,
+    this.groups = const [],
+    this.busyWorkers,
+    this.allowAssignments = true,
+    this.sequentialGroups = false,
+    this.scheduler = false,
+    this.taskEditing = false,
+    this.tools = const [],
+  });
+
+  /// Strategy identifier: a built-in preset (AGGRESSIVE, MODERATE, ECONOMICAL, BUG_SMASH, HOTFIX_SWARM, MAINTENANCE, PLANNING, WIND_DOWN) or a name defined under `strategies` in the workspace config.
+  String name;
+
+  /// Guidance appended to orchestrator prompts. `{focus}` expands to the focus groups and `{busy_workers}` to the worker target.
+  String hint;
+
+  /// Task group slugs eligible for assignment once the focus groups are finished (or when no focus is set).
+  List<String> groups;
+
+  /// Number of workers to keep busy. With `scheduler` on this is the scheduler's budget; otherwise it caps how many idle workers the orchestrator is hinted to assign. Omit to use every idle worker.
+  int? busyWorkers;
+
+  /// Whether new tasks may be assigned to workers.
+  bool allowAssignments;
+
+  /// Finish one eligible group before sourcing tasks from the next.
+  bool sequentialGroups;
+
+  /// Let the deterministic scheduler assign tasks directly when it is enabled.
+  bool scheduler;
+
+  /// Allow the orchestrator to create and edit tasks, groups and proposals.
+  bool taskEditing;
+
+  /// MCP tools the orchestrator may use. Omit to allow every tool.
+  List<String>? tools;
+
+  @override
+  bool operator ==(Object other) => identical(this, other) || other is StrategyDefinition &&
+    other.name == name &&
+    other.hint == hint &&
+    _deepEquality.equals(other.groups, groups) &&
+    other.busyWorkers == busyWorkers &&
+    other.allowAssignments == allowAssignments &&
+    other.sequentialGroups == sequentialGroups &&
+    other.scheduler == scheduler &&
+    other.taskEditing == taskEditing &&
+    _deepEquality.equals(other.tools, tools);
+
+  @override
+  int get hashCode =>
+    // ignore: unnecessary_parenthesis
+    (name.hashCode) +
+    (hint.hashCode) +
+    (groups.hashCode) +
+    (busyWorkers == null ? 0 : busyWorkers!.hashCode) +
+    (allowAssignments.hashCode) +
+    (sequentialGroups.hashCode) +
+    (scheduler.hashCode) +
+    (taskEditing.hashCode) +
+    (tools == null ? 0 : tools!.hashCode);
+
+  @override
+  String toString() => 'StrategyDefinition[name=$name, hint=$hint, groups=$groups, busyWorkers=$busyWorkers, allowAssignments=$allowAssignments, sequentialGroups=$sequentialGroups, scheduler=$scheduler, taskEditing=$taskEditing, tools=$tools]';
+
+  Map<String, dynamic> toJson() {
+    final json = <String, dynamic>{};
+      json[r'name'] = this.name;
+      json[r'hint'] = this.hint;
+      json[r'groups'] = this.groups;
+    if (this.busyWorkers != null) {
+      json[r'busy_workers'] = this.busyWorkers;
+    } else {
+      json[r'busy_workers'] = null;
+    }
+      json[r'allow_assignments'] = this.allowAssignments;
+      json[r'sequential_groups'] = this.sequentialGroups;
+      json[r'scheduler'] = this.scheduler;
+      json[r'task_editing'] = this.taskEditing;
+    if (this.tools != null) {
+      json[r'tools'] = this.tools;
+    } else {
+      json[r'tools'] = null;
+    }
+    return json;
+  }
+
+  /// Returns a new [StrategyDefinition] instance and imports its values from
+  /// [value] if it's a [Map], null otherwise.
+  // ignore: prefer_constructors_over_static_methods
+  static StrategyDefinition? fromJson(dynamic value) {
+    if (value is Map) {
+      final json = value.cast<String, dynamic>();
+
+      // Ensure that the map contains the required keys.
+      // Note 1: the values aren't checked for validity beyond being non-null.
+      // Note 2: this code is stripped in release mode!
+      assert(() {
+        requiredKeys.forEach((key) {
+          assert(json.containsKey(key), 'Required key "StrategyDefinition[$key]" is missing from JSON.');
+          assert(json[key] != null, 'Required key "StrategyDefinition[$key]" has a null value in JSON.');
+        });
+        return true;
+      }());
+
+      return StrategyDefinition(
+        name: mapValueOfType<String>(json, r'name')!,
+        hint: mapValueOfType<String>(json, r'hint')!,
+        groups: json[r'groups'] is Iterable
+            ? (json[r'groups'] as Iterable).cast<String>().toList(growable: false)
+            : const [],
+        busyWorkers: mapValueOfType<int>(json, r'busy_workers'),
+        allowAssignments: mapValueOfType<bool>(json, r'allow_assignments') ?? true,
+        sequentialGroups: mapValueOfType<bool>(json, r'sequential_groups') ?? false,
+        scheduler: mapValueOfType<bool>(json, r'scheduler') ?? false,
+        taskEditing: mapValueOfType<bool>(json, r'task_editing') ?? false,
+        tools: json[r'tools'] is Iterable
+            ? (json[r'tools'] as Iterable).cast<String>().toList(growable: false)
+            : const [],
+      );
+    }
+    return null;
+  }
+
+  static List<StrategyDefinition> listFromJson(dynamic json, {bool growable = false,}) {
+    final result = <StrategyDefinition>[];
+    if (json is List && json.isNotEmpty) {
+      for (final row in json) {
+        final value = StrategyDefinition.fromJson(row);
+        if (value != null) {
+          result.add(value);
+        }
+      }
+    }
+    return result.toList(growable: growable);
+  }
+
+  static Map<String, StrategyDefinition> mapFromJson(dynamic json) {
+    final map = <String, StrategyDefinition>{};
+    if (json is Map && json.isNotEmpty) {
+      json = json.cast<String, dynamic>(); // ignore: parameter_assignments
+      for (final entry in json.entries) {
+        final value = StrategyDefinition.fromJson(entry.value);
+        if (value != null) {
+          map[entry.key] = value;
+        }
+      }
+    }
+    return map;
+  }
+
+  // maps a json object with a list of StrategyDefinition-objects as value to a dart map
+  static Map<String, List<StrategyDefinition>> mapListFromJson(dynamic json, {bool growable = false,}) {
+    final map = <String, List<StrategyDefinition>>{};
+    if (json is Map && json.isNotEmpty) {
+      // ignore: parameter_assignments
+      json = json.cast<String, dynamic>();
+      for (final entry in json.entries) {
+        map[entry.key] = StrategyDefinition.listFromJson(entry.value, growable: growable,);
+      }
+    }
+    return map;
+  }
+
+  /// The list of required keys that must be present in a JSON.
+  static const requiredKeys = <String>{
+    'name',
+    'hint',
+  };
+}
+
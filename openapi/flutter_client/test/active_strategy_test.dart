@@ -16,7 +16,8 @@ void main() {
   // final instance = ActiveStrategy();
 
   group('test ActiveStrategy', () {
-    // Strategy id
+    // Strategy identifier: a built-in preset (AGGRESSIVE, MODERATE, ECONOMICAL, BUG_SMASH, HOTFIX_SWARM, MAINTENANCE, PLANNING, WIND_DOWN) or a name defined under `strategies` in the workspace config.
+    // String id
     test('to test the property `id`', () async {
       // TODO
     });
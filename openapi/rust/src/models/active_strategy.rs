@@ -14,8 +14,9 @@ use serde::{Deserialize, Serialize};
 /// ActiveStrategy : Represents the globally active strategy and optional focus task groups.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ActiveStrategy {
+    /// Strategy identifier: a built-in preset (AGGRESSIVE, MODERATE, ECONOMICAL, BUG_SMASH, HOTFIX_SWARM, MAINTENANCE, PLANNING, WIND_DOWN) or a name defined under `strategies` in the workspace config.
     #[serde(rename = "id")]
-    pub id: models::Strategy,
+    pub id: String,
     /// Optional list of task group identifiers receiving additional focus.
     #[serde(rename = "focus", skip_serializing_if = "Option::is_none")]
     pub focus: Option<Vec<i64>>,
@@ -23,7 +24,7 @@ pub struct ActiveStrategy {
 
 impl ActiveStrategy {
     /// Represents the globally active strategy and optional focus task groups.
-    pub fn new(id: models::Strategy) -> ActiveStrategy {
+    pub fn new(id: String) -> ActiveStrategy {
         ActiveStrategy { id, focus: None }
     }
 }
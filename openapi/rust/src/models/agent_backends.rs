@@ -0,0 +1,33 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AgentBackends : Agent backend that runs each persona's turns. Omitted personas use `codex`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AgentBackends {
+    /// Backend name for the orchestrator (e.g. `codex`). The task wizard uses the same backend.
+    #[serde(rename = "orchestrator", skip_serializing_if = "Option::is_none")]
+    pub orchestrator: Option<String>,
+    /// Backend name for workers (e.g. `codex`).
+    #[serde(rename = "worker", skip_serializing_if = "Option::is_none")]
+    pub worker: Option<String>,
+}
+
+impl AgentBackends {
+    /// Agent backend that runs each persona's turns. Omitted personas use `codex`.
+    pub fn new() -> AgentBackends {
+        AgentBackends {
+            orchestrator: None,
+            worker: None,
+        }
+    }
+}
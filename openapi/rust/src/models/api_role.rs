@@ -0,0 +1,40 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApiRole : Access level of an API token. `viewer` reads feed, tasks and status; `operator` also drives the queue, strategy, messages and tasks; `admin` also edits config, runs exec commands and manages worker lifecycle.
+/// Access level of an API token. `viewer` reads feed, tasks and status; `operator` also drives the queue, strategy, messages and tasks; `admin` also edits config, runs exec commands and manages worker lifecycle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ApiRole {
+    #[serde(rename = "viewer")]
+    Viewer,
+    #[serde(rename = "operator")]
+    Operator,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl std::fmt::Display for ApiRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Viewer => write!(f, "viewer"),
+            Self::Operator => write!(f, "operator"),
+            Self::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl Default for ApiRole {
+    fn default() -> ApiRole {
+        Self::Viewer
+    }
+}
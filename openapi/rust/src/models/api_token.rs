@@ -0,0 +1,32 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApiToken : Bearer token accepted by the REST API and WebSockets.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiToken {
+    /// Label identifying who holds the token.
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "role")]
+    pub role: models::ApiRole,
+    /// Secret presented as `Authorization: Bearer <token>` (or `?token=` on WebSocket upgrades).
+    #[serde(rename = "token")]
+    pub token: String,
+}
+
+impl ApiToken {
+    /// Bearer token accepted by the REST API and WebSockets.
+    pub fn new(name: String, role: models::ApiRole, token: String) -> ApiToken {
+        ApiToken { name, role, token }
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AuditEntry : A state-changing operator action recorded by the API.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Name of the API token that made the request (`anonymous` while auth is disabled).
+    #[serde(rename = "actor")]
+    pub actor: String,
+    #[serde(rename = "role")]
+    pub role: models::ApiRole,
+    /// Dotted action name, e.g. `strategy.update` or `exec.run`.
+    #[serde(rename = "action")]
+    pub action: String,
+    /// Action parameters as sent by the caller. Secrets are never recorded.
+    #[serde(rename = "params")]
+    pub params: serde_json::Value,
+    /// Unix timestamp (seconds).
+    #[serde(rename = "created_at")]
+    pub created_at: i64,
+}
+
+impl AuditEntry {
+    /// A state-changing operator action recorded by the API.
+    pub fn new(
+        id: i64,
+        actor: String,
+        role: models::ApiRole,
+        action: String,
+        params: serde_json::Value,
+        created_at: i64,
+    ) -> AuditEntry {
+        AuditEntry {
+            id,
+            actor,
+            role,
+            action,
+            params,
+            created_at,
+        }
+    }
+}
@@ -30,6 +30,34 @@ pub struct Config {
     pub post_turn_checks: Vec<String>,
     #[serde(rename = "docker_overrides")]
     pub docker_overrides: Box<models::DockerOverrides>,
+    #[serde(rename = "container_limits", skip_serializing_if = "Option::is_none")]
+    pub container_limits: Option<Box<models::ContainerLimitsConfig>>,
+    /// Named worker profiles; each `wsN` may be assigned to at most one.
+    #[serde(rename = "worker_profiles", skip_serializing_if = "Option::is_none")]
+    pub worker_profiles: Option<Vec<models::WorkerProfile>>,
+    /// What happens when a task is assigned to a worker lacking one of its required skills.
+    #[serde(rename = "skill_mismatch", skip_serializing_if = "Option::is_none")]
+    pub skill_mismatch: Option<SkillMismatch>,
+    /// How agent turns run: in Docker, in rootless Podman, or as plain host processes inside the worktree (trusted setups only). `host` rejects non-empty `docker_overrides` and container limits, which it cannot apply.
+    #[serde(rename = "container_runtime", skip_serializing_if = "Option::is_none")]
+    pub container_runtime: Option<ContainerRuntime>,
+    /// Tokens accepted by the API. When empty, every request is served read-only as an anonymous viewer.
+    #[serde(rename = "api_tokens", skip_serializing_if = "Option::is_none")]
+    pub api_tokens: Option<Vec<models::ApiToken>>,
+    /// Origins allowed to make cross-origin requests (e.g. `http://localhost:5173`). When empty, any origin is allowed.
+    #[serde(rename = "cors_origins", skip_serializing_if = "Option::is_none")]
+    pub cors_origins: Option<Vec<String>>,
+    #[serde(rename = "exec", skip_serializing_if = "Option::is_none")]
+    pub exec: Option<Box<models::ExecSettings>>,
+    #[serde(rename = "feed_retention", skip_serializing_if = "Option::is_none")]
+    pub feed_retention: Option<Box<models::FeedRetention>>,
+    #[serde(
+        rename = "effective_docker_args",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effective_docker_args: Option<Box<models::EffectiveDockerArgs>>,
+    #[serde(rename = "agent_backends", skip_serializing_if = "Option::is_none")]
+    pub agent_backends: Option<Box<models::AgentBackends>>,
     /// Action to take when staging worktree is dirty during task completion.
     #[serde(
         rename = "dirty_staging_action",
@@ -48,6 +76,23 @@ pub struct Config {
     /// (experimental) Attach worker reasoning traces to messages sent to the orchestrator.
     #[serde(rename = "drift_manager", skip_serializing_if = "Option::is_none")]
     pub drift_manager: Option<bool>,
+    /// Assign ready tasks directly under ECONOMICAL and MODERATE strategies, handing off to the orchestrator only for blocked reports, status updates and ambiguous cases.
+    #[serde(
+        rename = "deterministic_scheduler",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deterministic_scheduler: Option<bool>,
+    /// Batch pending messages for the same idle recipient into a single run.
+    #[serde(rename = "coalesce_messages", skip_serializing_if = "Option::is_none")]
+    pub coalesce_messages: Option<bool>,
+    #[serde(rename = "review_mode", skip_serializing_if = "Option::is_none")]
+    pub review_mode: Option<Box<models::ReviewMode>>,
+    /// User-defined strategies. Entries named after a built-in preset replace it.
+    #[serde(rename = "strategies", skip_serializing_if = "Option::is_none")]
+    pub strategies: Option<Vec<models::StrategyDefinition>>,
+    /// Time-based strategy and queue rules.
+    #[serde(rename = "schedule", skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Vec<models::ScheduleRule>>,
 }
 
 impl Config {
@@ -68,14 +113,59 @@ impl Config {
             commands,
             post_turn_checks,
             docker_overrides: Box::new(docker_overrides),
+            container_limits: None,
+            worker_profiles: None,
+            skill_mismatch: None,
+            container_runtime: None,
+            api_tokens: None,
+            cors_origins: None,
+            exec: None,
+            feed_retention: None,
+            effective_docker_args: None,
+            agent_backends: None,
             dirty_staging_action: None,
             on_staging_change: None,
             persistent_threads: None,
             ghost_commits: None,
             drift_manager: None,
+            deterministic_scheduler: None,
+            coalesce_messages: None,
+            review_mode: None,
+            strategies: None,
+            schedule: None,
         }
     }
 }
+/// What happens when a task is assigned to a worker lacking one of its required skills.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum SkillMismatch {
+    #[serde(rename = "warn")]
+    Warn,
+    #[serde(rename = "reject")]
+    Reject,
+}
+
+impl Default for SkillMismatch {
+    fn default() -> SkillMismatch {
+        Self::Warn
+    }
+}
+/// How agent turns run: in Docker, in rootless Podman, or as plain host processes inside the worktree (trusted setups only). `host` rejects non-empty `docker_overrides` and container limits, which it cannot apply.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ContainerRuntime {
+    #[serde(rename = "docker")]
+    Docker,
+    #[serde(rename = "podman")]
+    Podman,
+    #[serde(rename = "host")]
+    Host,
+}
+
+impl Default for ContainerRuntime {
+    fn default() -> ContainerRuntime {
+        Self::Docker
+    }
+}
 /// Action to take when staging worktree is dirty during task completion.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum DirtyStagingAction {
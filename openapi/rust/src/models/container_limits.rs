@@ -0,0 +1,53 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ContainerLimits : Typed resource and network limits applied to an agent container.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerLimits {
+    /// CPU count (`--cpus`), e.g. `1.5`.
+    #[serde(rename = "cpus", skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<f64>,
+    /// Memory limit (`--memory`), e.g. `2g` or `512m`.
+    #[serde(rename = "memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    /// Maximum number of processes (`--pids-limit`).
+    #[serde(rename = "pids_limit", skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<i64>,
+    /// Network mode (`--network`): `none`, `bridge` or the name of a custom network.
+    #[serde(rename = "network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// Mount the container's root filesystem read-only (`--read-only`).
+    #[serde(rename = "read_only", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Absolute container paths mounted as tmpfs, optionally followed by `:options` (`--tmpfs`).
+    #[serde(rename = "tmpfs", skip_serializing_if = "Option::is_none")]
+    pub tmpfs: Option<Vec<String>>,
+    /// Extra `/etc/hosts` entries as `name:ip` (`--add-host`).
+    #[serde(rename = "extra_hosts", skip_serializing_if = "Option::is_none")]
+    pub extra_hosts: Option<Vec<String>>,
+}
+
+impl ContainerLimits {
+    /// Typed resource and network limits applied to an agent container.
+    pub fn new() -> ContainerLimits {
+        ContainerLimits {
+            cpus: None,
+            memory: None,
+            pids_limit: None,
+            network: None,
+            read_only: None,
+            tmpfs: None,
+            extra_hosts: None,
+        }
+    }
+}
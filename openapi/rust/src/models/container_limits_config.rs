@@ -0,0 +1,37 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ContainerLimitsConfig : Container limits per persona. Entries under `workers` are keyed by worktree name (e.g. `ws3`) and replace individual fields of `worker`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerLimitsConfig {
+    #[serde(rename = "orchestrator", skip_serializing_if = "Option::is_none")]
+    pub orchestrator: Option<Box<models::ContainerLimits>>,
+    #[serde(rename = "worker", skip_serializing_if = "Option::is_none")]
+    pub worker: Option<Box<models::ContainerLimits>>,
+    #[serde(rename = "wizard", skip_serializing_if = "Option::is_none")]
+    pub wizard: Option<Box<models::ContainerLimits>>,
+    #[serde(rename = "workers", skip_serializing_if = "Option::is_none")]
+    pub workers: Option<std::collections::HashMap<String, models::ContainerLimits>>,
+}
+
+impl ContainerLimitsConfig {
+    /// Container limits per persona. Entries under `workers` are keyed by worktree name (e.g. `ws3`) and replace individual fields of `worker`.
+    pub fn new() -> ContainerLimitsConfig {
+        ContainerLimitsConfig {
+            orchestrator: None,
+            worker: None,
+            wizard: None,
+            workers: None,
+        }
+    }
+}
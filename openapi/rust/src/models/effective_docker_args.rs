@@ -0,0 +1,42 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// EffectiveDockerArgs : Container argv each persona is launched with, up to and including the image. Omitted under the host runtime.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EffectiveDockerArgs {
+    #[serde(rename = "orchestrator")]
+    pub orchestrator: Vec<String>,
+    #[serde(rename = "worker")]
+    pub worker: Vec<String>,
+    #[serde(rename = "wizard")]
+    pub wizard: Vec<String>,
+    /// Argv for every worker worktree, plus workers named in `container_limits.workers` or a worker profile, keyed by worktree name.
+    #[serde(rename = "workers", skip_serializing_if = "Option::is_none")]
+    pub workers: Option<std::collections::HashMap<String, Vec<String>>>,
+}
+
+impl EffectiveDockerArgs {
+    /// Container argv each persona is launched with, up to and including the image. Omitted under the host runtime.
+    pub fn new(
+        orchestrator: Vec<String>,
+        worker: Vec<String>,
+        wizard: Vec<String>,
+    ) -> EffectiveDockerArgs {
+        EffectiveDockerArgs {
+            orchestrator,
+            worker,
+            wizard,
+            workers: None,
+        }
+    }
+}
@@ -16,14 +16,21 @@ use serde::{Deserialize, Serialize};
 pub struct ExecCommandInput {
     #[serde(rename = "command")]
     pub command: String,
-    /// Optional working directory override relative to the workspace root.
+    /// Optional working directory override relative to the worktree.
     #[serde(rename = "cwd", skip_serializing_if = "Option::is_none")]
     pub cwd: Option<String>,
+    /// Seconds before the command is killed. Capped at `exec.timeout_secs`.
+    #[serde(rename = "timeout_secs", skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<i32>,
 }
 
 impl ExecCommandInput {
     /// Request payload for executing a shell command.
     pub fn new(command: String) -> ExecCommandInput {
-        ExecCommandInput { command, cwd: None }
+        ExecCommandInput {
+            command,
+            cwd: None,
+            timeout_secs: None,
+        }
     }
 }
@@ -21,6 +21,12 @@ pub struct ExecResult {
     pub stdout: String,
     #[serde(rename = "stderr")]
     pub stderr: String,
+    /// The command was killed after its timeout.
+    #[serde(rename = "timed_out", skip_serializing_if = "Option::is_none")]
+    pub timed_out: Option<bool>,
+    /// Output beyond `exec.max_output_bytes` was dropped.
+    #[serde(rename = "truncated", skip_serializing_if = "Option::is_none")]
+    pub truncated: Option<bool>,
 }
 
 impl ExecResult {
@@ -30,6 +36,8 @@ impl ExecResult {
             exit_code,
             stdout,
             stderr,
+            timed_out: None,
+            truncated: None,
         }
     }
 }
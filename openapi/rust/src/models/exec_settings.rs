@@ -0,0 +1,51 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ExecSettings : How `/workers/{workerId}/exec` and `/orchestrator/exec` run commands.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecSettings {
+    /// `sandbox` runs each command in an ephemeral container of the worker (or orchestrator) image with only that worktree mounted. `host` runs `bash -lc` directly on the server host.
+    #[serde(rename = "mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+    /// Longest a command may run.
+    #[serde(rename = "timeout_secs", skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<i32>,
+    /// Cap on captured stdout and stderr, each.
+    #[serde(rename = "max_output_bytes", skip_serializing_if = "Option::is_none")]
+    pub max_output_bytes: Option<i32>,
+}
+
+impl ExecSettings {
+    /// How `/workers/{workerId}/exec` and `/orchestrator/exec` run commands.
+    pub fn new() -> ExecSettings {
+        ExecSettings {
+            mode: None,
+            timeout_secs: None,
+            max_output_bytes: None,
+        }
+    }
+}
+/// `sandbox` runs each command in an ephemeral container of the worker (or orchestrator) image with only that worktree mounted. `host` runs `bash -lc` directly on the server host.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Mode {
+    #[serde(rename = "sandbox")]
+    Sandbox,
+    #[serde(rename = "host")]
+    Host,
+}
+
+impl Default for Mode {
+    fn default() -> Mode {
+        Self::Sandbox
+    }
+}
@@ -36,6 +36,23 @@ pub struct Feed {
     /// Category tag for the entry.
     #[serde(rename = "category")]
     pub category: String,
+    /// Agent run that produced the entry.
+    #[serde(rename = "run_id", skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+    /// Task the worker was assigned when the entry was recorded.
+    #[serde(rename = "task_slug", skip_serializing_if = "Option::is_none")]
+    pub task_slug: Option<String>,
+    /// Zero-based count of earlier runs by the same source on the same task (or without a task).
+    #[serde(rename = "turn_index", skip_serializing_if = "Option::is_none")]
+    pub turn_index: Option<i64>,
+    #[serde(rename = "item_kind", skip_serializing_if = "Option::is_none")]
+    pub item_kind: Option<models::FeedItemKind>,
+    /// Exit code of a `command` entry.
+    #[serde(rename = "exit_code", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Paths touched by a `file_change` entry.
+    #[serde(rename = "changed_files", skip_serializing_if = "Option::is_none")]
+    pub changed_files: Option<Vec<String>>,
 }
 
 impl Feed {
@@ -59,6 +76,12 @@ impl Feed {
             text,
             raw,
             category,
+            run_id: None,
+            task_slug: None,
+            turn_index: None,
+            item_kind: None,
+            exit_code: None,
+            changed_files: None,
         }
     }
 }
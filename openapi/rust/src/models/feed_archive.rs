@@ -0,0 +1,35 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// FeedArchive : A gzipped JSONL file of expired feed entries under `.robot-farm-rs/feed-archive/`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeedArchive {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "size_bytes")]
+    pub size_bytes: i64,
+    /// Unix timestamp (seconds).
+    #[serde(rename = "created_at")]
+    pub created_at: i64,
+}
+
+impl FeedArchive {
+    /// A gzipped JSONL file of expired feed entries under `.robot-farm-rs/feed-archive/`.
+    pub fn new(name: String, size_bytes: i64, created_at: i64) -> FeedArchive {
+        FeedArchive {
+            name,
+            size_bytes,
+            created_at,
+        }
+    }
+}
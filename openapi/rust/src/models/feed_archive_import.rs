@@ -0,0 +1,27 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// FeedArchiveImport : Outcome of importing an archive back into the feed.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeedArchiveImport {
+    /// Entries restored; entries already present are skipped.
+    #[serde(rename = "imported")]
+    pub imported: i64,
+}
+
+impl FeedArchiveImport {
+    /// Outcome of importing an archive back into the feed.
+    pub fn new(imported: i64) -> FeedArchiveImport {
+        FeedArchiveImport { imported }
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// FeedItemKind : What an agent-output feed entry records: one item of an agent turn, or the turn's structured result.
+/// What an agent-output feed entry records: one item of an agent turn, or the turn's structured result.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum FeedItemKind {
+    #[serde(rename = "session")]
+    Session,
+    #[serde(rename = "message")]
+    Message,
+    #[serde(rename = "reasoning")]
+    Reasoning,
+    #[serde(rename = "command")]
+    Command,
+    #[serde(rename = "file_change")]
+    FileChange,
+    #[serde(rename = "mcp_tool_call")]
+    McpToolCall,
+    #[serde(rename = "web_search")]
+    WebSearch,
+    #[serde(rename = "todo_list")]
+    TodoList,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "turn")]
+    Turn,
+}
+
+impl std::fmt::Display for FeedItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Session => write!(f, "session"),
+            Self::Message => write!(f, "message"),
+            Self::Reasoning => write!(f, "reasoning"),
+            Self::Command => write!(f, "command"),
+            Self::FileChange => write!(f, "file_change"),
+            Self::McpToolCall => write!(f, "mcp_tool_call"),
+            Self::WebSearch => write!(f, "web_search"),
+            Self::TodoList => write!(f, "todo_list"),
+            Self::Error => write!(f, "error"),
+            Self::Turn => write!(f, "turn"),
+        }
+    }
+}
+
+impl Default for FeedItemKind {
+    fn default() -> FeedItemKind {
+        Self::Session
+    }
+}
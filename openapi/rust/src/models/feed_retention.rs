@@ -0,0 +1,48 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// FeedRetention : When feed rows expire into compressed archives, and when large raw payloads leave SQLite.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeedRetention {
+    /// Archive entries older than this many days. Unset keeps entries regardless of age.
+    #[serde(rename = "max_age_days", skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<i32>,
+    /// Archive the oldest entries beyond this many rows.
+    #[serde(rename = "max_rows", skip_serializing_if = "Option::is_none")]
+    pub max_rows: Option<i64>,
+    /// Per-category age limits that replace `max_age_days` for those categories.
+    #[serde(rename = "categories", skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<models::FeedRetentionRule>>,
+    /// Agent output whose raw JSONL line exceeds this many bytes is stored in a per-run blob file instead of the database. Unset keeps every payload inline.
+    #[serde(rename = "raw_offload_bytes", skip_serializing_if = "Option::is_none")]
+    pub raw_offload_bytes: Option<i32>,
+    /// Delete entries restored from an archive this many days after they were imported; the archive file still holds them. Defaults to 7.
+    #[serde(
+        rename = "imported_max_age_days",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub imported_max_age_days: Option<i32>,
+}
+
+impl FeedRetention {
+    /// When feed rows expire into compressed archives, and when large raw payloads leave SQLite.
+    pub fn new() -> FeedRetention {
+        FeedRetention {
+            max_age_days: None,
+            max_rows: None,
+            categories: None,
+            raw_offload_bytes: None,
+            imported_max_age_days: None,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// FeedRetentionResult : Outcome of a retention sweep.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeedRetentionResult {
+    /// Entries moved out of the database.
+    #[serde(rename = "archived")]
+    pub archived: i64,
+    /// Archive files written by this sweep.
+    #[serde(rename = "archives")]
+    pub archives: Vec<String>,
+    /// Restored archive entries deleted because they outlived `imported_max_age_days`.
+    #[serde(rename = "imports_removed")]
+    pub imports_removed: i64,
+}
+
+impl FeedRetentionResult {
+    /// Outcome of a retention sweep.
+    pub fn new(archived: i64, archives: Vec<String>, imports_removed: i64) -> FeedRetentionResult {
+        FeedRetentionResult {
+            archived,
+            archives,
+            imports_removed,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// FeedRetentionRule : Age limit for one feed category.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeedRetentionRule {
+    #[serde(rename = "category")]
+    pub category: String,
+    #[serde(rename = "max_age_days")]
+    pub max_age_days: i32,
+}
+
+impl FeedRetentionRule {
+    /// Age limit for one feed category.
+    pub fn new(category: String, max_age_days: i32) -> FeedRetentionRule {
+        FeedRetentionRule {
+            category,
+            max_age_days,
+        }
+    }
+}
@@ -27,6 +27,20 @@ pub struct Message {
     /// Unix timestamp (seconds) when the message was queued.
     #[serde(rename = "inserted_at")]
     pub inserted_at: i64,
+    /// Delivery priority; higher values are dispatched first.
+    #[serde(rename = "priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// Unix timestamp (seconds) before which the message is held.
+    #[serde(rename = "deliver_after", skip_serializing_if = "Option::is_none")]
+    pub deliver_after: Option<i64>,
+    /// Unix timestamp (seconds) after which the message is dropped undelivered.
+    #[serde(rename = "expires_at", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+    #[serde(rename = "review_status", skip_serializing_if = "Option::is_none")]
+    pub review_status: Option<models::MessageReviewStatus>,
+    /// Why the message is held for review.
+    #[serde(rename = "hold_reason", skip_serializing_if = "Option::is_none")]
+    pub hold_reason: Option<String>,
 }
 
 impl Message {
@@ -38,6 +52,11 @@ impl Message {
             to,
             message,
             inserted_at,
+            priority: None,
+            deliver_after: None,
+            expires_at: None,
+            review_status: None,
+            hold_reason: None,
         }
     }
 }
@@ -0,0 +1,27 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MessageApproveInput : Release a held message, optionally replacing its body.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageApproveInput {
+    /// Edited body to deliver instead of the original.
+    #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl MessageApproveInput {
+    /// Release a held message, optionally replacing its body.
+    pub fn new() -> MessageApproveInput {
+        MessageApproveInput { message: None }
+    }
+}
@@ -21,10 +21,26 @@ pub struct MessageEnqueueInput {
     pub to: String,
     #[serde(rename = "message")]
     pub message: String,
+    /// Delivery priority; higher values are dispatched first. Defaults to 0.
+    #[serde(rename = "priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// Unix timestamp (seconds) to hold the message until.
+    #[serde(rename = "deliver_after", skip_serializing_if = "Option::is_none")]
+    pub deliver_after: Option<i64>,
+    /// Drop the message if it is still queued this many seconds after it becomes deliverable.
+    #[serde(rename = "ttl_seconds", skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<i64>,
 }
 
 impl MessageEnqueueInput {
     pub fn new(from: String, to: String, message: String) -> MessageEnqueueInput {
-        MessageEnqueueInput { from, to, message }
+        MessageEnqueueInput {
+            from,
+            to,
+            message,
+            priority: None,
+            deliver_after: None,
+            ttl_seconds: None,
+        }
     }
 }
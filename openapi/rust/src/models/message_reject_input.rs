@@ -0,0 +1,27 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MessageRejectInput : Drop a held message and reply to its sender.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageRejectInput {
+    /// Explanation delivered to the sender.
+    #[serde(rename = "reply")]
+    pub reply: String,
+}
+
+impl MessageRejectInput {
+    /// Drop a held message and reply to its sender.
+    pub fn new(reply: String) -> MessageRejectInput {
+        MessageRejectInput { reply }
+    }
+}
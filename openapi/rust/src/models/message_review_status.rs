@@ -0,0 +1,37 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MessageReviewStatus : Quality Assurance review state of a queued message.
+/// Quality Assurance review state of a queued message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum MessageReviewStatus {
+    #[serde(rename = "HELD")]
+    Held,
+    #[serde(rename = "APPROVED")]
+    Approved,
+}
+
+impl std::fmt::Display for MessageReviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Held => write!(f, "HELD"),
+            Self::Approved => write!(f, "APPROVED"),
+        }
+    }
+}
+
+impl Default for MessageReviewStatus {
+    fn default() -> MessageReviewStatus {
+        Self::Held
+    }
+}
@@ -1,29 +1,57 @@
 pub mod active_strategy;
 pub use self::active_strategy::ActiveStrategy;
+pub mod agent_backends;
+pub use self::agent_backends::AgentBackends;
 pub mod agent_model_overrides;
 pub use self::agent_model_overrides::AgentModelOverrides;
 pub mod agent_reasoning_overrides;
 pub use self::agent_reasoning_overrides::AgentReasoningOverrides;
+pub mod api_role;
+pub use self::api_role::ApiRole;
+pub mod api_token;
+pub use self::api_token::ApiToken;
 pub mod append_files_config;
 pub use self::append_files_config::AppendFilesConfig;
+pub mod audit_entry;
+pub use self::audit_entry::AuditEntry;
 pub mod command_config;
 pub use self::command_config::CommandConfig;
 pub mod commit_info;
 pub use self::commit_info::CommitInfo;
 pub mod config;
 pub use self::config::Config;
+pub mod container_limits;
+pub use self::container_limits::ContainerLimits;
+pub mod container_limits_config;
+pub use self::container_limits_config::ContainerLimitsConfig;
 pub mod docker_overrides;
 pub use self::docker_overrides::DockerOverrides;
+pub mod effective_docker_args;
+pub use self::effective_docker_args::EffectiveDockerArgs;
 pub mod exec_command_input;
 pub use self::exec_command_input::ExecCommandInput;
 pub mod exec_result;
 pub use self::exec_result::ExecResult;
+pub mod exec_settings;
+pub use self::exec_settings::ExecSettings;
 pub mod feed;
 pub use self::feed::Feed;
+pub mod feed_archive;
+pub use self::feed_archive::FeedArchive;
+pub mod feed_archive_import;
+pub use self::feed_archive_import::FeedArchiveImport;
+pub mod feed_item_kind;
+pub use self::feed_item_kind::FeedItemKind;
 pub mod feed_level;
 pub use self::feed_level::FeedLevel;
 pub mod feed_order_field;
 pub use self::feed_order_field::FeedOrderField;
+pub mod feed_retention;
+pub use self::feed_retention::FeedRetention;
+pub mod feed_retention_result;
+pub use self::feed_retention_result::FeedRetentionResult;
+pub mod feed_retention_rule;
+pub use self::feed_retention_rule::FeedRetentionRule;
 pub mod get_healthz_200_response;
 pub use self::get_healthz_200_response::GetHealthz200Response;
 pub mod _git_commit__worktree_id__post_request;
@@ -44,14 +72,40 @@ pub mod insert_message_one_of_1;
 pub use self::insert_message_one_of_1::InsertMessageOneOf1;
 pub mod message;
 pub use self::message::Message;
+pub mod message_approve_input;
+pub use self::message_approve_input::MessageApproveInput;
 pub mod message_enqueue_input;
 pub use self::message_enqueue_input::MessageEnqueueInput;
+pub mod message_reject_input;
+pub use self::message_reject_input::MessageRejectInput;
+pub mod message_review_status;
+pub use self::message_review_status::MessageReviewStatus;
+pub mod qa_inbox_item;
+pub use self::qa_inbox_item::QaInboxItem;
+pub mod qa_reply_input;
+pub use self::qa_reply_input::QaReplyInput;
+pub mod qa_thread;
+pub use self::qa_thread::QaThread;
 pub mod queue_state;
 pub use self::queue_state::QueueState;
 pub mod reasoning_effort;
 pub use self::reasoning_effort::ReasoningEffort;
-pub mod strategy;
-pub use self::strategy::Strategy;
+pub mod review_mode;
+pub use self::review_mode::ReviewMode;
+pub mod run_replay;
+pub use self::run_replay::RunReplay;
+pub mod schedule_action;
+pub use self::schedule_action::ScheduleAction;
+pub mod schedule_override_input;
+pub use self::schedule_override_input::ScheduleOverrideInput;
+pub mod schedule_rule;
+pub use self::schedule_rule::ScheduleRule;
+pub mod schedule_state;
+pub use self::schedule_state::ScheduleState;
+pub mod scheduled_run;
+pub use self::scheduled_run::ScheduledRun;
+pub mod strategy_definition;
+pub use self::strategy_definition::StrategyDefinition;
 pub mod task;
 pub use self::task::Task;
 pub mod task_create_input;
@@ -68,11 +122,23 @@ pub mod task_group_status;
 pub use self::task_group_status::TaskGroupStatus;
 pub mod task_group_update_input;
 pub use self::task_group_update_input::TaskGroupUpdateInput;
+pub mod task_history_entry;
+pub use self::task_history_entry::TaskHistoryEntry;
+pub mod task_proposal;
+pub use self::task_proposal::TaskProposal;
+pub mod task_proposal_accept_input;
+pub use self::task_proposal_accept_input::TaskProposalAcceptInput;
+pub mod task_proposal_reject_input;
+pub use self::task_proposal_reject_input::TaskProposalRejectInput;
+pub mod task_proposal_status;
+pub use self::task_proposal_status::TaskProposalStatus;
 pub mod task_status;
 pub use self::task_status::TaskStatus;
 pub mod task_update_input;
 pub use self::task_update_input::TaskUpdateInput;
 pub mod worker;
 pub use self::worker::Worker;
+pub mod worker_profile;
+pub use self::worker_profile::WorkerProfile;
 pub mod worker_state;
 pub use self::worker_state::WorkerState;
@@ -0,0 +1,64 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// QaInboxItem : A message to or from Quality Assurance.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QaInboxItem {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Message sender or recipient display value (\"Orchestrator\", \"Quality Assurance\", or worker handles like \"ws42\").
+    #[serde(rename = "agent")]
+    pub agent: String,
+    /// Task the agent was working on when the thread started.
+    #[serde(rename = "task_id", skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<i64>,
+    #[serde(rename = "task_slug", skip_serializing_if = "Option::is_none")]
+    pub task_slug: Option<String>,
+    #[serde(rename = "message")]
+    pub message: String,
+    /// True for replies sent by Quality Assurance.
+    #[serde(rename = "from_qa")]
+    pub from_qa: bool,
+    /// Inbox item this reply answers.
+    #[serde(rename = "reply_to", skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<i64>,
+    /// Unix timestamp (seconds).
+    #[serde(rename = "created_at")]
+    pub created_at: i64,
+    /// Unix timestamp (seconds) when Quality Assurance read the item.
+    #[serde(rename = "read_at", skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<i64>,
+}
+
+impl QaInboxItem {
+    /// A message to or from Quality Assurance.
+    pub fn new(
+        id: i64,
+        agent: String,
+        message: String,
+        from_qa: bool,
+        created_at: i64,
+    ) -> QaInboxItem {
+        QaInboxItem {
+            id,
+            agent,
+            task_id: None,
+            task_slug: None,
+            message,
+            from_qa,
+            reply_to: None,
+            created_at,
+            read_at: None,
+        }
+    }
+}
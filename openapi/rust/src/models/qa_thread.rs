@@ -0,0 +1,50 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// QaThread : Inbox items grouped by originating agent and task.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QaThread {
+    /// Message sender or recipient display value (\"Orchestrator\", \"Quality Assurance\", or worker handles like \"ws42\").
+    #[serde(rename = "agent")]
+    pub agent: String,
+    #[serde(rename = "task_id", skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<i64>,
+    #[serde(rename = "task_slug", skip_serializing_if = "Option::is_none")]
+    pub task_slug: Option<String>,
+    #[serde(rename = "unread")]
+    pub unread: i64,
+    /// Unix timestamp (seconds) of the newest item.
+    #[serde(rename = "last_activity")]
+    pub last_activity: i64,
+    #[serde(rename = "items")]
+    pub items: Vec<models::QaInboxItem>,
+}
+
+impl QaThread {
+    /// Inbox items grouped by originating agent and task.
+    pub fn new(
+        agent: String,
+        unread: i64,
+        last_activity: i64,
+        items: Vec<models::QaInboxItem>,
+    ) -> QaThread {
+        QaThread {
+            agent,
+            task_id: None,
+            task_slug: None,
+            unread,
+            last_activity,
+            items,
+        }
+    }
+}
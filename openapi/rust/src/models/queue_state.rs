@@ -15,10 +15,20 @@ use serde::{Deserialize, Serialize};
 pub struct QueueState {
     #[serde(rename = "paused")]
     pub paused: bool,
+    /// True while in-flight runs finish before the queue pauses. Start with `POST /queue/drain`.
+    #[serde(rename = "draining", skip_serializing_if = "Option::is_none")]
+    pub draining: Option<bool>,
+    /// Workers excluded from dispatch. Change with `POST /workers/{workerId}/pause` and `/resume`.
+    #[serde(rename = "paused_workers", skip_serializing_if = "Option::is_none")]
+    pub paused_workers: Option<Vec<i64>>,
 }
 
 impl QueueState {
     pub fn new(paused: bool) -> QueueState {
-        QueueState { paused }
+        QueueState {
+            paused,
+            draining: None,
+            paused_workers: None,
+        }
     }
 }
@@ -0,0 +1,40 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ReviewMode : Rules for holding queued messages until Quality Assurance approves them.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReviewMode {
+    /// Hold every orchestrator message to a worker, including assignments and those the deterministic scheduler makes.
+    #[serde(
+        rename = "orchestrator_to_worker",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub orchestrator_to_worker: Option<bool>,
+    /// Hold messages to or from workers assigned a task in these group slugs.
+    #[serde(rename = "task_groups", skip_serializing_if = "Option::is_none")]
+    pub task_groups: Option<Vec<String>>,
+    /// Hold every message while one of these strategies is active.
+    #[serde(rename = "strategies", skip_serializing_if = "Option::is_none")]
+    pub strategies: Option<Vec<String>>,
+}
+
+impl ReviewMode {
+    /// Rules for holding queued messages until Quality Assurance approves them.
+    pub fn new() -> ReviewMode {
+        ReviewMode {
+            orchestrator_to_worker: None,
+            task_groups: None,
+            strategies: None,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RunReplay : Outcome of replaying a recorded agent run.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunReplay {
+    #[serde(rename = "run_id")]
+    pub run_id: String,
+    /// Agent that produced the recording (e.g. `orchestrator`, `ws1`).
+    #[serde(rename = "agent")]
+    pub agent: String,
+    /// Feed entries found in the recording.
+    #[serde(rename = "feed_entries")]
+    pub feed_entries: i64,
+    /// Intent of the structured turn found in the recording, if any.
+    #[serde(rename = "turn_intent", skip_serializing_if = "Option::is_none")]
+    pub turn_intent: Option<String>,
+    /// Whether the feed entries and turn went through live turn processing.
+    #[serde(rename = "applied")]
+    pub applied: bool,
+}
+
+impl RunReplay {
+    /// Outcome of replaying a recorded agent run.
+    pub fn new(run_id: String, agent: String, feed_entries: i64, applied: bool) -> RunReplay {
+        RunReplay {
+            run_id,
+            agent,
+            feed_entries,
+            turn_intent: None,
+            applied,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ScheduleAction : What a schedule rule does when it fires.
+/// What a schedule rule does when it fires.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ScheduleAction {
+    #[serde(rename = "SET_STRATEGY")]
+    SetStrategy,
+    #[serde(rename = "PAUSE")]
+    Pause,
+    #[serde(rename = "RESUME")]
+    Resume,
+}
+
+impl std::fmt::Display for ScheduleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SetStrategy => write!(f, "SET_STRATEGY"),
+            Self::Pause => write!(f, "PAUSE"),
+            Self::Resume => write!(f, "RESUME"),
+        }
+    }
+}
+
+impl Default for ScheduleAction {
+    fn default() -> ScheduleAction {
+        Self::SetStrategy
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ScheduleOverrideInput : Suspend the schedule until the given time.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleOverrideInput {
+    /// Unix timestamp (seconds) when the schedule resumes.
+    #[serde(rename = "until")]
+    pub until: i64,
+}
+
+impl ScheduleOverrideInput {
+    /// Suspend the schedule until the given time.
+    pub fn new(until: i64) -> ScheduleOverrideInput {
+        ScheduleOverrideInput { until }
+    }
+}
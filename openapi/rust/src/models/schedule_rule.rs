@@ -0,0 +1,52 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ScheduleRule : Time-based rule that switches strategy or pauses/resumes the queue. Times use the server's local clock.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleRule {
+    /// Label shown in the feed and schedule listing.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Days the rule starts on (mon, tue, wed, thu, fri, sat, sun). Omit for every day.
+    #[serde(rename = "days", skip_serializing_if = "Option::is_none")]
+    pub days: Option<Vec<String>>,
+    /// Start time as HH:MM.
+    #[serde(rename = "at")]
+    pub at: String,
+    /// Optional end time as HH:MM. When reached, the state from before the rule fired is restored; an end earlier than `at` spans midnight.
+    #[serde(rename = "until", skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    #[serde(rename = "action")]
+    pub action: models::ScheduleAction,
+    /// Strategy to activate for SET_STRATEGY.
+    #[serde(rename = "strategy", skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+    /// Focus task group ids for SET_STRATEGY.
+    #[serde(rename = "focus", skip_serializing_if = "Option::is_none")]
+    pub focus: Option<Vec<i64>>,
+}
+
+impl ScheduleRule {
+    /// Time-based rule that switches strategy or pauses/resumes the queue. Times use the server's local clock.
+    pub fn new(at: String, action: models::ScheduleAction) -> ScheduleRule {
+        ScheduleRule {
+            name: None,
+            days: None,
+            at,
+            until: None,
+            action,
+            strategy: None,
+            focus: None,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ScheduleState : Configured schedule, override status and upcoming transitions.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleState {
+    #[serde(rename = "rules")]
+    pub rules: Vec<models::ScheduleRule>,
+    /// Unix timestamp (seconds) until which the schedule is suspended.
+    #[serde(rename = "override_until", skip_serializing_if = "Option::is_none")]
+    pub override_until: Option<i64>,
+    #[serde(rename = "upcoming")]
+    pub upcoming: Vec<models::ScheduledRun>,
+}
+
+impl ScheduleState {
+    /// Configured schedule, override status and upcoming transitions.
+    pub fn new(
+        rules: Vec<models::ScheduleRule>,
+        upcoming: Vec<models::ScheduledRun>,
+    ) -> ScheduleState {
+        ScheduleState {
+            rules,
+            override_until: None,
+            upcoming,
+        }
+    }
+}
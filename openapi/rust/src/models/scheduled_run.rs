@@ -0,0 +1,48 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ScheduledRun : Upcoming schedule transition.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledRun {
+    /// Rule name, or its position in the schedule when unnamed.
+    #[serde(rename = "rule")]
+    pub rule: String,
+    #[serde(rename = "action")]
+    pub action: models::ScheduleAction,
+    #[serde(rename = "strategy", skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+    /// Unix timestamp (seconds).
+    #[serde(rename = "at")]
+    pub at: i64,
+    /// True when this transition restores the state from before the rule started.
+    #[serde(rename = "ends_window")]
+    pub ends_window: bool,
+}
+
+impl ScheduledRun {
+    /// Upcoming schedule transition.
+    pub fn new(
+        rule: String,
+        action: models::ScheduleAction,
+        at: i64,
+        ends_window: bool,
+    ) -> ScheduledRun {
+        ScheduledRun {
+            rule,
+            action,
+            strategy: None,
+            at,
+            ends_window,
+        }
+    }
+}
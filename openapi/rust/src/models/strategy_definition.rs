@@ -0,0 +1,61 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// StrategyDefinition : Strategy preset or user-defined strategy from the workspace config.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StrategyDefinition {
+    /// Strategy identifier: a built-in preset (AGGRESSIVE, MODERATE, ECONOMICAL, BUG_SMASH, HOTFIX_SWARM, MAINTENANCE, PLANNING, WIND_DOWN) or a name defined under `strategies` in the workspace config.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Guidance appended to orchestrator prompts. `{focus}` expands to the focus groups and `{busy_workers}` to the worker target.
+    #[serde(rename = "hint")]
+    pub hint: String,
+    /// Task group slugs eligible for assignment once the focus groups are finished (or when no focus is set).
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// Number of workers to keep busy. With `scheduler` on this is the scheduler's budget; otherwise it caps how many idle workers the orchestrator is hinted to assign. Omit to use every idle worker.
+    #[serde(rename = "busy_workers", skip_serializing_if = "Option::is_none")]
+    pub busy_workers: Option<i64>,
+    /// Whether new tasks may be assigned to workers.
+    #[serde(rename = "allow_assignments", skip_serializing_if = "Option::is_none")]
+    pub allow_assignments: Option<bool>,
+    /// Finish one eligible group before sourcing tasks from the next.
+    #[serde(rename = "sequential_groups", skip_serializing_if = "Option::is_none")]
+    pub sequential_groups: Option<bool>,
+    /// Let the deterministic scheduler assign tasks directly when it is enabled.
+    #[serde(rename = "scheduler", skip_serializing_if = "Option::is_none")]
+    pub scheduler: Option<bool>,
+    /// Allow the orchestrator to create and edit tasks, groups and proposals.
+    #[serde(rename = "task_editing", skip_serializing_if = "Option::is_none")]
+    pub task_editing: Option<bool>,
+    /// MCP tools the orchestrator may use. Omit to allow every tool.
+    #[serde(rename = "tools", skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<String>>,
+}
+
+impl StrategyDefinition {
+    /// Strategy preset or user-defined strategy from the workspace config.
+    pub fn new(name: String, hint: String) -> StrategyDefinition {
+        StrategyDefinition {
+            name,
+            hint,
+            groups: None,
+            busy_workers: None,
+            allow_assignments: None,
+            sequential_groups: None,
+            scheduler: None,
+            task_editing: None,
+            tools: None,
+        }
+    }
+}
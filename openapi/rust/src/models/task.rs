@@ -37,6 +37,12 @@ pub struct Task {
     pub model_override: Option<String>,
     #[serde(rename = "reasoning_override", skip_serializing_if = "Option::is_none")]
     pub reasoning_override: Option<String>,
+    /// Scheduling priority; higher values are assigned first by the deterministic scheduler (defaults to 0).
+    #[serde(rename = "priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// Skills a worker must provide to be assigned this task (e.g. `flutter`), in addition to the group's.
+    #[serde(rename = "required_skills", skip_serializing_if = "Option::is_none")]
+    pub required_skills: Option<Vec<String>>,
 }
 
 impl Task {
@@ -61,6 +67,8 @@ impl Task {
             description,
             model_override: None,
             reasoning_override: None,
+            priority: None,
+            required_skills: None,
         }
     }
 }
@@ -24,6 +24,9 @@ pub struct TaskGroup {
     pub description: String,
     #[serde(rename = "status")]
     pub status: models::TaskGroupStatus,
+    /// Skills every task in this group requires (e.g. `flutter`).
+    #[serde(rename = "required_skills", skip_serializing_if = "Option::is_none")]
+    pub required_skills: Option<Vec<String>>,
 }
 
 impl TaskGroup {
@@ -41,6 +44,7 @@ impl TaskGroup {
             title,
             description,
             status,
+            required_skills: None,
         }
     }
 }
@@ -20,6 +20,9 @@ pub struct TaskGroupCreateInput {
     pub title: String,
     #[serde(rename = "description")]
     pub description: String,
+    /// Skills every task in this group requires (e.g. `flutter`).
+    #[serde(rename = "required_skills", skip_serializing_if = "Option::is_none")]
+    pub required_skills: Option<Vec<String>>,
 }
 
 impl TaskGroupCreateInput {
@@ -29,6 +32,7 @@ impl TaskGroupCreateInput {
             slug,
             title,
             description,
+            required_skills: None,
         }
     }
 }
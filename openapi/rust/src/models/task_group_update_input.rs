@@ -20,6 +20,9 @@ pub struct TaskGroupUpdateInput {
     pub title: Option<String>,
     #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Skills every task in this group requires (e.g. `flutter`).
+    #[serde(rename = "required_skills", skip_serializing_if = "Option::is_none")]
+    pub required_skills: Option<Vec<String>>,
 }
 
 impl TaskGroupUpdateInput {
@@ -29,6 +32,7 @@ impl TaskGroupUpdateInput {
             slug: None,
             title: None,
             description: None,
+            required_skills: None,
         }
     }
 }
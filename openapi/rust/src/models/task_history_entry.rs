@@ -0,0 +1,62 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TaskHistoryEntry : One field change recorded on a task, or its creation.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskHistoryEntry {
+    #[serde(rename = "id")]
+    pub id: i64,
+    #[serde(rename = "task_id")]
+    pub task_id: i64,
+    /// Task field that changed, e.g. `status` or `owner`, or `created` for the entry written when the task is created (its `new_value` is the slug).
+    #[serde(rename = "field")]
+    pub field: String,
+    /// Previous value; absent when the field was unset.
+    #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    /// New value; absent when the field was cleared.
+    #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+    /// Who made the change: an agent label (`Orchestrator`, `ws3`), an API token name, or `System`.
+    #[serde(rename = "actor")]
+    pub actor: String,
+    /// Where the change came from: `rest`, the MCP tool name (e.g. `tasks_set_status`), or `system`.
+    #[serde(rename = "source")]
+    pub source: String,
+    /// Unix timestamp (seconds).
+    #[serde(rename = "created_at")]
+    pub created_at: i64,
+}
+
+impl TaskHistoryEntry {
+    /// One field change recorded on a task, or its creation.
+    pub fn new(
+        id: i64,
+        task_id: i64,
+        field: String,
+        actor: String,
+        source: String,
+        created_at: i64,
+    ) -> TaskHistoryEntry {
+        TaskHistoryEntry {
+            id,
+            task_id,
+            field,
+            old_value: None,
+            new_value: None,
+            actor,
+            source,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TaskProposal : Follow-up task proposed by a worker and awaiting review.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskProposal {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Worker handle that filed the proposal (e.g., ws42).
+    #[serde(rename = "proposer")]
+    pub proposer: String,
+    /// Task the worker was on when the proposal was filed.
+    #[serde(rename = "source_task_slug", skip_serializing_if = "Option::is_none")]
+    pub source_task_slug: Option<String>,
+    #[serde(rename = "title")]
+    pub title: String,
+    /// Detailed description of the proposed work.
+    #[serde(rename = "description")]
+    pub description: String,
+    /// Task group slug suggested by the worker (`chores` or `bugs`).
+    #[serde(rename = "suggested_group")]
+    pub suggested_group: String,
+    /// Slugs of tasks the proposed work depends on.
+    #[serde(rename = "dependencies")]
+    pub dependencies: Vec<String>,
+    #[serde(rename = "status")]
+    pub status: models::TaskProposalStatus,
+    /// Who reviewed the proposal: the reviewing agent (Orchestrator or Quality Assurance) or the API token name for REST reviews.
+    #[serde(rename = "reviewer", skip_serializing_if = "Option::is_none")]
+    pub reviewer: Option<String>,
+    /// Note left by the reviewer.
+    #[serde(rename = "review_note", skip_serializing_if = "Option::is_none")]
+    pub review_note: Option<String>,
+    /// Task created when the proposal was accepted.
+    #[serde(rename = "task_id", skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<i64>,
+    /// Unix timestamp (seconds) when the proposal was filed.
+    #[serde(rename = "created_at")]
+    pub created_at: i64,
+    /// Unix timestamp (seconds) when the proposal was reviewed.
+    #[serde(rename = "reviewed_at", skip_serializing_if = "Option::is_none")]
+    pub reviewed_at: Option<i64>,
+}
+
+impl TaskProposal {
+    /// Follow-up task proposed by a worker and awaiting review.
+    pub fn new(
+        id: i64,
+        proposer: String,
+        title: String,
+        description: String,
+        suggested_group: String,
+        dependencies: Vec<String>,
+        status: models::TaskProposalStatus,
+        created_at: i64,
+    ) -> TaskProposal {
+        TaskProposal {
+            id,
+            proposer,
+            source_task_slug: None,
+            title,
+            description,
+            suggested_group,
+            dependencies,
+            status,
+            reviewer: None,
+            review_note: None,
+            task_id: None,
+            created_at,
+            reviewed_at: None,
+        }
+    }
+}
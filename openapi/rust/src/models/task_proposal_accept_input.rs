@@ -0,0 +1,45 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TaskProposalAcceptInput : Optional adjustments applied when accepting a proposal.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskProposalAcceptInput {
+    /// Destination group (`chores` or `bugs`); defaults to the suggested group.
+    #[serde(rename = "group_slug", skip_serializing_if = "Option::is_none")]
+    pub group_slug: Option<String>,
+    /// Slug for the created task; derived from the title when omitted.
+    #[serde(rename = "slug", skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// Replacement title for the created task.
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Replacement description for the created task.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Review note stored with the proposal.
+    #[serde(rename = "note", skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl TaskProposalAcceptInput {
+    /// Optional adjustments applied when accepting a proposal.
+    pub fn new() -> TaskProposalAcceptInput {
+        TaskProposalAcceptInput {
+            group_slug: None,
+            slug: None,
+            title: None,
+            description: None,
+            note: None,
+        }
+    }
+}
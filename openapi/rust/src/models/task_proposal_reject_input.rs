@@ -0,0 +1,27 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TaskProposalRejectInput : Reason for rejecting a proposal.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskProposalRejectInput {
+    /// Review note stored with the proposal.
+    #[serde(rename = "note", skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl TaskProposalRejectInput {
+    /// Reason for rejecting a proposal.
+    pub fn new() -> TaskProposalRejectInput {
+        TaskProposalRejectInput { note: None }
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TaskProposalStatus : Review state for a worker task proposal.
+/// Review state for a worker task proposal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum TaskProposalStatus {
+    #[serde(rename = "Pending")]
+    Pending,
+    #[serde(rename = "Accepted")]
+    Accepted,
+    #[serde(rename = "Rejected")]
+    Rejected,
+}
+
+impl std::fmt::Display for TaskProposalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Pending => write!(f, "Pending"),
+            Self::Accepted => write!(f, "Accepted"),
+            Self::Rejected => write!(f, "Rejected"),
+        }
+    }
+}
+
+impl Default for TaskProposalStatus {
+    fn default() -> TaskProposalStatus {
+        Self::Pending
+    }
+}
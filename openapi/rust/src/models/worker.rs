@@ -24,6 +24,9 @@ pub struct Worker {
     /// Most recent Codex thread id for this worker, if any.
     #[serde(rename = "thread_id", skip_serializing_if = "Option::is_none")]
     pub thread_id: Option<String>,
+    /// Skills this worker provides, taken from its worker profile.
+    #[serde(rename = "skills", skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
 }
 
 impl Worker {
@@ -34,6 +37,7 @@ impl Worker {
             last_seen,
             state,
             thread_id: None,
+            skills: None,
         }
     }
 }
@@ -0,0 +1,59 @@
+/*
+ * Robot Farm API
+ *
+ * Base OpenAPI definition for Robot Farm services.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// WorkerProfile : Named worker setup with its own image, model defaults, container limits and directives. Worktrees listed in `workers` use it; the rest use the shared worker settings.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WorkerProfile {
+    /// Profile name: lowercase letters, digits, `-` and `_`.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Dockerfile fragment for this profile, relative to `.robot-farm-rs/`. Defaults to the shared `Dockerfile` fragment.
+    #[serde(rename = "dockerfile", skip_serializing_if = "Option::is_none")]
+    pub dockerfile: Option<String>,
+    /// Image tag. Defaults to the worker image tag suffixed with `-<name>`.
+    #[serde(rename = "image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Model for workers on this profile unless a task overrides it.
+    #[serde(rename = "model", skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(rename = "reasoning", skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<models::ReasoningEffort>,
+    #[serde(rename = "limits", skip_serializing_if = "Option::is_none")]
+    pub limits: Option<Box<models::ContainerLimits>>,
+    /// Extra AGENTS files appended after `append_agents_file.worker`, resolved relative to the worktree.
+    #[serde(rename = "directives", skip_serializing_if = "Option::is_none")]
+    pub directives: Option<Vec<String>>,
+    /// Skills workers on this profile provide (e.g. `flutter`, `sql`).
+    #[serde(rename = "skills", skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+    /// Worktree names (e.g. `ws2`) assigned to this profile.
+    #[serde(rename = "workers")]
+    pub workers: Vec<String>,
+}
+
+impl WorkerProfile {
+    /// Named worker setup with its own image, model defaults, container limits and directives. Worktrees listed in `workers` use it; the rest use the shared worker settings.
+    pub fn new(name: String, workers: Vec<String>) -> WorkerProfile {
+        WorkerProfile {
+            name,
+            dockerfile: None,
+            image: None,
+            model: None,
+            reasoning: None,
+            limits: None,
+            directives: None,
+            skills: None,
+            workers,
+        }
+    }
+}
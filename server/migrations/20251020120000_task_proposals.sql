@@ -0,0 +1,18 @@
+-- Task proposals hold follow-up work filed by workers until it is reviewed.
+CREATE TABLE IF NOT EXISTS task_proposal (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proposer TEXT NOT NULL,
+    source_task_slug TEXT,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    suggested_group TEXT NOT NULL,
+    dependencies TEXT NOT NULL DEFAULT '[]',
+    status TEXT NOT NULL DEFAULT 'Pending',
+    reviewer TEXT,
+    review_note TEXT,
+    task_id INTEGER REFERENCES task(id) ON DELETE SET NULL,
+    created_at BIGINT NOT NULL,
+    reviewed_at BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS task_proposal_status_idx ON task_proposal (status, created_at DESC);
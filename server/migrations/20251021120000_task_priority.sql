@@ -0,0 +1,2 @@
+-- Scheduling priority used by the deterministic scheduler (higher runs first).
+ALTER TABLE task ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,6 @@
+-- Delivery controls for queued messages: ordering priority, delayed delivery and expiry.
+ALTER TABLE message_queue ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE message_queue ADD COLUMN deliver_after BIGINT;
+ALTER TABLE message_queue ADD COLUMN expires_at BIGINT;
+
+CREATE INDEX IF NOT EXISTS message_queue_priority_idx ON message_queue (priority DESC, inserted_at ASC);
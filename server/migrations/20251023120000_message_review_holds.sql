@@ -0,0 +1,3 @@
+-- Quality Assurance review holds for queued messages.
+ALTER TABLE message_queue ADD COLUMN review_status TEXT;
+ALTER TABLE message_queue ADD COLUMN hold_reason TEXT;
@@ -0,0 +1,16 @@
+-- Messages addressed to Quality Assurance and the replies sent back, threaded
+-- by the agent that raised them and the task it was working on.
+CREATE TABLE IF NOT EXISTS qa_inbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    agent TEXT NOT NULL,
+    task_id INTEGER REFERENCES task(id) ON DELETE SET NULL,
+    task_slug TEXT,
+    message TEXT NOT NULL,
+    from_qa INTEGER NOT NULL DEFAULT 0,
+    reply_to INTEGER REFERENCES qa_inbox(id) ON DELETE SET NULL,
+    created_at BIGINT NOT NULL,
+    read_at BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS qa_inbox_thread_idx ON qa_inbox (agent, task_id, created_at);
+CREATE INDEX IF NOT EXISTS qa_inbox_unread_idx ON qa_inbox (read_at) WHERE read_at IS NULL;
@@ -0,0 +1,4 @@
+-- Skills a worker must provide before it can be assigned a task, stored as
+-- JSON arrays of lowercase names. Group skills apply to every task inside it.
+ALTER TABLE task ADD COLUMN required_skills TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE task_group ADD COLUMN required_skills TEXT NOT NULL DEFAULT '[]';
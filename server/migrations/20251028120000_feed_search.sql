@@ -0,0 +1,24 @@
+-- Full-text index over feed text, kept in sync with the feed table by triggers,
+-- plus an index for category filters.
+CREATE VIRTUAL TABLE IF NOT EXISTS feed_fts USING fts5 (
+    text,
+    content = 'feed',
+    content_rowid = 'id'
+);
+
+CREATE TRIGGER IF NOT EXISTS feed_fts_insert AFTER INSERT ON feed BEGIN
+    INSERT INTO feed_fts (rowid, text) VALUES (new.id, new.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS feed_fts_delete AFTER DELETE ON feed BEGIN
+    INSERT INTO feed_fts (feed_fts, rowid, text) VALUES ('delete', old.id, old.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS feed_fts_update AFTER UPDATE OF text ON feed BEGIN
+    INSERT INTO feed_fts (feed_fts, rowid, text) VALUES ('delete', old.id, old.text);
+    INSERT INTO feed_fts (rowid, text) VALUES (new.id, new.text);
+END;
+
+INSERT INTO feed_fts (feed_fts) VALUES ('rebuild');
+
+CREATE INDEX IF NOT EXISTS feed_category_idx ON feed (category, id);
@@ -0,0 +1,9 @@
+-- Raw payloads moved out to per-run blob files are referenced by file, byte
+-- offset and length. Rows restored from an archive remember which one and are
+-- skipped by retention sweeps.
+ALTER TABLE feed ADD COLUMN raw_blob TEXT;
+ALTER TABLE feed ADD COLUMN raw_offset BIGINT;
+ALTER TABLE feed ADD COLUMN raw_len BIGINT;
+ALTER TABLE feed ADD COLUMN imported_from TEXT;
+
+CREATE INDEX IF NOT EXISTS feed_raw_blob_idx ON feed (raw_blob) WHERE raw_blob IS NOT NULL;
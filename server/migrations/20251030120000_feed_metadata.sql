@@ -0,0 +1,12 @@
+-- Typed metadata recorded with agent-output feed entries. `changed_files` is a
+-- JSON array of paths so it can be searched with json_each.
+ALTER TABLE feed ADD COLUMN run_id TEXT;
+ALTER TABLE feed ADD COLUMN task_slug TEXT;
+ALTER TABLE feed ADD COLUMN turn_index BIGINT;
+ALTER TABLE feed ADD COLUMN item_kind TEXT;
+ALTER TABLE feed ADD COLUMN exit_code INTEGER;
+ALTER TABLE feed ADD COLUMN changed_files TEXT;
+
+CREATE INDEX IF NOT EXISTS feed_run_id_idx ON feed (run_id) WHERE run_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS feed_task_slug_idx ON feed (task_slug, source) WHERE task_slug IS NOT NULL;
+CREATE INDEX IF NOT EXISTS feed_item_kind_idx ON feed (item_kind) WHERE item_kind IS NOT NULL;
@@ -0,0 +1,8 @@
+-- When a row was restored from an archive, so imports can expire on their own
+-- schedule. Rows imported before this column existed start their clock now.
+ALTER TABLE feed ADD COLUMN imported_at BIGINT;
+
+UPDATE feed SET imported_at = CAST(strftime('%s', 'now') AS INTEGER)
+WHERE imported_from IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS feed_imported_at_idx ON feed (imported_at) WHERE imported_from IS NOT NULL;
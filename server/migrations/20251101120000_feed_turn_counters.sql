@@ -0,0 +1,15 @@
+-- Runs dispatched per source and task, so a dispatch claims its turn index
+-- without counting feed rows. `task_slug` is '' for runs outside any task.
+-- Seeded from the runs already recorded in the feed.
+CREATE TABLE IF NOT EXISTS feed_turn_counter (
+    source TEXT NOT NULL,
+    task_slug TEXT NOT NULL DEFAULT '',
+    turns BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (source, task_slug)
+);
+
+INSERT OR IGNORE INTO feed_turn_counter (source, task_slug, turns)
+SELECT source, COALESCE(task_slug, ''), COUNT(DISTINCT run_id)
+FROM feed
+WHERE run_id IS NOT NULL
+GROUP BY source, COALESCE(task_slug, '');
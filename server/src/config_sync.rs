@@ -15,7 +15,11 @@ use crate::{
     routes::config::{self, ConfigError},
     shared::git,
     system::staging_hooks,
-    system::{codex_config, docker_overrides, features},
+    system::{
+        agent_backend, api_auth, codex_config, container_limits, container_runtime,
+        docker_overrides, exec, features, feed_archive, review, schedule, skills, strategy,
+        worker_profiles,
+    },
 };
 
 #[derive(Debug, Error)]
@@ -28,6 +32,26 @@ pub enum ConfigSyncError {
     Git(#[from] git::GitError),
     #[error("codex settings invalid: {0}")]
     InvalidCodexSettings(String),
+    #[error("strategies invalid: {0}")]
+    InvalidStrategies(String),
+    #[error("schedule invalid: {0}")]
+    InvalidSchedule(String),
+    #[error("review mode invalid: {0}")]
+    InvalidReviewMode(String),
+    #[error("agent backends invalid: {0}")]
+    InvalidAgentBackends(String),
+    #[error("container limits invalid: {0}")]
+    InvalidContainerLimits(String),
+    #[error("container runtime invalid: {0}")]
+    InvalidContainerRuntime(String),
+    #[error("worker profiles invalid: {0}")]
+    InvalidWorkerProfiles(String),
+    #[error("API auth invalid: {0}")]
+    InvalidApiAuth(String),
+    #[error("exec settings invalid: {0}")]
+    InvalidExecSettings(String),
+    #[error("feed retention invalid: {0}")]
+    InvalidFeedRetention(String),
 }
 
 pub fn reload_from_disk() -> Result<(), ConfigSyncError> {
@@ -43,8 +67,19 @@ pub fn clear_state() -> Result<(), ConfigSyncError> {
     PostTurnCheckRegistry::global().replace(Vec::new());
     staging_hooks::replace(Vec::new());
     codex_config::reset();
+    agent_backend::reset();
+    container_limits::reset();
+    container_runtime::reset();
     docker_overrides::reset();
     features::reset();
+    strategy::reset();
+    schedule::reset();
+    review::reset();
+    worker_profiles::reset();
+    skills::reset();
+    api_auth::reset();
+    exec::reset();
+    feed_archive::reset();
     remove_agent_overrides()?;
     Ok(())
 }
@@ -52,15 +87,64 @@ pub fn clear_state() -> Result<(), ConfigSyncError> {
 fn apply_config(config: &WorkspaceConfig) -> Result<(), ConfigSyncError> {
     codex_config::validate_preferences(config.models.as_ref(), config.reasoning.as_ref())
         .map_err(ConfigSyncError::InvalidCodexSettings)?;
+    let strategies = config.strategies.clone().unwrap_or_default();
+    strategy::validate_definitions(&strategies).map_err(ConfigSyncError::InvalidStrategies)?;
+    let rules = config.schedule.clone().unwrap_or_default();
+    schedule::validate_rules(&rules, |name| strategy::is_defined(name, &strategies))
+        .map_err(ConfigSyncError::InvalidSchedule)?;
+    let review_mode = config.review_mode.as_deref().cloned().unwrap_or_default();
+    review::validate(&review_mode, |name| strategy::is_defined(name, &strategies))
+        .map_err(ConfigSyncError::InvalidReviewMode)?;
+    let backends = config
+        .agent_backends
+        .as_deref()
+        .cloned()
+        .unwrap_or_default();
+    agent_backend::validate(&backends).map_err(ConfigSyncError::InvalidAgentBackends)?;
+    let limits = config
+        .container_limits
+        .as_deref()
+        .cloned()
+        .unwrap_or_default();
+    container_limits::validate(&limits).map_err(ConfigSyncError::InvalidContainerLimits)?;
+    let profiles = config.worker_profiles.clone().unwrap_or_default();
+    worker_profiles::validate(&profiles).map_err(ConfigSyncError::InvalidWorkerProfiles)?;
+    let runtime = config.container_runtime.unwrap_or_default();
+    container_runtime::validate(runtime, &config.docker_overrides, &limits, &profiles)
+        .map_err(ConfigSyncError::InvalidContainerRuntime)?;
+    let api_tokens = config.api_tokens.clone().unwrap_or_default();
+    let cors_origins = config.cors_origins.clone().unwrap_or_default();
+    api_auth::validate(&api_tokens, &cors_origins).map_err(ConfigSyncError::InvalidApiAuth)?;
+    let exec_settings = config.exec.as_deref().cloned().unwrap_or_default();
+    exec::validate(&exec_settings).map_err(ConfigSyncError::InvalidExecSettings)?;
+    let retention = config
+        .feed_retention
+        .as_deref()
+        .cloned()
+        .unwrap_or_default();
+    feed_archive::validate(&retention).map_err(ConfigSyncError::InvalidFeedRetention)?;
     codex_config::replace((*config.models).clone(), (*config.reasoning).clone());
+    agent_backend::replace(backends);
+    container_limits::replace(limits);
+    worker_profiles::replace(profiles);
+    skills::replace(config.skill_mismatch.unwrap_or_default());
+    container_runtime::replace(runtime);
+    api_auth::replace(api_tokens, cors_origins);
+    exec::replace(exec_settings);
+    feed_archive::replace(retention);
     docker_overrides::replace((*config.docker_overrides).clone());
     ProjectCommandRegistry::global().replace(config.commands.clone());
     PostTurnCheckRegistry::global().replace(config.post_turn_checks.clone());
     staging_hooks::replace(config.on_staging_change.clone().unwrap_or_default());
+    strategy::replace(strategies);
+    schedule::replace(rules);
+    review::replace(review_mode);
     features::replace(features::FeatureToggles {
         persistent_threads: config.persistent_threads.unwrap_or(false),
         ghost_commits: config.ghost_commits.unwrap_or(false),
         drift_manager: config.drift_manager.unwrap_or(false),
+        deterministic_scheduler: config.deterministic_scheduler.unwrap_or(false),
+        coalesce_messages: config.coalesce_messages.unwrap_or(false),
     });
     regenerate_agent_overrides(&config.append_agents_file)?;
     // workspace_path is informational only; no runtime effect.
@@ -88,6 +172,14 @@ fn write_override_for_worktree(
         WorktreeRole::Worker => {
             append_role_directive(WorktreeRole::Worker, &mut buffer)?;
             append_sections(worktree, &config.worker, &mut buffer)?;
+            let profile_directives = worktree
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(container_limits::worker_id_from_key)
+                .and_then(worker_profiles::profile_for)
+                .and_then(|profile| profile.directives)
+                .unwrap_or_default();
+            append_sections(worktree, &profile_directives, &mut buffer)?;
         }
     }
 
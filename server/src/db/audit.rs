@@ -0,0 +1,86 @@
+use crate::db::{self, DbResult};
+use chrono::Utc;
+use openapi::models::{ApiRole, AuditEntry};
+use sqlx::{QueryBuilder, Row, Sqlite};
+
+const COLUMNS: &str = "id, actor, role, action, params, created_at";
+
+#[derive(Debug, Clone)]
+pub struct NewAuditEntry {
+    pub actor: String,
+    pub role: ApiRole,
+    pub action: String,
+    pub params: serde_json::Value,
+}
+
+#[derive(Debug, Default)]
+pub struct AuditFilters {
+    pub actor: Option<String>,
+    pub action: Option<String>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub limit: i64,
+}
+
+pub async fn insert_entry(entry: NewAuditEntry) -> DbResult<AuditEntry> {
+    let row = sqlx::query(&format!(
+        r#"
+        INSERT INTO audit_log (actor, role, action, params, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        RETURNING {COLUMNS}
+        "#
+    ))
+    .bind(entry.actor)
+    .bind(entry.role.to_string())
+    .bind(entry.action)
+    .bind(entry.params.to_string())
+    .bind(Utc::now().timestamp())
+    .fetch_one(db::pool())
+    .await?;
+    Ok(row_to_entry(row))
+}
+
+/// Entries matching `filters`, newest first.
+pub async fn list_entries(filters: AuditFilters) -> DbResult<Vec<AuditEntry>> {
+    let mut builder =
+        QueryBuilder::<Sqlite>::new(format!("SELECT {COLUMNS} FROM audit_log WHERE 1 = 1"));
+    if let Some(actor) = filters.actor {
+        builder.push(" AND actor = ").push_bind(actor);
+    }
+    if let Some(action) = filters.action {
+        builder.push(" AND action = ").push_bind(action);
+    }
+    if let Some(since) = filters.since {
+        builder.push(" AND created_at >= ").push_bind(since);
+    }
+    if let Some(until) = filters.until {
+        builder.push(" AND created_at <= ").push_bind(until);
+    }
+    builder
+        .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+        .push_bind(filters.limit);
+
+    let rows = builder.build().fetch_all(db::pool()).await?;
+    Ok(rows.into_iter().map(row_to_entry).collect())
+}
+
+fn parse_role(value: &str) -> ApiRole {
+    match value {
+        "admin" => ApiRole::Admin,
+        "operator" => ApiRole::Operator,
+        _ => ApiRole::Viewer,
+    }
+}
+
+fn row_to_entry(row: sqlx::sqlite::SqliteRow) -> AuditEntry {
+    let role: String = row.get("role");
+    let params: String = row.get("params");
+    AuditEntry {
+        id: row.get("id"),
+        actor: row.get("actor"),
+        role: parse_role(&role),
+        action: row.get("action"),
+        params: serde_json::from_str(&params).unwrap_or_default(),
+        created_at: row.get("created_at"),
+    }
+}
@@ -1,8 +1,11 @@
 use crate::db::{self, DbResult};
 use chrono::Utc;
-use openapi::models::{Feed, FeedLevel, FeedOrderField};
+use openapi::models::{Feed, FeedItemKind, FeedLevel, FeedOrderField};
 use sqlx::{QueryBuilder, Row, Sqlite};
 
+const COLUMNS: &str = "id, source, target, ts, level, text, raw, category, run_id, task_slug, \
+                       turn_index, item_kind, exit_code, changed_files";
+
 #[derive(Clone)]
 pub struct NewFeedEntry {
     pub source: String,
@@ -11,52 +14,215 @@ pub struct NewFeedEntry {
     pub text: String,
     pub raw: String,
     pub category: String,
+    /// Set when `raw` was moved to a blob file; `raw` is then empty.
+    pub raw_blob: Option<RawBlob>,
+    pub meta: FeedEntryMeta,
+}
+
+/// Typed metadata of an agent-output entry; left at its default for everything else.
+#[derive(Clone, Debug, Default)]
+pub struct FeedEntryMeta {
+    pub run_id: Option<String>,
+    pub task_slug: Option<String>,
+    pub turn_index: Option<i64>,
+    pub item_kind: Option<FeedItemKind>,
+    pub exit_code: Option<i32>,
+    pub changed_files: Vec<String>,
+}
+
+/// Location of a raw payload stored outside the database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawBlob {
+    pub file: String,
+    pub offset: i64,
+    pub len: i64,
+}
+
+/// Which rows a retention sweep expires. Rows restored from archives are only
+/// subject to `imported_before`, and are deleted rather than archived again.
+#[derive(Debug, Default, PartialEq)]
+pub struct RetentionCutoffs {
+    /// Entries older than this timestamp, outside categories with their own rule.
+    pub before_ts: Option<i64>,
+    /// `(category, timestamp)`: entries of `category` older than `timestamp`.
+    pub categories: Vec<(String, i64)>,
+    /// Keep only this many of the newest entries.
+    pub keep_rows: Option<i64>,
+    /// Restored entries imported before this timestamp.
+    pub imported_before: Option<i64>,
 }
 
+#[derive(Default)]
 pub struct FeedFilters {
     pub source: Option<String>,
     pub target: Option<String>,
     pub level: Option<FeedLevel>,
+    pub categories: Vec<String>,
+    /// Only entries with an id below this cursor (older pages).
+    pub before_id: Option<i64>,
+    /// Only entries with an id above this cursor (newer pages / backfill).
+    pub after_id: Option<i64>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    /// Full-text search over `text`.
+    pub query: Option<String>,
+    pub run_id: Option<String>,
+    pub task_slug: Option<String>,
+    pub turn_index: Option<i64>,
+    pub item_kinds: Vec<FeedItemKind>,
+    pub exit_code: Option<i32>,
+    /// Only entries whose `changed_files` include this path.
+    pub file: Option<String>,
     pub order_by: Option<FeedOrderField>,
+    pub limit: Option<i64>,
     pub include_raw: bool,
 }
 
+/// Entries matching `filters`. Id and timestamp ordering are newest first; with only
+/// `after_id` set, the page holds the entries immediately after the cursor.
 pub async fn list_feed(filters: FeedFilters) -> DbResult<Vec<Feed>> {
-    let mut builder = QueryBuilder::<Sqlite>::new(
-        "SELECT id, source, target, ts, level, text, raw, category FROM feed",
-    );
+    let order_field = filters.order_by.unwrap_or(FeedOrderField::Ts);
+    let newest_first = matches!(order_field, FeedOrderField::Ts | FeedOrderField::Id);
+    let forward = newest_first && filters.after_id.is_some() && filters.before_id.is_none();
+
+    let mut builder = QueryBuilder::<Sqlite>::new(if forward {
+        format!("SELECT * FROM (SELECT {COLUMNS} FROM feed")
+    } else {
+        format!("SELECT {COLUMNS} FROM feed")
+    });
     let mut has_clause = false;
 
     if let Some(source) = filters.source {
         builder
-            .push(if has_clause { " AND " } else { " WHERE " })
+            .push(clause(&mut has_clause))
             .push("source = ")
             .push_bind(source);
-        has_clause = true;
     }
 
     if let Some(target) = filters.target {
         builder
-            .push(if has_clause { " AND " } else { " WHERE " })
+            .push(clause(&mut has_clause))
             .push("target = ")
             .push_bind(target);
-        has_clause = true;
     }
 
     if let Some(level) = filters.level {
         builder
-            .push(if has_clause { " AND " } else { " WHERE " })
+            .push(clause(&mut has_clause))
             .push("level = ")
             .push_bind(level_to_str(level));
     }
 
-    let order_field = filters.order_by.unwrap_or(FeedOrderField::Ts);
-    builder
-        .push(" ORDER BY ")
-        .push(order_field_name(order_field));
+    if !filters.categories.is_empty() {
+        builder.push(clause(&mut has_clause)).push("category IN (");
+        let mut separated = builder.separated(", ");
+        for category in filters.categories {
+            separated.push_bind(category);
+        }
+        builder.push(")");
+    }
+
+    if let Some(before_id) = filters.before_id {
+        builder
+            .push(clause(&mut has_clause))
+            .push("id < ")
+            .push_bind(before_id);
+    }
 
-    if matches!(order_field, FeedOrderField::Ts | FeedOrderField::Id) {
-        builder.push(" DESC");
+    if let Some(after_id) = filters.after_id {
+        builder
+            .push(clause(&mut has_clause))
+            .push("id > ")
+            .push_bind(after_id);
+    }
+
+    if let Some(since) = filters.since {
+        builder
+            .push(clause(&mut has_clause))
+            .push("ts >= ")
+            .push_bind(since);
+    }
+
+    if let Some(until) = filters.until {
+        builder
+            .push(clause(&mut has_clause))
+            .push("ts <= ")
+            .push_bind(until);
+    }
+
+    if let Some(query) = filters.query.as_deref().and_then(fts_query) {
+        builder
+            .push(clause(&mut has_clause))
+            .push("id IN (SELECT rowid FROM feed_fts WHERE feed_fts MATCH ")
+            .push_bind(query)
+            .push(")");
+    }
+
+    if let Some(run_id) = filters.run_id {
+        builder
+            .push(clause(&mut has_clause))
+            .push("run_id = ")
+            .push_bind(run_id);
+    }
+
+    if let Some(task_slug) = filters.task_slug {
+        builder
+            .push(clause(&mut has_clause))
+            .push("task_slug = ")
+            .push_bind(task_slug);
+    }
+
+    if let Some(turn_index) = filters.turn_index {
+        builder
+            .push(clause(&mut has_clause))
+            .push("turn_index = ")
+            .push_bind(turn_index);
+    }
+
+    if !filters.item_kinds.is_empty() {
+        builder.push(clause(&mut has_clause)).push("item_kind IN (");
+        let mut separated = builder.separated(", ");
+        for kind in filters.item_kinds {
+            separated.push_bind(kind.to_string());
+        }
+        builder.push(")");
+    }
+
+    if let Some(exit_code) = filters.exit_code {
+        builder
+            .push(clause(&mut has_clause))
+            .push("exit_code = ")
+            .push_bind(exit_code);
+    }
+
+    if let Some(file) = filters.file {
+        builder
+            .push(clause(&mut has_clause))
+            .push("EXISTS (SELECT 1 FROM json_each(feed.changed_files) WHERE value = ")
+            .push_bind(file)
+            .push(")");
+    }
+
+    if forward {
+        builder.push(" ORDER BY id ASC");
+    } else {
+        builder
+            .push(" ORDER BY ")
+            .push(order_field_name(order_field));
+        if newest_first {
+            builder.push(" DESC");
+        }
+        if order_field == FeedOrderField::Ts {
+            builder.push(", id DESC");
+        }
+    }
+
+    if let Some(limit) = filters.limit {
+        builder.push(" LIMIT ").push_bind(limit);
+    }
+
+    if forward {
+        builder.push(") ORDER BY id DESC");
     }
 
     let rows = builder.build().fetch_all(db::pool()).await?;
@@ -73,20 +239,47 @@ pub async fn list_feed(filters: FeedFilters) -> DbResult<Vec<Feed>> {
     Ok(feed)
 }
 
+fn clause(has_clause: &mut bool) -> &'static str {
+    let keyword = if *has_clause { " AND " } else { " WHERE " };
+    *has_clause = true;
+    keyword
+}
+
+/// Quote each search term so user input is matched as plain words (all must appear)
+/// rather than parsed as FTS5 query syntax.
+fn fts_query(raw: &str) -> Option<String> {
+    let terms: Vec<String> = raw
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Clear the feed and restart turn numbering with it.
 pub async fn delete_feed() -> DbResult<()> {
-    sqlx::query("DELETE FROM feed").execute(db::pool()).await?;
+    let mut tx = db::pool().begin().await?;
+    sqlx::query("DELETE FROM feed").execute(&mut *tx).await?;
+    sqlx::query("DELETE FROM feed_turn_counter")
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
     Ok(())
 }
 
 pub async fn insert_feed_entry(entry: NewFeedEntry) -> DbResult<Feed> {
     let ts = Utc::now().timestamp();
-    let row = sqlx::query(
+    let blob = entry.raw_blob.as_ref();
+    let meta = &entry.meta;
+    let row = sqlx::query(&format!(
         r#"
-        INSERT INTO feed (source, target, ts, level, text, raw, category)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-        RETURNING id, source, target, ts, level, text, raw, category
-        "#,
-    )
+        INSERT INTO feed (
+            source, target, ts, level, text, raw, category, raw_blob, raw_offset, raw_len,
+            run_id, task_slug, turn_index, item_kind, exit_code, changed_files
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+        RETURNING {COLUMNS}
+        "#
+    ))
     .bind(&entry.source)
     .bind(&entry.target)
     .bind(ts)
@@ -94,12 +287,176 @@ pub async fn insert_feed_entry(entry: NewFeedEntry) -> DbResult<Feed> {
     .bind(&entry.text)
     .bind(&entry.raw)
     .bind(&entry.category)
+    .bind(blob.map(|blob| blob.file.as_str()))
+    .bind(blob.map(|blob| blob.offset))
+    .bind(blob.map(|blob| blob.len))
+    .bind(&meta.run_id)
+    .bind(&meta.task_slug)
+    .bind(meta.turn_index)
+    .bind(meta.item_kind.map(|kind| kind.to_string()))
+    .bind(meta.exit_code)
+    .bind(files_to_json(&meta.changed_files))
     .fetch_one(db::pool())
     .await?;
 
     row_to_feed(row).ok_or(sqlx::Error::RowNotFound)
 }
 
+pub async fn get_raw_blob(feed_id: i64) -> DbResult<Option<RawBlob>> {
+    let row = sqlx::query("SELECT raw_blob, raw_offset, raw_len FROM feed WHERE id = ?1")
+        .bind(feed_id)
+        .fetch_optional(db::pool())
+        .await?;
+    Ok(row.as_ref().and_then(row_to_blob))
+}
+
+/// Claim the turn index of a new run of `source` on `task_slug` (or on no task): how
+/// many runs were dispatched for that pair before it.
+pub async fn next_turn_index(source: &str, task_slug: Option<&str>) -> DbResult<i64> {
+    let row = sqlx::query(
+        "INSERT INTO feed_turn_counter (source, task_slug, turns) VALUES (?1, ?2, 1) \
+         ON CONFLICT (source, task_slug) DO UPDATE SET turns = turns + 1 \
+         RETURNING turns - 1 AS turn",
+    )
+    .bind(source)
+    .bind(task_slug.unwrap_or_default())
+    .fetch_one(db::pool())
+    .await?;
+    Ok(row.get("turn"))
+}
+
+/// Up to `limit` expired entries, oldest first, with `raw` left empty when it lives in a blob.
+pub async fn list_expired(
+    cutoffs: &RetentionCutoffs,
+    limit: i64,
+) -> DbResult<Vec<(Feed, Option<RawBlob>)>> {
+    let mut builder = QueryBuilder::<Sqlite>::new(format!(
+        "SELECT {COLUMNS}, raw_blob, raw_offset, raw_len FROM feed WHERE imported_from IS NULL AND (0"
+    ));
+    let mut has_rule = false;
+
+    if let Some(before_ts) = cutoffs.before_ts {
+        builder.push(" OR (ts < ").push_bind(before_ts);
+        if !cutoffs.categories.is_empty() {
+            builder.push(" AND category NOT IN (");
+            let mut separated = builder.separated(", ");
+            for (category, _) in &cutoffs.categories {
+                separated.push_bind(category.clone());
+            }
+            builder.push(")");
+        }
+        builder.push(")");
+        has_rule = true;
+    }
+
+    for (category, before_ts) in &cutoffs.categories {
+        builder
+            .push(" OR (category = ")
+            .push_bind(category.clone())
+            .push(" AND ts < ")
+            .push_bind(*before_ts)
+            .push(")");
+        has_rule = true;
+    }
+
+    if let Some(keep_rows) = cutoffs.keep_rows {
+        builder
+            .push(" OR id <= (SELECT id FROM feed WHERE imported_from IS NULL ORDER BY id DESC LIMIT 1 OFFSET ")
+            .push_bind(keep_rows)
+            .push(")");
+        has_rule = true;
+    }
+
+    if !has_rule {
+        return Ok(Vec::new());
+    }
+    builder.push(") ORDER BY id ASC LIMIT ").push_bind(limit);
+
+    let rows = builder.build().fetch_all(db::pool()).await?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            let blob = row_to_blob(&row);
+            row_to_feed(row).map(|entry| (entry, blob))
+        })
+        .collect())
+}
+
+/// Delete restored entries that `cutoffs` expires; their archive still holds them.
+pub async fn delete_expired_imports(cutoffs: &RetentionCutoffs) -> DbResult<u64> {
+    let Some(before) = cutoffs.imported_before else {
+        return Ok(0);
+    };
+    let result =
+        sqlx::query("DELETE FROM feed WHERE imported_from IS NOT NULL AND imported_at < ?1")
+            .bind(before)
+            .execute(db::pool())
+            .await?;
+    Ok(result.rows_affected())
+}
+
+pub async fn delete_entries(ids: &[i64]) -> DbResult<u64> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let mut builder = QueryBuilder::<Sqlite>::new("DELETE FROM feed WHERE id IN (");
+    let mut separated = builder.separated(", ");
+    for id in ids {
+        separated.push_bind(*id);
+    }
+    builder.push(")");
+    let result = builder.build().execute(db::pool()).await?;
+    Ok(result.rows_affected())
+}
+
+/// Whether any remaining entry still points into blob `file`.
+pub async fn blob_in_use(file: &str) -> DbResult<bool> {
+    let row = sqlx::query("SELECT 1 FROM feed WHERE raw_blob = ?1 LIMIT 1")
+        .bind(file)
+        .fetch_optional(db::pool())
+        .await?;
+    Ok(row.is_some())
+}
+
+/// Restore archived entries under their original ids, skipping ids already present.
+pub async fn import_entries(entries: Vec<Feed>, archive: &str) -> DbResult<u64> {
+    let imported_at = Utc::now().timestamp();
+    let mut tx = db::pool().begin().await?;
+    let mut imported = 0;
+    for entry in entries {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO feed (
+                id, source, target, ts, level, text, raw, category, run_id, task_slug,
+                turn_index, item_kind, exit_code, changed_files, imported_from, imported_at
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+            "#,
+        )
+        .bind(entry.id)
+        .bind(&entry.source)
+        .bind(&entry.target)
+        .bind(entry.ts)
+        .bind(level_to_str(entry.level))
+        .bind(&entry.text)
+        .bind(&entry.raw)
+        .bind(&entry.category)
+        .bind(&entry.run_id)
+        .bind(&entry.task_slug)
+        .bind(entry.turn_index)
+        .bind(entry.item_kind.map(|kind| kind.to_string()))
+        .bind(entry.exit_code)
+        .bind(entry.changed_files.as_deref().and_then(files_to_json))
+        .bind(archive)
+        .bind(imported_at)
+        .execute(&mut *tx)
+        .await?;
+        imported += result.rows_affected();
+    }
+    tx.commit().await?;
+    Ok(imported)
+}
+
 fn level_to_str(level: FeedLevel) -> &'static str {
     match level {
         FeedLevel::Info => "info",
@@ -117,6 +474,30 @@ fn parse_level(value: &str) -> Option<FeedLevel> {
     }
 }
 
+fn parse_item_kind(value: &str) -> Option<FeedItemKind> {
+    match value {
+        "session" => Some(FeedItemKind::Session),
+        "message" => Some(FeedItemKind::Message),
+        "reasoning" => Some(FeedItemKind::Reasoning),
+        "command" => Some(FeedItemKind::Command),
+        "file_change" => Some(FeedItemKind::FileChange),
+        "mcp_tool_call" => Some(FeedItemKind::McpToolCall),
+        "web_search" => Some(FeedItemKind::WebSearch),
+        "todo_list" => Some(FeedItemKind::TodoList),
+        "error" => Some(FeedItemKind::Error),
+        "turn" => Some(FeedItemKind::Turn),
+        _ => None,
+    }
+}
+
+/// `None` for an empty list so entries without file changes keep a NULL column.
+fn files_to_json(files: &[String]) -> Option<String> {
+    if files.is_empty() {
+        return None;
+    }
+    serde_json::to_string(files).ok()
+}
+
 fn order_field_name(field: FeedOrderField) -> &'static str {
     match field {
         FeedOrderField::Id => "id",
@@ -130,9 +511,20 @@ fn order_field_name(field: FeedOrderField) -> &'static str {
     }
 }
 
+fn row_to_blob(row: &sqlx::sqlite::SqliteRow) -> Option<RawBlob> {
+    let file: Option<String> = row.get("raw_blob");
+    Some(RawBlob {
+        file: file?,
+        offset: row.get::<Option<i64>, _>("raw_offset").unwrap_or_default(),
+        len: row.get::<Option<i64>, _>("raw_len").unwrap_or_default(),
+    })
+}
+
 fn row_to_feed(row: sqlx::sqlite::SqliteRow) -> Option<Feed> {
     let level: String = row.get("level");
     let level = parse_level(&level)?;
+    let item_kind: Option<String> = row.get("item_kind");
+    let changed_files: Option<String> = row.get("changed_files");
     Some(Feed {
         id: row.get("id"),
         source: row.get("source"),
@@ -142,20 +534,20 @@ fn row_to_feed(row: sqlx::sqlite::SqliteRow) -> Option<Feed> {
         text: row.get("text"),
         raw: row.get("raw"),
         category: row.get("category"),
+        run_id: row.get("run_id"),
+        task_slug: row.get("task_slug"),
+        turn_index: row.get("turn_index"),
+        item_kind: item_kind.as_deref().and_then(parse_item_kind),
+        exit_code: row.get("exit_code"),
+        changed_files: changed_files.and_then(|files| serde_json::from_str(&files).ok()),
     })
 }
 
 pub async fn get_feed_entry(feed_id: i64) -> DbResult<Option<Feed>> {
-    let row = sqlx::query(
-        r#"
-        SELECT id, source, target, ts, level, text, raw, category
-        FROM feed
-        WHERE id = ?1
-        "#,
-    )
-    .bind(feed_id)
-    .fetch_optional(db::pool())
-    .await?;
+    let row = sqlx::query(&format!("SELECT {COLUMNS} FROM feed WHERE id = ?1"))
+        .bind(feed_id)
+        .fetch_optional(db::pool())
+        .await?;
 
     Ok(row.and_then(row_to_feed))
 }
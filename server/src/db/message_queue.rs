@@ -1,6 +1,6 @@
 use crate::db::{self, DbResult};
 use chrono::Utc;
-use openapi::models::Message;
+use openapi::models::{Message, MessageReviewStatus};
 use sqlx::{QueryBuilder, Row, Sqlite};
 
 #[derive(Clone, Debug, Default)]
@@ -9,6 +9,17 @@ pub struct MessageFilters {
     pub to: Option<String>,
 }
 
+/// Delivery controls for a new queue entry.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MessageOptions {
+    /// Higher values are dispatched first.
+    pub priority: i64,
+    /// Unix timestamp the message is held until.
+    pub deliver_after: Option<i64>,
+    /// Seconds the message may wait once deliverable before it is dropped.
+    pub ttl_seconds: Option<i64>,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum RelativePosition {
     Before(i64),
@@ -21,13 +32,15 @@ pub enum MessageQueueError {
     MessageNotFound,
     #[error("anchor message not found")]
     AnchorNotFound,
+    #[error("message is not held for review")]
+    NotHeld,
     #[error(transparent)]
     Db(#[from] sqlx::Error),
 }
 
 pub async fn list_messages(filters: MessageFilters) -> DbResult<Vec<Message>> {
     let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(
-        "SELECT id, from_actor, to_actor, message, inserted_at FROM message_queue",
+        "SELECT id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason FROM message_queue",
     );
     let mut has_clause = false;
 
@@ -46,7 +59,7 @@ pub async fn list_messages(filters: MessageFilters) -> DbResult<Vec<Message>> {
             .push_bind(to);
     }
 
-    builder.push(" ORDER BY inserted_at ASC");
+    builder.push(" ORDER BY priority DESC, inserted_at ASC");
 
     let rows = builder.build().fetch_all(db::pool()).await?;
     Ok(rows.into_iter().map(row_to_message).collect())
@@ -60,12 +73,28 @@ pub async fn delete_all_messages() -> DbResult<()> {
 }
 
 pub async fn enqueue_message(from_actor: &str, to_actor: &str, message: &str) -> DbResult<Message> {
+    enqueue_message_with(from_actor, to_actor, message, MessageOptions::default()).await
+}
+
+pub async fn enqueue_message_with(
+    from_actor: &str,
+    to_actor: &str,
+    message: &str,
+    options: MessageOptions,
+) -> DbResult<Message> {
     // Avoid duplicate enqueues of the same message payload to the same recipient.
+    // Copies with a different priority, delivery time or TTL are kept apart.
     if let Some(row) = sqlx::query(
         r#"
-        SELECT id, from_actor, to_actor, message, inserted_at
+        SELECT id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason
         FROM message_queue
         WHERE from_actor = ?1 AND to_actor = ?2 AND message = ?3
+          AND priority = ?4
+          AND deliver_after IS ?5
+          AND (
+            (?6 IS NULL AND expires_at IS NULL)
+            OR expires_at - MAX(COALESCE(deliver_after, inserted_at), inserted_at) = ?6
+          )
         ORDER BY inserted_at ASC
         LIMIT 1
         "#,
@@ -73,6 +102,9 @@ pub async fn enqueue_message(from_actor: &str, to_actor: &str, message: &str) ->
     .bind(from_actor)
     .bind(to_actor)
     .bind(message)
+    .bind(options.priority)
+    .bind(options.deliver_after)
+    .bind(options.ttl_seconds)
     .fetch_optional(db::pool())
     .await?
     {
@@ -80,17 +112,27 @@ pub async fn enqueue_message(from_actor: &str, to_actor: &str, message: &str) ->
     }
 
     let inserted_at = Utc::now().timestamp();
+    let expires_at = options.ttl_seconds.map(|ttl| {
+        options
+            .deliver_after
+            .unwrap_or(inserted_at)
+            .max(inserted_at)
+            + ttl
+    });
     let row = sqlx::query(
         r#"
-        INSERT INTO message_queue (from_actor, to_actor, message, inserted_at)
-        VALUES (?1, ?2, ?3, ?4)
-        RETURNING id, from_actor, to_actor, message, inserted_at
+        INSERT INTO message_queue (from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        RETURNING id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason
         "#,
     )
     .bind(from_actor)
     .bind(to_actor)
     .bind(message)
     .bind(inserted_at)
+    .bind(options.priority)
+    .bind(options.deliver_after)
+    .bind(expires_at)
     .fetch_one(db::pool())
     .await?;
     Ok(row_to_message(row))
@@ -104,6 +146,96 @@ pub async fn delete_message_by_id(message_id: i64) -> DbResult<bool> {
     Ok(result.rows_affected() > 0)
 }
 
+/// Hold a message for Quality Assurance review. Returns `None` if it was
+/// already delivered or reviewed.
+pub async fn hold_message(message_id: i64, reason: &str) -> DbResult<Option<Message>> {
+    let row = sqlx::query(
+        r#"
+        UPDATE message_queue
+        SET review_status = ?1, hold_reason = ?2
+        WHERE id = ?3 AND review_status IS NULL
+        RETURNING id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason
+        "#,
+    )
+    .bind(MessageReviewStatus::Held.to_string())
+    .bind(reason)
+    .bind(message_id)
+    .fetch_optional(db::pool())
+    .await?;
+    Ok(row.map(row_to_message))
+}
+
+/// Release a held message, optionally replacing its body.
+pub async fn approve_message(
+    message_id: i64,
+    body: Option<&str>,
+) -> Result<Message, MessageQueueError> {
+    let row = sqlx::query(
+        r#"
+        UPDATE message_queue
+        SET review_status = ?1, message = COALESCE(?2, message)
+        WHERE id = ?3 AND review_status = ?4
+        RETURNING id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason
+        "#,
+    )
+    .bind(MessageReviewStatus::Approved.to_string())
+    .bind(body)
+    .bind(message_id)
+    .bind(MessageReviewStatus::Held.to_string())
+    .fetch_optional(db::pool())
+    .await?;
+    match row {
+        Some(row) => Ok(row_to_message(row)),
+        None => Err(held_lookup_error(message_id).await),
+    }
+}
+
+/// Remove a held message so it can be rejected.
+pub async fn take_held_message(message_id: i64) -> Result<Message, MessageQueueError> {
+    let row = sqlx::query(
+        r#"
+        DELETE FROM message_queue
+        WHERE id = ?1 AND review_status = ?2
+        RETURNING id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason
+        "#,
+    )
+    .bind(message_id)
+    .bind(MessageReviewStatus::Held.to_string())
+    .fetch_optional(db::pool())
+    .await?;
+    match row {
+        Some(row) => Ok(row_to_message(row)),
+        None => Err(held_lookup_error(message_id).await),
+    }
+}
+
+async fn held_lookup_error(message_id: i64) -> MessageQueueError {
+    let exists = sqlx::query("SELECT 1 FROM message_queue WHERE id = ?1")
+        .bind(message_id)
+        .fetch_optional(db::pool())
+        .await;
+    match exists {
+        Ok(Some(_)) => MessageQueueError::NotHeld,
+        Ok(None) => MessageQueueError::MessageNotFound,
+        Err(err) => MessageQueueError::Db(err),
+    }
+}
+
+/// Remove messages whose TTL has run out, returning what was dropped.
+pub async fn purge_expired(now: i64) -> DbResult<Vec<Message>> {
+    let rows = sqlx::query(
+        r#"
+        DELETE FROM message_queue
+        WHERE expires_at IS NOT NULL AND expires_at <= ?1
+        RETURNING id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason
+        "#,
+    )
+    .bind(now)
+    .fetch_all(db::pool())
+    .await?;
+    Ok(rows.into_iter().map(row_to_message).collect())
+}
+
 pub async fn delete_messages_for_recipient(recipient: &str) -> DbResult<u64> {
     let result = sqlx::query("DELETE FROM message_queue WHERE to_actor = ?1")
         .bind(recipient)
@@ -121,7 +253,7 @@ pub async fn insert_message_relative(
     sqlx::query("BEGIN IMMEDIATE").execute(&mut *conn).await?;
 
     let rows = sqlx::query(
-        "SELECT id, from_actor, to_actor, message, inserted_at FROM message_queue ORDER BY inserted_at ASC",
+        "SELECT id, from_actor, to_actor, message, inserted_at, priority, deliver_after, expires_at, review_status, hold_reason FROM message_queue ORDER BY priority DESC, inserted_at ASC",
     )
     .fetch_all(&mut *conn)
     .await?;
@@ -158,7 +290,11 @@ pub async fn insert_message_relative(
         RelativePosition::After(_) => anchor_index + 1,
     };
 
-    let message = queue.remove(current_index);
+    // Moving next to the anchor means sharing its priority; otherwise the
+    // priority ordering would undo the move.
+    let anchor_priority = queue[anchor_index].priority;
+    let mut message = queue.remove(current_index);
+    message.priority = anchor_priority;
     if new_index > current_index {
         new_index = new_index.saturating_sub(1);
     }
@@ -170,8 +306,9 @@ pub async fn insert_message_relative(
     let base = Utc::now().timestamp();
     for (offset, item) in queue.iter_mut().enumerate() {
         item.inserted_at = base + offset as i64;
-        sqlx::query("UPDATE message_queue SET inserted_at = ?1 WHERE id = ?2")
+        sqlx::query("UPDATE message_queue SET inserted_at = ?1, priority = ?2 WHERE id = ?3")
             .bind(item.inserted_at)
+            .bind(item.priority.unwrap_or_default())
             .bind(item.id)
             .execute(&mut *conn)
             .await?;
@@ -188,5 +325,19 @@ fn row_to_message(row: sqlx::sqlite::SqliteRow) -> Message {
         to: row.get("to_actor"),
         message: row.get("message"),
         inserted_at: row.get("inserted_at"),
+        priority: Some(row.get("priority")),
+        deliver_after: row.get("deliver_after"),
+        expires_at: row.get("expires_at"),
+        review_status: row
+            .get::<Option<String>, _>("review_status")
+            .map(|raw| parse_review_status(&raw)),
+        hold_reason: row.get("hold_reason"),
+    }
+}
+
+fn parse_review_status(raw: &str) -> MessageReviewStatus {
+    match raw {
+        "APPROVED" => MessageReviewStatus::Approved,
+        _ => MessageReviewStatus::Held,
     }
 }
@@ -7,13 +7,17 @@ use std::path::PathBuf;
 use tracing::info;
 
 pub mod assignments;
+pub mod audit;
 pub mod feed;
 pub mod image_cache;
 pub mod message_queue;
+pub mod qa_inbox;
 pub mod session;
 pub mod task;
 pub mod task_dependency;
 pub mod task_group;
+pub mod task_history;
+pub mod task_proposal;
 pub mod worker;
 
 /// Embedded SQLx migrator. The macro bundles the migrations at compile time, so
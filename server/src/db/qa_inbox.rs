@@ -0,0 +1,133 @@
+use crate::db::{self, DbResult};
+use chrono::Utc;
+use openapi::models::{QaInboxItem, QaThread};
+use sqlx::Row;
+
+const COLUMNS: &str =
+    "id, agent, task_id, task_slug, message, from_qa, reply_to, created_at, read_at";
+
+#[derive(Debug, Clone)]
+pub struct NewQaItem {
+    pub agent: String,
+    pub task_id: Option<i64>,
+    pub task_slug: Option<String>,
+    pub message: String,
+    pub from_qa: bool,
+    pub reply_to: Option<i64>,
+}
+
+pub async fn insert_item(item: NewQaItem) -> DbResult<QaInboxItem> {
+    let now = Utc::now().timestamp();
+    // Replies from Quality Assurance never count as unread.
+    let read_at = item.from_qa.then_some(now);
+    let row = sqlx::query(&format!(
+        r#"
+        INSERT INTO qa_inbox (agent, task_id, task_slug, message, from_qa, reply_to, created_at, read_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        RETURNING {COLUMNS}
+        "#
+    ))
+    .bind(item.agent)
+    .bind(item.task_id)
+    .bind(item.task_slug)
+    .bind(item.message)
+    .bind(item.from_qa)
+    .bind(item.reply_to)
+    .bind(now)
+    .bind(read_at)
+    .fetch_one(db::pool())
+    .await?;
+    Ok(row_to_item(row))
+}
+
+pub async fn get_item(item_id: i64) -> DbResult<Option<QaInboxItem>> {
+    let row = sqlx::query(&format!("SELECT {COLUMNS} FROM qa_inbox WHERE id = ?1"))
+        .bind(item_id)
+        .fetch_optional(db::pool())
+        .await?;
+    Ok(row.map(row_to_item))
+}
+
+pub async fn list_unread() -> DbResult<Vec<QaInboxItem>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM qa_inbox WHERE read_at IS NULL ORDER BY created_at ASC, id ASC"
+    ))
+    .fetch_all(db::pool())
+    .await?;
+    Ok(rows.into_iter().map(row_to_item).collect())
+}
+
+/// Threads keyed by agent and task, most recent activity first. Threads are
+/// counted and filtered in SQL, so only items of the returned threads are read.
+pub async fn list_threads(unread_only: bool) -> DbResult<Vec<QaThread>> {
+    let rows = sqlx::query(&format!(
+        r#"
+        WITH threads (thread_agent, thread_task_id, unread, last_activity, first_id) AS (
+            SELECT agent, task_id, SUM(read_at IS NULL), MAX(created_at), MIN(id)
+            FROM qa_inbox
+            GROUP BY agent, task_id
+            HAVING ?1 = 0 OR SUM(read_at IS NULL) > 0
+        )
+        SELECT {COLUMNS}, unread, last_activity
+        FROM qa_inbox
+        JOIN threads ON agent = thread_agent AND task_id IS thread_task_id
+        ORDER BY last_activity DESC, first_id ASC, created_at ASC, id ASC
+        "#
+    ))
+    .bind(unread_only)
+    .fetch_all(db::pool())
+    .await?;
+
+    let mut threads: Vec<QaThread> = Vec::new();
+    for row in rows {
+        let unread: i64 = row.get("unread");
+        let last_activity: i64 = row.get("last_activity");
+        let item = row_to_item(row);
+        match threads.last_mut() {
+            Some(thread) if thread.agent == item.agent && thread.task_id == item.task_id => {
+                thread.items.push(item);
+            }
+            _ => threads.push(QaThread {
+                agent: item.agent.clone(),
+                task_id: item.task_id,
+                task_slug: item.task_slug.clone(),
+                unread,
+                last_activity,
+                items: vec![item],
+            }),
+        }
+    }
+    Ok(threads)
+}
+
+/// Mark `item` and everything before it in its thread as read.
+pub async fn mark_thread_read(item: &QaInboxItem) -> DbResult<u64> {
+    let result = sqlx::query(
+        r#"
+        UPDATE qa_inbox
+        SET read_at = ?1
+        WHERE agent = ?2 AND task_id IS ?3 AND id <= ?4 AND read_at IS NULL
+        "#,
+    )
+    .bind(Utc::now().timestamp())
+    .bind(&item.agent)
+    .bind(item.task_id)
+    .bind(item.id)
+    .execute(db::pool())
+    .await?;
+    Ok(result.rows_affected())
+}
+
+fn row_to_item(row: sqlx::sqlite::SqliteRow) -> QaInboxItem {
+    QaInboxItem {
+        id: row.get("id"),
+        agent: row.get("agent"),
+        task_id: row.get("task_id"),
+        task_slug: row.get("task_slug"),
+        message: row.get("message"),
+        from_qa: row.get("from_qa"),
+        reply_to: row.get("reply_to"),
+        created_at: row.get("created_at"),
+        read_at: row.get("read_at"),
+    }
+}
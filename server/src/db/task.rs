@@ -1,8 +1,24 @@
-use crate::db::{self, DbResult};
+use crate::db::{
+    self, DbResult,
+    task_history::{self, TaskChangeOrigin},
+};
 use openapi::models::{Task, TaskCreateInput, TaskStatus, TaskUpdateInput};
-use sqlx::Row;
+use sqlx::{Row, SqliteConnection};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tracing::debug;
 
+/// Bumped after every committed write to tasks, groups, dependencies or
+/// workers, so pollers can skip their queries while nothing changed.
+static REVISION: AtomicU64 = AtomicU64::new(0);
+
+pub fn revision() -> u64 {
+    REVISION.load(Ordering::Acquire)
+}
+
+pub(crate) fn touch() {
+    REVISION.fetch_add(1, Ordering::AcqRel);
+}
+
 fn normalize_owner(owner: Option<String>) -> Option<String> {
     owner.map(|o| o.to_ascii_lowercase())
 }
@@ -32,14 +48,32 @@ fn row_to_task(row: sqlx::sqlite::SqliteRow) -> Task {
         description: row.get("description"),
         model_override: row.get("model_override"),
         reasoning_override: row.get("reasoning_override"),
+        priority: Some(row.get("priority")),
+        required_skills: Some(decode_skills(row.get("required_skills"))),
     }
 }
 
+/// Skills are stored lowercase, trimmed, sorted and without duplicates.
+pub(crate) fn encode_skills(skills: Vec<String>) -> String {
+    let mut skills: Vec<String> = skills
+        .into_iter()
+        .map(|skill| skill.trim().to_ascii_lowercase())
+        .filter(|skill| !skill.is_empty())
+        .collect();
+    skills.sort();
+    skills.dedup();
+    serde_json::to_string(&skills).unwrap_or_else(|_| "[]".to_string())
+}
+
+pub(crate) fn decode_skills(raw: String) -> Vec<String> {
+    serde_json::from_str(&raw).unwrap_or_default()
+}
+
 pub async fn list_tasks() -> DbResult<Vec<Task>> {
     let rows = sqlx::query(
         r#"
         SELECT id, group_id, slug, title, commit_hash, status, owner, description
-        , model_override, reasoning_override
+        , model_override, reasoning_override, priority, required_skills
         FROM task
         ORDER BY id ASC
         "#,
@@ -50,7 +84,21 @@ pub async fn list_tasks() -> DbResult<Vec<Task>> {
     Ok(rows.into_iter().map(row_to_task).collect())
 }
 
-pub async fn create_task(payload: TaskCreateInput) -> DbResult<Task> {
+/// Create a task and journal its creation under `origin`.
+pub async fn create_task(payload: TaskCreateInput, origin: &TaskChangeOrigin) -> DbResult<Task> {
+    let mut tx = db::pool().begin().await?;
+    let task = create_task_with(&mut *tx, payload, origin).await?;
+    tx.commit().await?;
+    touch();
+    Ok(task)
+}
+
+/// [`create_task`] on `conn`, so callers can create a task inside their own transaction.
+pub(crate) async fn create_task_with(
+    conn: &mut SqliteConnection,
+    payload: TaskCreateInput,
+    origin: &TaskChangeOrigin,
+) -> DbResult<Task> {
     let TaskCreateInput {
         group_id,
         slug,
@@ -61,9 +109,12 @@ pub async fn create_task(payload: TaskCreateInput) -> DbResult<Task> {
         description,
         model_override,
         reasoning_override,
+        priority,
+        required_skills,
     } = payload;
 
     let status_str = status.to_string();
+    let required_skills = encode_skills(required_skills.unwrap_or_default());
     let owner = normalize_owner_owned(owner);
 
     let row = sqlx::query(
@@ -77,10 +128,12 @@ pub async fn create_task(payload: TaskCreateInput) -> DbResult<Task> {
             owner,
             description,
             model_override,
-            reasoning_override
+            reasoning_override,
+            priority,
+            required_skills
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
-        RETURNING id, group_id, slug, title, commit_hash, status, owner, description, model_override, reasoning_override
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, COALESCE(?10, 0), ?11)
+        RETURNING id, group_id, slug, title, commit_hash, status, owner, description, model_override, reasoning_override, priority, required_skills
         "#,
     )
     .bind(group_id)
@@ -92,17 +145,21 @@ pub async fn create_task(payload: TaskCreateInput) -> DbResult<Task> {
     .bind(description)
     .bind(model_override)
     .bind(reasoning_override)
-    .fetch_one(db::pool())
+    .bind(priority)
+    .bind(required_skills)
+    .fetch_one(&mut *conn)
     .await?;
 
-    Ok(row_to_task(row))
+    let task = row_to_task(row);
+    task_history::record_created(&mut *conn, &task, origin).await?;
+    Ok(task)
 }
 
 pub async fn get_task(task_id: i64) -> DbResult<Option<Task>> {
     let row = sqlx::query(
         r#"
         SELECT id, group_id, slug, title, commit_hash, status, owner, description
-        , model_override, reasoning_override
+        , model_override, reasoning_override, priority, required_skills
         FROM task
         WHERE id = ?1
         "#,
@@ -114,7 +171,12 @@ pub async fn get_task(task_id: i64) -> DbResult<Option<Task>> {
     Ok(row.map(row_to_task))
 }
 
-pub async fn update_task(task_id: i64, payload: TaskUpdateInput) -> DbResult<Option<Task>> {
+/// Apply `payload` and journal the fields it changed under `origin`.
+pub async fn update_task(
+    task_id: i64,
+    payload: TaskUpdateInput,
+    origin: &TaskChangeOrigin,
+) -> DbResult<Option<Task>> {
     let TaskUpdateInput {
         group_id,
         slug,
@@ -125,6 +187,8 @@ pub async fn update_task(task_id: i64, payload: TaskUpdateInput) -> DbResult<Opt
         description,
         model_override,
         reasoning_override,
+        priority,
+        required_skills,
     } = payload;
 
     if group_id.is_none()
@@ -136,6 +200,8 @@ pub async fn update_task(task_id: i64, payload: TaskUpdateInput) -> DbResult<Opt
         && description.is_none()
         && model_override.is_none()
         && reasoning_override.is_none()
+        && priority.is_none()
+        && required_skills.is_none()
     {
         return get_task(task_id).await;
     }
@@ -144,6 +210,23 @@ pub async fn update_task(task_id: i64, payload: TaskUpdateInput) -> DbResult<Opt
 
     let status = status.map(|s| s.to_string());
     let owner = normalize_owner(owner);
+    let required_skills = required_skills.map(encode_skills);
+
+    let mut tx = db::pool().begin().await?;
+    let Some(before) = sqlx::query(
+        r#"
+        SELECT id, group_id, slug, title, commit_hash, status, owner, description
+        , model_override, reasoning_override, priority, required_skills
+        FROM task
+        WHERE id = ?1
+        "#,
+    )
+    .bind(task_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .map(row_to_task) else {
+        return Ok(None);
+    };
 
     let row = sqlx::query(
         r#"
@@ -156,9 +239,11 @@ pub async fn update_task(task_id: i64, payload: TaskUpdateInput) -> DbResult<Opt
             owner = COALESCE(?6, owner),
             description = COALESCE(?7, description),
             model_override = COALESCE(?8, model_override),
-            reasoning_override = COALESCE(?9, reasoning_override)
-        WHERE id = ?10
-        RETURNING id, group_id, slug, title, commit_hash, status, owner, description, model_override, reasoning_override
+            reasoning_override = COALESCE(?9, reasoning_override),
+            priority = COALESCE(?10, priority),
+            required_skills = COALESCE(?11, required_skills)
+        WHERE id = ?12
+        RETURNING id, group_id, slug, title, commit_hash, status, owner, description, model_override, reasoning_override, priority, required_skills
         "#,
     )
     .bind(group_id)
@@ -170,11 +255,17 @@ pub async fn update_task(task_id: i64, payload: TaskUpdateInput) -> DbResult<Opt
     .bind(description)
     .bind(model_override)
     .bind(reasoning_override)
+    .bind(priority)
+    .bind(required_skills)
     .bind(task_id)
-    .fetch_optional(db::pool())
+    .fetch_one(&mut *tx)
     .await?;
 
-    Ok(row.map(row_to_task))
+    let after = row_to_task(row);
+    task_history::record_changes(&mut *tx, &before, &after, origin).await?;
+    tx.commit().await?;
+    touch();
+    Ok(Some(after))
 }
 
 pub async fn delete_task(task_id: i64) -> DbResult<bool> {
@@ -188,6 +279,7 @@ pub async fn delete_task(task_id: i64) -> DbResult<bool> {
     .execute(db::pool())
     .await?;
 
+    touch();
     Ok(result.rows_affected() > 0)
 }
 
@@ -195,7 +287,7 @@ pub async fn get_task_by_slug(slug: &str) -> DbResult<Option<Task>> {
     let row = sqlx::query(
         r#"
         SELECT id, group_id, slug, title, commit_hash, status, owner, description
-        , model_override, reasoning_override
+        , model_override, reasoning_override, priority, required_skills
         FROM task
         WHERE slug = ?1
         "#,
@@ -208,21 +300,45 @@ pub async fn get_task_by_slug(slug: &str) -> DbResult<Option<Task>> {
 }
 
 /// Mark a task as Done and set its owner, matched by slug. Returns true if a row was updated.
-pub async fn mark_done_and_owner(slug: &str, owner: &str) -> DbResult<bool> {
+pub async fn mark_done_and_owner(
+    slug: &str,
+    owner: &str,
+    origin: &TaskChangeOrigin,
+) -> DbResult<bool> {
     let normalized_owner = owner.to_ascii_lowercase();
-    let result = sqlx::query(
+    let mut tx = db::pool().begin().await?;
+    let Some(before) = sqlx::query(
         r#"
-        UPDATE task
-        SET status = 'Done', owner = ?2
+        SELECT id, group_id, slug, title, commit_hash, status, owner, description
+        , model_override, reasoning_override, priority, required_skills
+        FROM task
         WHERE slug = ?1
         "#,
     )
     .bind(slug)
+    .fetch_optional(&mut *tx)
+    .await?
+    .map(row_to_task) else {
+        return Ok(false);
+    };
+
+    let row = sqlx::query(
+        r#"
+        UPDATE task
+        SET status = 'Done', owner = ?2
+        WHERE id = ?1
+        RETURNING id, group_id, slug, title, commit_hash, status, owner, description, model_override, reasoning_override, priority, required_skills
+        "#,
+    )
+    .bind(before.id)
     .bind(normalized_owner)
-    .execute(db::pool())
+    .fetch_one(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    task_history::record_changes(&mut *tx, &before, &row_to_task(row), origin).await?;
+    tx.commit().await?;
+    touch();
+    Ok(true)
 }
 
 pub async fn count_ready_in_group(group_id: i64) -> DbResult<i64> {
@@ -240,3 +356,47 @@ pub async fn count_ready_in_group(group_id: i64) -> DbResult<i64> {
 
     Ok(row.get::<i64, _>("cnt"))
 }
+
+/// Ready tasks in a group that are still unassigned (owned by the orchestrator)
+/// and whose dependencies are all Done, highest priority first.
+pub async fn list_schedulable_in_group(group_id: i64) -> DbResult<Vec<Task>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT t.id, t.group_id, t.slug, t.title, t.commit_hash, t.status, t.owner, t.description
+        , t.model_override, t.reasoning_override, t.priority, t.required_skills
+        FROM task t
+        WHERE t.group_id = ?1
+          AND t.status = 'Ready'
+          AND t.owner = 'orchestrator'
+          AND NOT EXISTS (
+            SELECT 1
+            FROM task_deps d
+            JOIN task dep ON dep.id = d.depends_on_task_id
+            WHERE d.task_id = t.id
+              AND dep.status != 'Done'
+          )
+        ORDER BY t.priority DESC, t.id ASC
+        "#,
+    )
+    .bind(group_id)
+    .fetch_all(db::pool())
+    .await?;
+
+    Ok(rows.into_iter().map(row_to_task).collect())
+}
+
+pub async fn count_open_in_group(group_id: i64) -> DbResult<i64> {
+    let row = sqlx::query(
+        r#"
+        SELECT COUNT(*) as cnt
+        FROM task
+        WHERE group_id = ?1
+          AND status != 'Done'
+        "#,
+    )
+    .bind(group_id)
+    .fetch_one(db::pool())
+    .await?;
+
+    Ok(row.get::<i64, _>("cnt"))
+}
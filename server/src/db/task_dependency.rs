@@ -40,6 +40,7 @@ pub async fn create_task_dependency(
     .execute(db::pool())
     .await?;
 
+    db::task::touch();
     Ok(TaskDependency {
         task_id,
         depends_on_task_id,
@@ -58,5 +59,6 @@ pub async fn delete_task_dependency(task_id: i64, depends_on_task_id: i64) -> Db
     .execute(db::pool())
     .await?;
 
+    db::task::touch();
     Ok(result.rows_affected() > 0)
 }
@@ -1,4 +1,7 @@
-use crate::db::{self, DbResult};
+use crate::db::{
+    self, DbResult,
+    task::{self, decode_skills, encode_skills},
+};
 use openapi::models::{TaskGroup, TaskGroupCreateInput, TaskGroupStatus, TaskGroupUpdateInput};
 use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
 
@@ -31,13 +34,14 @@ fn row_to_task_group(row: sqlx::sqlite::SqliteRow) -> TaskGroup {
         title: row.get("title"),
         description: row.get("description"),
         status: parse_status(&status),
+        required_skills: Some(decode_skills(row.get("required_skills"))),
     }
 }
 
 pub async fn list_task_groups() -> DbResult<Vec<TaskGroup>> {
     let rows = sqlx::query(
         r#"
-        SELECT id, slug, title, description, status
+        SELECT id, slug, title, description, status, required_skills
         FROM task_group
         ORDER BY id ASC
         "#,
@@ -53,31 +57,35 @@ pub async fn create_task_group(payload: TaskGroupCreateInput) -> DbResult<TaskGr
         slug,
         title,
         description,
+        required_skills,
     } = payload;
 
     let status = TaskGroupStatus::Ready.to_string();
+    let required_skills = encode_skills(required_skills.unwrap_or_default());
 
     let row = sqlx::query(
         r#"
-        INSERT INTO task_group (slug, title, description, status)
-        VALUES (?1, ?2, ?3, ?4)
-        RETURNING id, slug, title, description, status
+        INSERT INTO task_group (slug, title, description, status, required_skills)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        RETURNING id, slug, title, description, status, required_skills
         "#,
     )
     .bind(slug)
     .bind(title)
     .bind(description)
     .bind(status)
+    .bind(required_skills)
     .fetch_one(db::pool())
     .await?;
 
+    task::touch();
     Ok(row_to_task_group(row))
 }
 
 pub async fn get_task_group(task_group_id: i64) -> DbResult<Option<TaskGroup>> {
     let row = sqlx::query(
         r#"
-        SELECT id, slug, title, description, status
+        SELECT id, slug, title, description, status, required_skills
         FROM task_group
         WHERE id = ?1
         "#,
@@ -97,9 +105,10 @@ pub async fn update_task_group(
         slug,
         title,
         description,
+        required_skills,
     } = payload;
 
-    if slug.is_none() && title.is_none() && description.is_none() {
+    if slug.is_none() && title.is_none() && description.is_none() && required_skills.is_none() {
         return get_task_group(task_group_id).await;
     }
 
@@ -124,15 +133,25 @@ pub async fn update_task_group(
         if !first {
             builder.push(", ");
         }
+        first = false;
         builder.push("description = ").push_bind(description);
     }
+    if let Some(skills) = required_skills {
+        if !first {
+            builder.push(", ");
+        }
+        builder
+            .push("required_skills = ")
+            .push_bind(encode_skills(skills));
+    }
 
     builder
         .push(" WHERE id = ")
         .push_bind(task_group_id)
-        .push(" RETURNING id, slug, title, description, status");
+        .push(" RETURNING id, slug, title, description, status, required_skills");
 
     let row = builder.build().fetch_optional(db::pool()).await?;
+    task::touch();
     Ok(row.map(row_to_task_group))
 }
 
@@ -147,6 +166,7 @@ pub async fn delete_task_group(task_group_id: i64) -> DbResult<bool> {
     .execute(db::pool())
     .await?;
 
+    task::touch();
     Ok(result.rows_affected() > 0)
 }
 
@@ -159,7 +179,7 @@ pub async fn update_task_group_status(
         UPDATE task_group
         SET status = ?1
         WHERE id = ?2
-        RETURNING id, slug, title, description, status
+        RETURNING id, slug, title, description, status, required_skills
         "#,
     )
     .bind(status.to_string())
@@ -167,6 +187,7 @@ pub async fn update_task_group_status(
     .fetch_optional(db::pool())
     .await?;
 
+    task::touch();
     Ok(row.map(row_to_task_group))
 }
 
@@ -195,7 +216,7 @@ pub async fn ensure_builtin_groups(pool: &SqlitePool) -> DbResult<()> {
 pub async fn get_task_group_by_slug(slug: &str) -> DbResult<Option<TaskGroup>> {
     let row = sqlx::query(
         r#"
-        SELECT id, slug, title, description, status
+        SELECT id, slug, title, description, status, required_skills
         FROM task_group
         WHERE slug = ?1
         "#,
@@ -0,0 +1,145 @@
+use crate::db::{self, DbResult};
+use chrono::Utc;
+use openapi::models::{Task, TaskHistoryEntry};
+use serde_json::Value;
+use sqlx::{Row, SqliteConnection};
+
+const COLUMNS: &str = "id, task_id, field, old_value, new_value, actor, source, created_at";
+
+/// Who changed a task and through which surface.
+#[derive(Debug, Clone)]
+pub struct TaskChangeOrigin {
+    pub actor: String,
+    pub source: String,
+}
+
+impl TaskChangeOrigin {
+    /// A REST caller, identified by API token name.
+    pub fn rest(actor: impl Into<String>) -> Self {
+        Self {
+            actor: actor.into(),
+            source: "rest".to_string(),
+        }
+    }
+
+    /// An agent calling the MCP tool `tool`.
+    pub fn mcp(actor: impl Into<String>, tool: &str) -> Self {
+        Self {
+            actor: actor.into(),
+            source: tool.to_string(),
+        }
+    }
+
+    /// The queue manager acting on behalf of `actor` (`System` when nobody asked).
+    pub fn system(actor: impl Into<String>) -> Self {
+        Self {
+            actor: actor.into(),
+            source: "system".to_string(),
+        }
+    }
+}
+
+pub async fn list_for_task(task_id: i64) -> DbResult<Vec<TaskHistoryEntry>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {COLUMNS} FROM task_history WHERE task_id = ?1 ORDER BY created_at ASC, id ASC"
+    ))
+    .bind(task_id)
+    .fetch_all(db::pool())
+    .await?;
+    Ok(rows.into_iter().map(row_to_entry).collect())
+}
+
+/// Journal every field that differs between `before` and `after`, one row per field.
+pub(crate) async fn record_changes(
+    conn: &mut SqliteConnection,
+    before: &Task,
+    after: &Task,
+    origin: &TaskChangeOrigin,
+) -> DbResult<()> {
+    let (Ok(Value::Object(before)), Ok(Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Ok(());
+    };
+    let now = Utc::now().timestamp();
+    let mut fields: Vec<&String> = after.keys().chain(before.keys()).collect();
+    fields.sort();
+    fields.dedup();
+    for field in fields.into_iter().filter(|field| *field != "id") {
+        let old_value = before.get(field).and_then(value_text);
+        let new_value = after.get(field).and_then(value_text);
+        if old_value == new_value {
+            continue;
+        }
+        let task_id = after.get("id").and_then(Value::as_i64);
+        insert_entry(conn, task_id, field, old_value, new_value, origin, now).await?;
+    }
+    Ok(())
+}
+
+/// Journal that `task` was created: one `created` row whose new value is its slug.
+pub(crate) async fn record_created(
+    conn: &mut SqliteConnection,
+    task: &Task,
+    origin: &TaskChangeOrigin,
+) -> DbResult<()> {
+    let now = Utc::now().timestamp();
+    insert_entry(
+        conn,
+        Some(task.id),
+        "created",
+        None,
+        Some(task.slug.clone()),
+        origin,
+        now,
+    )
+    .await
+}
+
+async fn insert_entry(
+    conn: &mut SqliteConnection,
+    task_id: Option<i64>,
+    field: &str,
+    old_value: Option<String>,
+    new_value: Option<String>,
+    origin: &TaskChangeOrigin,
+    created_at: i64,
+) -> DbResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO task_history (task_id, field, old_value, new_value, actor, source, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "#,
+    )
+    .bind(task_id)
+    .bind(field)
+    .bind(old_value)
+    .bind(new_value)
+    .bind(&origin.actor)
+    .bind(&origin.source)
+    .bind(created_at)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+fn value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn row_to_entry(row: sqlx::sqlite::SqliteRow) -> TaskHistoryEntry {
+    TaskHistoryEntry {
+        id: row.get("id"),
+        task_id: row.get("task_id"),
+        field: row.get("field"),
+        old_value: row.get("old_value"),
+        new_value: row.get("new_value"),
+        actor: row.get("actor"),
+        source: row.get("source"),
+        created_at: row.get("created_at"),
+    }
+}
@@ -0,0 +1,359 @@
+use crate::db::task as task_db;
+use crate::db::task_history::TaskChangeOrigin;
+use crate::db::{self, DbResult};
+use chrono::Utc;
+use openapi::models::{
+    TaskCreateInput, TaskProposal, TaskProposalAcceptInput, TaskProposalStatus, TaskStatus,
+};
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection};
+use tracing::warn;
+
+/// Task groups that accepted proposals may land in.
+pub const PROPOSAL_GROUPS: &[&str] = &["chores", "bugs"];
+
+const SELECT_COLUMNS: &str = "SELECT id, proposer, source_task_slug, title, description, suggested_group, dependencies, status, reviewer, review_note, task_id, created_at, reviewed_at FROM task_proposal";
+
+#[derive(Clone, Debug)]
+pub struct NewTaskProposal {
+    pub proposer: String,
+    pub source_task_slug: Option<String>,
+    pub title: String,
+    pub description: String,
+    pub suggested_group: String,
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TaskProposalFilters {
+    pub status: Option<TaskProposalStatus>,
+    pub proposer: Option<String>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TaskProposalError {
+    #[error("task proposal not found")]
+    NotFound,
+    #[error("task proposal was already {0}")]
+    AlreadyReviewed(TaskProposalStatus),
+    #[error("task group {0} does not accept proposals (expected one of: chores, bugs)")]
+    IneligibleGroup(String),
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+}
+
+pub fn is_proposal_group(slug: &str) -> bool {
+    PROPOSAL_GROUPS
+        .iter()
+        .any(|group| group.eq_ignore_ascii_case(slug.trim()))
+}
+
+fn parse_status(raw: &str) -> TaskProposalStatus {
+    match raw {
+        "Accepted" => TaskProposalStatus::Accepted,
+        "Rejected" => TaskProposalStatus::Rejected,
+        _ => TaskProposalStatus::Pending,
+    }
+}
+
+fn row_to_proposal(row: sqlx::sqlite::SqliteRow) -> TaskProposal {
+    let status: String = row.get("status");
+    let dependencies: String = row.get("dependencies");
+    TaskProposal {
+        id: row.get("id"),
+        proposer: row.get("proposer"),
+        source_task_slug: row.get("source_task_slug"),
+        title: row.get("title"),
+        description: row.get("description"),
+        suggested_group: row.get("suggested_group"),
+        dependencies: serde_json::from_str(&dependencies).unwrap_or_default(),
+        status: parse_status(&status),
+        reviewer: row.get("reviewer"),
+        review_note: row.get("review_note"),
+        task_id: row.get("task_id"),
+        created_at: row.get("created_at"),
+        reviewed_at: row.get("reviewed_at"),
+    }
+}
+
+pub async fn create_proposal(payload: NewTaskProposal) -> DbResult<TaskProposal> {
+    let NewTaskProposal {
+        proposer,
+        source_task_slug,
+        title,
+        description,
+        suggested_group,
+        dependencies,
+    } = payload;
+
+    let dependencies = serde_json::to_string(&dependencies).unwrap_or_else(|_| "[]".to_string());
+    let row = sqlx::query(
+        r#"
+        INSERT INTO task_proposal (
+            proposer,
+            source_task_slug,
+            title,
+            description,
+            suggested_group,
+            dependencies,
+            status,
+            created_at
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        RETURNING id, proposer, source_task_slug, title, description, suggested_group, dependencies, status, reviewer, review_note, task_id, created_at, reviewed_at
+        "#,
+    )
+    .bind(proposer)
+    .bind(source_task_slug)
+    .bind(title)
+    .bind(description)
+    .bind(suggested_group.to_ascii_lowercase())
+    .bind(dependencies)
+    .bind(TaskProposalStatus::Pending.to_string())
+    .bind(Utc::now().timestamp())
+    .fetch_one(db::pool())
+    .await?;
+
+    Ok(row_to_proposal(row))
+}
+
+pub async fn list_proposals(filters: TaskProposalFilters) -> DbResult<Vec<TaskProposal>> {
+    let mut builder = QueryBuilder::<Sqlite>::new(SELECT_COLUMNS);
+    let mut has_clause = false;
+
+    if let Some(status) = filters.status {
+        builder
+            .push(if has_clause { " AND " } else { " WHERE " })
+            .push("status = ")
+            .push_bind(status.to_string());
+        has_clause = true;
+    }
+
+    if let Some(proposer) = filters.proposer {
+        builder
+            .push(if has_clause { " AND " } else { " WHERE " })
+            .push("proposer = ")
+            .push_bind(proposer);
+    }
+
+    builder.push(" ORDER BY created_at DESC, id DESC");
+
+    let rows = builder.build().fetch_all(db::pool()).await?;
+    Ok(rows.into_iter().map(row_to_proposal).collect())
+}
+
+pub async fn get_proposal(proposal_id: i64) -> DbResult<Option<TaskProposal>> {
+    let row = sqlx::query(&format!("{SELECT_COLUMNS} WHERE id = ?1"))
+        .bind(proposal_id)
+        .fetch_optional(db::pool())
+        .await?;
+
+    Ok(row.map(row_to_proposal))
+}
+
+/// Create a task from a pending proposal and mark the proposal as accepted.
+///
+/// The task is created `Ready` and owned by the orchestrator so it shows up in
+/// the next assignment pass. Dependencies that no longer resolve are skipped.
+/// The proposal is claimed with a conditional update inside the transaction, so
+/// concurrent accepts create at most one task.
+pub async fn accept_proposal(
+    proposal_id: i64,
+    reviewer: &str,
+    input: TaskProposalAcceptInput,
+    origin: &TaskChangeOrigin,
+) -> Result<TaskProposal, TaskProposalError> {
+    let proposal = get_proposal(proposal_id)
+        .await?
+        .ok_or(TaskProposalError::NotFound)?;
+    if proposal.status != TaskProposalStatus::Pending {
+        return Err(TaskProposalError::AlreadyReviewed(proposal.status));
+    }
+
+    let group_slug = input
+        .group_slug
+        .unwrap_or_else(|| proposal.suggested_group.clone())
+        .trim()
+        .to_ascii_lowercase();
+    if !is_proposal_group(&group_slug) {
+        return Err(TaskProposalError::IneligibleGroup(group_slug));
+    }
+
+    let mut tx = db::pool().begin().await?;
+
+    let claimed = sqlx::query(
+        r#"
+        UPDATE task_proposal SET
+            status = ?1,
+            reviewer = ?2,
+            review_note = ?3,
+            reviewed_at = ?4
+        WHERE id = ?5 AND status = 'Pending'
+        "#,
+    )
+    .bind(TaskProposalStatus::Accepted.to_string())
+    .bind(reviewer)
+    .bind(input.note)
+    .bind(Utc::now().timestamp())
+    .bind(proposal_id)
+    .execute(&mut *tx)
+    .await?;
+    if claimed.rows_affected() == 0 {
+        // Someone else reviewed it between our read and the claim.
+        return Err(lost_claim(&mut *tx, proposal_id).await);
+    }
+
+    let group_id: i64 = sqlx::query("SELECT id FROM task_group WHERE slug = ?1")
+        .bind(&group_slug)
+        .fetch_optional(&mut *tx)
+        .await?
+        .map(|row| row.get("id"))
+        .ok_or_else(|| TaskProposalError::IneligibleGroup(group_slug.clone()))?;
+
+    let title = input.title.unwrap_or_else(|| proposal.title.clone());
+    let description = input
+        .description
+        .unwrap_or_else(|| proposal.description.clone());
+    let base_slug = input
+        .slug
+        .map(|slug| slug.trim().to_string())
+        .filter(|slug| !slug.is_empty())
+        .unwrap_or_else(|| derive_slug(&group_slug, &title));
+
+    let mut slug = base_slug.clone();
+    let mut suffix = 2;
+    while sqlx::query("SELECT 1 FROM task WHERE slug = ?1")
+        .bind(&slug)
+        .fetch_optional(&mut *tx)
+        .await?
+        .is_some()
+    {
+        slug = format!("{base_slug}-{suffix}");
+        suffix += 1;
+    }
+
+    let task = task_db::create_task_with(
+        &mut *tx,
+        TaskCreateInput::new(
+            group_id,
+            slug,
+            title,
+            TaskStatus::Ready,
+            "orchestrator".to_string(),
+            description,
+        ),
+        origin,
+    )
+    .await?;
+
+    for dependency in &proposal.dependencies {
+        let depends_on = sqlx::query("SELECT id FROM task WHERE slug = ?1")
+            .bind(dependency)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(depends_on) = depends_on else {
+            warn!(
+                proposal_id,
+                dependency, "skipping unknown proposal dependency"
+            );
+            continue;
+        };
+        sqlx::query(
+            "INSERT OR IGNORE INTO task_deps (task_id, depends_on_task_id) VALUES (?1, ?2)",
+        )
+        .bind(task.id)
+        .bind(depends_on.get::<i64, _>("id"))
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let row = sqlx::query(
+        r#"
+        UPDATE task_proposal SET task_id = ?1
+        WHERE id = ?2
+        RETURNING id, proposer, source_task_slug, title, description, suggested_group, dependencies, status, reviewer, review_note, task_id, created_at, reviewed_at
+        "#,
+    )
+    .bind(task.id)
+    .bind(proposal_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    task_db::touch();
+    Ok(row_to_proposal(row))
+}
+
+pub async fn reject_proposal(
+    proposal_id: i64,
+    reviewer: &str,
+    note: Option<String>,
+) -> Result<TaskProposal, TaskProposalError> {
+    let proposal = get_proposal(proposal_id)
+        .await?
+        .ok_or(TaskProposalError::NotFound)?;
+    if proposal.status != TaskProposalStatus::Pending {
+        return Err(TaskProposalError::AlreadyReviewed(proposal.status));
+    }
+
+    let mut tx = db::pool().begin().await?;
+    let row = sqlx::query(
+        r#"
+        UPDATE task_proposal SET
+            status = ?1,
+            reviewer = ?2,
+            review_note = ?3,
+            reviewed_at = ?4
+        WHERE id = ?5 AND status = 'Pending'
+        RETURNING id, proposer, source_task_slug, title, description, suggested_group, dependencies, status, reviewer, review_note, task_id, created_at, reviewed_at
+        "#,
+    )
+    .bind(TaskProposalStatus::Rejected.to_string())
+    .bind(reviewer)
+    .bind(note)
+    .bind(Utc::now().timestamp())
+    .bind(proposal_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some(row) = row else {
+        return Err(lost_claim(&mut *tx, proposal_id).await);
+    };
+
+    tx.commit().await?;
+    Ok(row_to_proposal(row))
+}
+
+/// Error for a conditional claim that matched no row, using the status the
+/// competing reviewer left behind.
+async fn lost_claim(conn: &mut SqliteConnection, proposal_id: i64) -> TaskProposalError {
+    let row = sqlx::query("SELECT status FROM task_proposal WHERE id = ?1")
+        .bind(proposal_id)
+        .fetch_optional(conn)
+        .await;
+    match row {
+        Ok(Some(row)) => {
+            TaskProposalError::AlreadyReviewed(parse_status(&row.get::<String, _>("status")))
+        }
+        Ok(None) => TaskProposalError::NotFound,
+        Err(err) => TaskProposalError::Db(err),
+    }
+}
+
+fn derive_slug(group_slug: &str, title: &str) -> String {
+    let mut slug = String::new();
+    for ch in title.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+        if slug.len() >= 40 {
+            break;
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        format!("{group_slug}-proposal")
+    } else {
+        format!("{group_slug}-{slug}")
+    }
+}
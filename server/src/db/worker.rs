@@ -1,4 +1,4 @@
-use crate::{db, globals::PROJECT_DIR, shared::git};
+use crate::{db, globals::PROJECT_DIR, shared::git, system::skills};
 use openapi::models::{Worker, WorkerState};
 use std::{
     collections::HashSet,
@@ -46,11 +46,13 @@ pub async fn create_worker() -> Result<Worker, WorkerCreateError> {
     let staging_dir = project_dir.join("staging");
     let target = project_dir.join(format!("ws{next_id}"));
     git::create_worker_worktree(&staging_dir, &target, next_id)?;
+    db::task::touch();
     Ok(Worker {
         id: next_id,
         last_seen: 0,
         state: WorkerState::Ready,
         thread_id: None,
+        skills: Some(skills::provided_by(next_id)),
     })
 }
 
@@ -98,6 +100,7 @@ fn discover_workers() -> Result<Vec<Worker>, WorkerDiscoveryError> {
                         last_seen: 0,
                         state: WorkerState::Ready,
                         thread_id: None,
+                        skills: Some(skills::provided_by(worker_id)),
                     });
                 }
             }
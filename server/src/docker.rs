@@ -2,13 +2,14 @@ use crate::ai::schemas::{OrchestratorTurn, WorkerTurn, generated_schema_for};
 use crate::db::image_cache;
 use crate::globals::PROJECT_NAME;
 use crate::routes::config::CONFIG_DIR;
+use crate::system::container_runtime::{self, ContainerRuntime};
+use crate::system::worker_profiles;
 use schemars::JsonSchema;
 use sha2::{Digest, Sha256};
 use std::fs;
-use std::io::Write;
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tempfile::TempDir;
 use tracing::{debug, info};
 
@@ -26,6 +27,10 @@ pub static DOCKER_IMAGE_ORCHESTRATOR: LazyLock<String> = LazyLock::new(|| {
     format!("{DOCKER_IMAGE_PREFIX}-orchestrator_{proj_name}")
 });
 
+/// Set once [`make_worker_image`] has run, so profile changes made through the API
+/// rebuild images only when this process manages them.
+static IMAGES_MANAGED: AtomicBool = AtomicBool::new(false);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ImageType {
     Worker,
@@ -41,8 +46,19 @@ impl ImageType {
     }
 }
 
-/// Creates 3 docker images, one for each Codex executor
+/// Creates 3 docker images, one for each Codex executor, plus one per worker
+/// profile. The host runtime skips images entirely and only writes the response
+/// schemas to disk.
 pub async fn make_worker_image() {
+    IMAGES_MANAGED.store(true, Ordering::Relaxed);
+    let runtime = container_runtime::current();
+    if !runtime.uses_images() {
+        info!("Host runtime selected; writing schemas instead of building images");
+        write_host_schema::<OrchestratorTurn>(DOCKER_IMAGE_ORCHESTRATOR.as_str());
+        write_host_schema::<WorkerTurn>(DOCKER_IMAGE_WORKER.as_str());
+        make_profile_images().await;
+        return;
+    }
     let p = PathBuf::from(format!("{}/Dockerfile", CONFIG_DIR.as_str()));
     if !p.exists() {
         if let Some(parent) = p.parent() {
@@ -63,30 +79,63 @@ pub async fn make_worker_image() {
     let concatenated_hash = hash_content(&concatenated);
     let wizard_hash = hash_content(DOCKER_WIZARD);
 
-    if should_build_image(orch_image, &concatenated_hash).await {
+    if should_build_image(runtime, orch_image, &concatenated_hash).await {
         info!("Creating Orchestrator image");
         generate_response_schema::<OrchestratorTurn>(&tmp);
-        run_docker_build(tmp.path(), &concatenated, orch_image);
+        run_image_build(runtime, tmp.path(), &concatenated, orch_image);
         store_hash(orch_image, &concatenated_hash).await;
     } else {
         info!("Orchestrator image up-to-date; skipping build");
     }
-    if should_build_image(work_image, &concatenated_hash).await {
+    if should_build_image(runtime, work_image, &concatenated_hash).await {
         info!("Creating Worker image");
         generate_response_schema::<WorkerTurn>(&tmp);
-        run_docker_build(tmp.path(), &concatenated, work_image);
+        run_image_build(runtime, tmp.path(), &concatenated, work_image);
         store_hash(work_image, &concatenated_hash).await;
     } else {
         info!("Worker image up-to-date; skipping build");
     }
-    if should_build_image(DOCKER_IMAGE_WIZARD, &wizard_hash).await {
+    make_profile_images().await;
+    if should_build_image(runtime, DOCKER_IMAGE_WIZARD, &wizard_hash).await {
         info!("Creating Wizard image");
-        run_docker_build(tmp.path(), DOCKER_WIZARD, DOCKER_IMAGE_WIZARD);
+        run_image_build(runtime, tmp.path(), DOCKER_WIZARD, DOCKER_IMAGE_WIZARD);
         store_hash(DOCKER_IMAGE_WIZARD, &wizard_hash).await;
     } else {
         info!("Wizard image up-to-date; skipping build");
     }
 }
+/// Build (or, under the host runtime, write the schema for) every worker profile
+/// image that is missing or stale. Called at startup and again whenever the config
+/// API changes `worker_profiles`; does nothing if images are not managed here.
+pub async fn make_profile_images() {
+    if !IMAGES_MANAGED.load(Ordering::Relaxed) {
+        return;
+    }
+    let runtime = container_runtime::current();
+    let profiles = worker_profiles::snapshot();
+    if !runtime.uses_images() {
+        for profile in &profiles {
+            write_host_schema::<WorkerTurn>(&worker_profiles::image_for(profile));
+        }
+        return;
+    }
+    let tmp = TempDir::new().unwrap_or_else(|_| panic!("failed to create temporary directory"));
+    for profile in &profiles {
+        let tag = worker_profiles::image_for(profile);
+        let fragment = worker_profiles::dockerfile_for(profile);
+        let dockerfile = combine_dockerfiles(&fragment.display().to_string());
+        let hash = hash_content(&dockerfile);
+        if should_build_image(runtime, &tag, &hash).await {
+            info!(profile = %profile.name, "Creating worker profile image");
+            generate_response_schema::<WorkerTurn>(&tmp);
+            run_image_build(runtime, tmp.path(), &dockerfile, &tag);
+            store_hash(&tag, &hash).await;
+        } else {
+            info!(profile = %profile.name, "Worker profile image up-to-date; skipping build");
+        }
+    }
+}
+
 fn write_schema_file(tmp: &TempDir, schema: schemars::Schema) -> Result<(), anyhow::Error> {
     let bytes = serde_json::to_vec_pretty(&schema)?;
 
@@ -106,39 +155,28 @@ fn generate_response_schema<T: JsonSchema>(tmp: &TempDir) {
         .unwrap_or_else(|_| panic!("failed to write schema file"));
 }
 
-fn run_docker_build(tmp_dir: &std::path::Path, dockerfile: &str, tag: &str) {
-    use std::process::{Command, Stdio};
-
-    let mut child = Command::new("docker")
-        .args([
-            "build",
-            "--tag",
-            tag,
-            "--build-arg",
-            "UID=1000",
-            "--build-arg",
-            "GID=1000",
-            "-f",
-            "-",
-            tmp_dir.to_str().unwrap(),
-        ])
-        .stdin(Stdio::piped())
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap_or_else(|_| panic!("failed to spawn docker build process"));
-
-    {
-        let stdin = child.stdin.as_mut().expect("docker build missing stdin");
-        stdin
-            .write_all(dockerfile.as_bytes())
-            .expect("failed to write dockerfile to stdin");
-    }
+fn run_image_build(runtime: &dyn ContainerRuntime, tmp_dir: &Path, dockerfile: &str, tag: &str) {
+    runtime
+        .build_image(tmp_dir, dockerfile, tag)
+        .unwrap_or_else(|err| panic!("{} build for tag {tag} failed: {err}", runtime.name()));
+}
 
-    let status = child.wait().expect("failed to wait on docker build");
-    if !status.success() {
-        panic!("docker build for tag {tag} failed with {status}");
+/// Schema file passed to `--output-schema` when agents run on the host instead of
+/// inside `image`.
+pub fn host_schema_path(image: &str) -> PathBuf {
+    PathBuf::from(format!("{}/schemas/{image}.json", CONFIG_DIR.as_str()))
+}
+
+fn write_host_schema<T: JsonSchema>(image: &str) {
+    let path = host_schema_path(image);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .unwrap_or_else(|err| panic!("failed to create {}: {err}", parent.display()));
     }
+    let bytes = serde_json::to_vec_pretty(&generated_schema_for::<T>())
+        .unwrap_or_else(|_| panic!("failed to serialize schema for {image}"));
+    fs::write(&path, bytes)
+        .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
 }
 
 pub fn combine_dockerfiles(path: &str) -> String {
@@ -177,19 +215,9 @@ fn hash_content(content: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
-fn docker_image_exists(tag: &str) -> bool {
-    Command::new("docker")
-        .args(["image", "inspect", tag])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
-}
-
-async fn should_build_image(tag: &str, hash: &str) -> bool {
+async fn should_build_image(runtime: &dyn ContainerRuntime, tag: &str, hash: &str) -> bool {
     let cached = image_cache::get_hash(tag).await.ok().flatten();
-    let exists = docker_image_exists(tag);
+    let exists = runtime.image_exists(tag);
     match cached {
         Some(stored) if stored == hash && exists => {
             debug!(tag, "image matches cached hash; build skipped");
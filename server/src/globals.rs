@@ -1,9 +1,22 @@
 use std::fs;
-use std::path::PathBuf;
-use std::sync::LazyLock;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, OnceLock};
 
-pub static PROJECT_DIR: LazyLock<String> =
-    LazyLock::new(|| std::env::current_dir().unwrap().display().to_string());
+static PROJECT_DIR_CELL: OnceLock<String> = OnceLock::new();
+
+pub static PROJECT_DIR: LazyLock<String> = LazyLock::new(|| {
+    PROJECT_DIR_CELL
+        .get_or_init(|| std::env::current_dir().unwrap().display().to_string())
+        .clone()
+});
+
+/// Points `PROJECT_DIR` at `path` instead of the working directory. Only takes
+/// effect before the first read of `PROJECT_DIR`; returns false otherwise.
+pub fn set_project_dir(path: impl AsRef<Path>) -> bool {
+    PROJECT_DIR_CELL
+        .set(path.as_ref().display().to_string())
+        .is_ok()
+}
 
 pub static PROJECT_NAME: LazyLock<String> = LazyLock::new(|| {
     let p = PathBuf::from(PROJECT_DIR.as_str());
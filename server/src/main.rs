@@ -5,6 +5,7 @@ use axum::serve;
 use std::env;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::net::TcpListener;
 use tracing::{info, warn};
 
@@ -56,7 +57,16 @@ async fn main() -> Result<(), anyhow::Error> {
     system::init_system_state();
     realtime::init();
 
-    make_worker_image().await;
+    // Replay recorded agent turns instead of running Codex in Docker (tests, demos).
+    match env::var("ROBOT_FARM_AGENT_FIXTURES") {
+        Ok(dir) => {
+            let launcher = system::mock_agent::ScriptedLauncher::from_fixture_dir(Path::new(&dir))
+                .map_err(|err| anyhow!("failed to load agent fixtures from {dir}: {err}"))?;
+            system::launcher::install(Arc::new(launcher));
+            info!("replaying scripted agent turns from {dir}");
+        }
+        Err(_) => make_worker_image().await,
+    }
     threads::init_background_threads();
     let app: Router = routes::build_routes();
     let port: u16 = env::var("PORT")
@@ -12,9 +12,9 @@ use thiserror::Error;
 
 use crate::{
     db::{task as task_db, task_group as task_group_db},
-    system::strategy::StrategyState,
+    system::strategy::{self, StrategyState},
 };
-use openapi::models::{ActiveStrategy, Strategy, Task, TaskGroup, TaskGroupStatus, TaskStatus};
+use openapi::models::{ActiveStrategy, Task, TaskGroup, TaskGroupStatus, TaskStatus};
 
 pub(crate) mod session;
 
@@ -23,6 +23,9 @@ mod git_status;
 mod project_command_list;
 mod project_command_run;
 pub(crate) mod project_commands;
+mod task_proposals_accept;
+mod task_proposals_list;
+mod task_proposals_reject;
 mod tasks_create;
 mod tasks_create_group;
 mod tasks_delete;
@@ -33,7 +36,9 @@ mod tasks_groups_delete;
 mod tasks_groups_get;
 mod tasks_groups_list;
 mod tasks_groups_update;
+mod tasks_history;
 mod tasks_list;
+mod tasks_propose;
 mod tasks_set_status;
 mod tasks_update;
 
@@ -50,6 +55,7 @@ static TOOL_REGISTRY: Lazy<Vec<Arc<dyn McpTool>>> = Lazy::new(|| {
         Arc::new(git_diff::GitDiffTool::default()),
         Arc::new(tasks_list::TasksListTool::default()),
         Arc::new(tasks_get::TasksGetTool::default()),
+        Arc::new(tasks_history::TasksHistoryTool::default()),
         Arc::new(tasks_create::TasksCreateTool::default()),
         Arc::new(tasks_update::TasksUpdateTool::default()),
         Arc::new(tasks_delete::TasksDeleteTool::default()),
@@ -61,6 +67,10 @@ static TOOL_REGISTRY: Lazy<Vec<Arc<dyn McpTool>>> = Lazy::new(|| {
         Arc::new(tasks_groups_delete::TaskGroupsDeleteTool::default()),
         Arc::new(tasks_dependencies_get::TasksDependenciesGetTool::default()),
         Arc::new(tasks_dependencies_set::TasksDependenciesSetTool::default()),
+        Arc::new(tasks_propose::TasksProposeTool::default()),
+        Arc::new(task_proposals_list::TaskProposalsListTool::default()),
+        Arc::new(task_proposals_accept::TaskProposalsAcceptTool::default()),
+        Arc::new(task_proposals_reject::TaskProposalsRejectTool::default()),
         Arc::new(project_command_list::ProjectCommandListTool::default()),
         Arc::new(project_command_run::ProjectCommandRunTool::default()),
     ]
@@ -84,30 +94,6 @@ pub enum AgentRole {
 }
 
 impl Agent {
-    pub fn parse(raw: &str) -> Result<Self, AgentHeaderError> {
-        let value = raw.trim();
-        if value.is_empty() {
-            return Err(AgentHeaderError::Missing);
-        }
-        let lower = value.to_ascii_lowercase();
-        match lower.as_str() {
-            "orchestrator" => Ok(Agent::Orchestrator),
-            "worker" => Ok(Agent::Worker),
-            "qa" => Ok(Agent::Qa),
-            "wizard" => Ok(Agent::Wizard),
-            _ => {
-                if let Some(id_str) = lower.strip_prefix("ws") {
-                    let id: i64 = id_str
-                        .parse()
-                        .map_err(|_| AgentHeaderError::Invalid(value.to_string()))?;
-                    Ok(Agent::WorkerWithId(id))
-                } else {
-                    Err(AgentHeaderError::Invalid(value.to_string()))
-                }
-            }
-        }
-    }
-
     pub fn role(&self) -> AgentRole {
         match self {
             Agent::Orchestrator => AgentRole::Orchestrator,
@@ -129,11 +115,13 @@ impl Agent {
 }
 
 #[derive(Debug, Error)]
-pub enum AgentHeaderError {
-    #[error("missing AGENT header")]
+pub enum McpAuthError {
+    #[error("missing Authorization bearer token")]
     Missing,
-    #[error("invalid AGENT header: {0}")]
-    Invalid(String),
+    #[error("malformed Authorization header")]
+    Malformed,
+    #[error("unknown or expired MCP token")]
+    Unknown,
 }
 
 #[derive(Clone, Debug)]
@@ -147,8 +135,14 @@ impl ToolContext {
         self.agent.role()
     }
 
-    pub fn is_planning(&self) -> bool {
-        matches!(self.strategy.id, Strategy::Planning)
+    pub fn allows_task_editing(&self) -> bool {
+        strategy::resolve(&self.strategy.id).task_editing
+    }
+
+    /// Strategies may restrict which tools the orchestrator can use.
+    pub fn allows_tool(&self, name: &str) -> bool {
+        self.role() != AgentRole::Orchestrator
+            || strategy::resolve(&self.strategy.id).allows_tool(name)
     }
 }
 
@@ -158,6 +152,8 @@ pub struct TaskGroupSummary {
     pub slug: String,
     pub title: String,
     pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub required_skills: Vec<String>,
 }
 
 impl From<TaskGroup> for TaskGroupSummary {
@@ -167,6 +163,7 @@ impl From<TaskGroup> for TaskGroupSummary {
             slug: value.slug,
             title: value.title,
             description: value.description,
+            required_skills: value.required_skills.unwrap_or_default(),
         }
     }
 }
@@ -267,6 +264,10 @@ impl ToolInvocationError {
     }
 }
 
+pub fn is_known_tool(name: &str) -> bool {
+    TOOL_REGISTRY.iter().any(|tool| tool.name() == name)
+}
+
 fn is_task_mutation_tool(name: &str) -> bool {
     TASK_MUTATION_TOOLS.iter().any(|tool| tool == &name)
 }
@@ -274,9 +275,10 @@ fn is_task_mutation_tool(name: &str) -> bool {
 fn mutation_denied_message(ctx: &ToolContext) -> Option<String> {
     match ctx.role() {
         AgentRole::Worker => Some("Workers are not allowed to edit tasks.".to_string()),
-        AgentRole::Orchestrator if !ctx.is_planning() => {
-            Some("You may only use this tool when PLANNING is the active strategy.".to_string())
-        }
+        AgentRole::Orchestrator if !ctx.allows_task_editing() => Some(
+            "You may only use this tool when the active strategy allows task editing (e.g. PLANNING)."
+                .to_string(),
+        ),
         _ => None,
     }
 }
@@ -316,6 +318,8 @@ const ROLES_ALL: &[AgentRole] = &[
 ];
 const ROLES_COORDINATION: &[AgentRole] =
     &[AgentRole::Orchestrator, AgentRole::Qa, AgentRole::Wizard];
+const ROLES_WORKER: &[AgentRole] = &[AgentRole::Worker];
+const ROLES_REVIEW: &[AgentRole] = &[AgentRole::Orchestrator, AgentRole::Qa];
 
 const TASK_MUTATION_TOOLS: &[&str] = &[
     "tasks_create",
@@ -326,6 +330,8 @@ const TASK_MUTATION_TOOLS: &[&str] = &[
     "task_groups_update",
     "task_groups_delete",
     "tasks_dependencies_set",
+    "task_proposals_accept",
+    "task_proposals_reject",
 ];
 
 pub async fn handle_http_request(
@@ -517,7 +523,7 @@ fn handle_initialize(id: Option<Value>) -> Value {
             "capabilities": {
                 "tools": { "listChanged": false },
             },
-            "instructions": "Use Robot Farm MCP tools via /mcp. Provide the AGENT header (orchestrator, wizard, qa, worker, wsN).",
+            "instructions": "Use Robot Farm MCP tools via /mcp. Authenticate with the per-run bearer token issued to your session.",
         }),
     )
 }
@@ -525,7 +531,7 @@ fn handle_initialize(id: Option<Value>) -> Value {
 fn handle_tools_list(ctx: &ToolContext, id: Option<Value>) -> Value {
     let tools: Vec<Value> = TOOL_REGISTRY
         .iter()
-        .filter(|tool| tool.is_visible(ctx))
+        .filter(|tool| tool.is_visible(ctx) && ctx.allows_tool(tool.name()))
         .map(|tool| {
             let mut entry = json!({
                 "name": tool.name(),
@@ -578,6 +584,15 @@ async fn handle_tools_call(ctx: &ToolContext, id: Option<Value>, params: Option<
         return make_error_response(id, -32001, message, None);
     }
 
+    if !ctx.allows_tool(tool.name()) {
+        let message = format!(
+            "{} is not permitted under the {} strategy",
+            tool.name(),
+            ctx.strategy.id
+        );
+        return make_error_response(id, -32001, message, None);
+    }
+
     match tool.call(ctx, params.arguments).await {
         Ok(result) => make_result_response(
             id,
@@ -792,6 +807,14 @@ pub const fn roles_coordination() -> &'static [AgentRole] {
     ROLES_COORDINATION
 }
 
+pub const fn roles_worker() -> &'static [AgentRole] {
+    ROLES_WORKER
+}
+
+pub const fn roles_review() -> &'static [AgentRole] {
+    ROLES_REVIEW
+}
+
 pub fn schema_for_type<T: JsonSchema>() -> Value {
     let (value, report) = strict_value_for_type::<T>();
     debug_assert!(
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use openapi::models::TaskProposalAcceptInput;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::db::task_history::TaskChangeOrigin;
+use crate::db::task_proposal::{self as proposal_db, TaskProposalError};
+use crate::system::{events::SystemActor, queue::QueueCoordinator};
+
+use super::{
+    AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse,
+    ensure_task_mutation_allowed, parse_params, require_task_by_id, roles_review, schema_for_type,
+    serialize_json, summarize_task,
+};
+
+#[derive(Default)]
+pub struct TaskProposalsAcceptTool;
+
+#[async_trait]
+impl McpTool for TaskProposalsAcceptTool {
+    fn name(&self) -> &'static str {
+        "task_proposals_accept"
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Accept Task Proposal")
+    }
+
+    fn description(&self) -> &'static str {
+        "Accept a worker proposal, creating a READY task in `chores` or `bugs` (orchestrator restricted to planning)."
+    }
+
+    fn input_schema(&self) -> Value {
+        schema_for_type::<TaskProposalsAcceptPayload>()
+    }
+
+    fn allowed_roles(&self) -> &'static [AgentRole] {
+        roles_review()
+    }
+
+    fn is_visible(&self, ctx: &ToolContext) -> bool {
+        match ctx.role() {
+            AgentRole::Qa => true,
+            AgentRole::Orchestrator => ctx.allows_task_editing(),
+            AgentRole::Worker | AgentRole::Wizard => false,
+        }
+    }
+
+    async fn call(
+        &self,
+        ctx: &ToolContext,
+        args: Value,
+    ) -> Result<ToolInvocationResponse, ToolInvocationError> {
+        ensure_task_mutation_allowed(ctx)?;
+        let input: TaskProposalsAcceptPayload = parse_params(args)?;
+        let mut accept = TaskProposalAcceptInput::new();
+        accept.group_slug = input.group_slug;
+        accept.slug = input.slug;
+        accept.title = input.title;
+        accept.description = input.description;
+        accept.note = input.note;
+
+        let origin = TaskChangeOrigin::mcp(ctx.agent.label(), self.name());
+        let proposal =
+            proposal_db::accept_proposal(input.proposal_id, &ctx.agent.label(), accept, &origin)
+                .await
+                .map_err(proposal_tool_error)?;
+        let task_id = proposal
+            .task_id
+            .ok_or_else(|| ToolInvocationError::Internal("accepted proposal has no task".into()))?;
+        let task = summarize_task(require_task_by_id(task_id).await?).await?;
+
+        let reviewer = SystemActor::from_label(&ctx.agent.label()).unwrap_or(SystemActor::System);
+        QueueCoordinator::global().proposal_reviewed(reviewer, &proposal);
+
+        let text = serialize_json(&json!({ "proposal": proposal, "task": task }))?;
+        Ok(ToolInvocationResponse::text(text))
+    }
+}
+
+pub(super) fn proposal_tool_error(err: TaskProposalError) -> ToolInvocationError {
+    match err {
+        TaskProposalError::NotFound => ToolInvocationError::NotFound(err.to_string()),
+        TaskProposalError::AlreadyReviewed(_) | TaskProposalError::IneligibleGroup(_) => {
+            ToolInvocationError::InvalidParams(err.to_string())
+        }
+        TaskProposalError::Db(err) => ToolInvocationError::Internal(err.to_string()),
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[schemars(description = "Accept a task proposal, optionally adjusting the created task.")]
+struct TaskProposalsAcceptPayload {
+    /// Identifier of the proposal to accept.
+    pub proposal_id: i64,
+    /// Destination group (`chores` or `bugs`); defaults to the suggested group.
+    pub group_slug: Option<String>,
+    /// Slug for the created task; derived from the title when omitted.
+    pub slug: Option<String>,
+    /// Replacement title for the created task.
+    pub title: Option<String>,
+    /// Replacement description for the created task.
+    pub description: Option<String>,
+    /// Review note stored with the proposal.
+    pub note: Option<String>,
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use openapi::models::TaskProposalStatus;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::db::task_proposal::{self as proposal_db, TaskProposalFilters};
+
+use super::{
+    AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse, parse_params,
+    roles_review, schema_for_type, serialize_json,
+};
+
+#[derive(Default)]
+pub struct TaskProposalsListTool;
+
+#[async_trait]
+impl McpTool for TaskProposalsListTool {
+    fn name(&self) -> &'static str {
+        "task_proposals_list"
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("List Task Proposals")
+    }
+
+    fn description(&self) -> &'static str {
+        "List follow-up tasks proposed by workers (defaults to pending proposals)."
+    }
+
+    fn input_schema(&self) -> Value {
+        schema_for_type::<TaskProposalsListInput>()
+    }
+
+    fn allowed_roles(&self) -> &'static [AgentRole] {
+        roles_review()
+    }
+
+    async fn call(
+        &self,
+        _ctx: &ToolContext,
+        args: Value,
+    ) -> Result<ToolInvocationResponse, ToolInvocationError> {
+        let input: TaskProposalsListInput = parse_params(args)?;
+        let status = match input.status.as_deref().map(str::trim) {
+            None | Some("") => Some(TaskProposalStatus::Pending),
+            Some(value) => parse_proposal_status(value)?,
+        };
+        let proposals = proposal_db::list_proposals(TaskProposalFilters {
+            status,
+            proposer: input.proposer,
+        })
+        .await
+        .map_err(|err| ToolInvocationError::Internal(err.to_string()))?;
+        let text = serialize_json(&json!({ "proposals": proposals }))?;
+        Ok(ToolInvocationResponse::text(text))
+    }
+}
+
+fn parse_proposal_status(raw: &str) -> Result<Option<TaskProposalStatus>, ToolInvocationError> {
+    match raw.to_ascii_lowercase().as_str() {
+        "all" => Ok(None),
+        "pending" => Ok(Some(TaskProposalStatus::Pending)),
+        "accepted" => Ok(Some(TaskProposalStatus::Accepted)),
+        "rejected" => Ok(Some(TaskProposalStatus::Rejected)),
+        other => Err(ToolInvocationError::InvalidParams(format!(
+            "unknown proposal status: {other}"
+        ))),
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[schemars(description = "Filters for listing task proposals.")]
+struct TaskProposalsListInput {
+    /// PENDING (default), ACCEPTED, REJECTED or ALL.
+    pub status: Option<String>,
+    /// Only include proposals filed by this worker (e.g., ws3).
+    pub proposer: Option<String>,
+}
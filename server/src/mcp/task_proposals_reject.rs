@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::db::task_proposal as proposal_db;
+use crate::system::{events::SystemActor, queue::QueueCoordinator};
+
+use super::{
+    AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse,
+    ensure_task_mutation_allowed, parse_params, roles_review, schema_for_type, serialize_json,
+    task_proposals_accept::proposal_tool_error,
+};
+
+#[derive(Default)]
+pub struct TaskProposalsRejectTool;
+
+#[async_trait]
+impl McpTool for TaskProposalsRejectTool {
+    fn name(&self) -> &'static str {
+        "task_proposals_reject"
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Reject Task Proposal")
+    }
+
+    fn description(&self) -> &'static str {
+        "Reject a worker proposal with an optional note (orchestrator restricted to planning)."
+    }
+
+    fn input_schema(&self) -> Value {
+        schema_for_type::<TaskProposalsRejectPayload>()
+    }
+
+    fn allowed_roles(&self) -> &'static [AgentRole] {
+        roles_review()
+    }
+
+    fn is_visible(&self, ctx: &ToolContext) -> bool {
+        match ctx.role() {
+            AgentRole::Qa => true,
+            AgentRole::Orchestrator => ctx.allows_task_editing(),
+            AgentRole::Worker | AgentRole::Wizard => false,
+        }
+    }
+
+    async fn call(
+        &self,
+        ctx: &ToolContext,
+        args: Value,
+    ) -> Result<ToolInvocationResponse, ToolInvocationError> {
+        ensure_task_mutation_allowed(ctx)?;
+        let input: TaskProposalsRejectPayload = parse_params(args)?;
+        let proposal =
+            proposal_db::reject_proposal(input.proposal_id, &ctx.agent.label(), input.note)
+                .await
+                .map_err(proposal_tool_error)?;
+
+        let reviewer = SystemActor::from_label(&ctx.agent.label()).unwrap_or(SystemActor::System);
+        QueueCoordinator::global().proposal_reviewed(reviewer, &proposal);
+
+        let text = serialize_json(&json!({ "proposal": proposal }))?;
+        Ok(ToolInvocationResponse::text(text))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[schemars(description = "Reject a task proposal.")]
+struct TaskProposalsRejectPayload {
+    /// Identifier of the proposal to reject.
+    pub proposal_id: i64,
+    /// Reason for rejecting the proposal.
+    pub note: Option<String>,
+}
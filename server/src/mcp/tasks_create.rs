@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::{Value, json};
 
-use crate::db::task as task_db;
+use crate::db::{task as task_db, task_history::TaskChangeOrigin};
 
 use super::{
     AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse,
@@ -40,7 +40,7 @@ impl McpTool for TasksCreateTool {
     fn is_visible(&self, ctx: &ToolContext) -> bool {
         match ctx.role() {
             AgentRole::Wizard | AgentRole::Qa => true,
-            AgentRole::Orchestrator => ctx.is_planning(),
+            AgentRole::Orchestrator => ctx.allows_task_editing(),
             AgentRole::Worker => false,
         }
     }
@@ -67,7 +67,10 @@ impl McpTool for TasksCreateTool {
             input.description,
         );
         payload.commit_hash = input.commit_hash;
-        let created = task_db::create_task(payload)
+        payload.priority = input.priority;
+        payload.required_skills = input.required_skills;
+        let origin = TaskChangeOrigin::mcp(ctx.agent.label(), self.name());
+        let created = task_db::create_task(payload, &origin)
             .await
             .map_err(|err| ToolInvocationError::Internal(err.to_string()))?;
         let summary = summarize_task(created).await?;
@@ -93,4 +96,8 @@ struct TasksCreateInputPayload {
     pub owner: Option<String>,
     /// Detailed task description and acceptance notes.
     pub description: String,
+    /// Scheduling priority (higher is assigned first, defaults to 0).
+    pub priority: Option<i64>,
+    /// Skills a worker must provide to take this task (e.g. "flutter").
+    pub required_skills: Option<Vec<String>>,
 }
@@ -39,7 +39,7 @@ impl McpTool for TaskGroupsCreateTool {
     fn is_visible(&self, ctx: &ToolContext) -> bool {
         match ctx.role() {
             AgentRole::Wizard | AgentRole::Qa => true,
-            AgentRole::Orchestrator => ctx.is_planning(),
+            AgentRole::Orchestrator => ctx.allows_task_editing(),
             AgentRole::Worker => false,
         }
     }
@@ -51,7 +51,8 @@ impl McpTool for TaskGroupsCreateTool {
     ) -> Result<ToolInvocationResponse, ToolInvocationError> {
         ensure_task_mutation_allowed(ctx)?;
         let input: TaskGroupsCreateInput = parse_params(args)?;
-        let payload = TaskGroupCreateInput::new(input.slug, input.title, input.description);
+        let mut payload = TaskGroupCreateInput::new(input.slug, input.title, input.description);
+        payload.required_skills = input.required_skills;
         let created = task_group_db::create_task_group(payload)
             .await
             .map_err(|err| ToolInvocationError::Internal(err.to_string()))?;
@@ -70,4 +71,6 @@ struct TaskGroupsCreateInput {
     pub title: String,
     /// Description and scope for the group.
     pub description: String,
+    /// Skills every task in the group requires (e.g. "flutter").
+    pub required_skills: Option<Vec<String>>,
 }
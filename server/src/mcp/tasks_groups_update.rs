@@ -56,6 +56,10 @@ impl McpTool for TaskGroupsUpdateTool {
             payload.description = Some(description);
             touched = true;
         }
+        if let Some(skills) = input.required_skills {
+            payload.required_skills = Some(skills);
+            touched = true;
+        }
 
         if !touched {
             return Err(ToolInvocationError::InvalidParams(
@@ -84,4 +88,6 @@ struct TaskGroupsUpdateInput {
     pub title: Option<String>,
     /// Optional new description.
     pub description: Option<String>,
+    /// Optional replacement list of skills every task in the group requires.
+    pub required_skills: Option<Vec<String>>,
 }
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::db::task_history;
+
+use super::{
+    AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse, parse_params,
+    require_task_by_slug, require_visible_task_by_slug, roles_all, schema_for_type, serialize_json,
+};
+
+#[derive(Default)]
+pub struct TasksHistoryTool;
+
+#[async_trait]
+impl McpTool for TasksHistoryTool {
+    fn name(&self) -> &'static str {
+        "tasks_history"
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Task History")
+    }
+
+    fn description(&self) -> &'static str {
+        "List every recorded field change on a task (status, owner, description, ...), oldest first, with who made it and through which tool. Use it to see prior attempts before reassigning a task."
+    }
+
+    fn input_schema(&self) -> Value {
+        schema_for_type::<TasksHistoryInput>()
+    }
+
+    fn allowed_roles(&self) -> &'static [AgentRole] {
+        roles_all()
+    }
+
+    async fn call(
+        &self,
+        ctx: &ToolContext,
+        args: Value,
+    ) -> Result<ToolInvocationResponse, ToolInvocationError> {
+        let input: TasksHistoryInput = parse_params(args)?;
+        // Workers only see the history of their own tasks.
+        let task = match ctx.role() {
+            AgentRole::Worker => require_visible_task_by_slug(ctx, &input.slug).await?,
+            _ => require_task_by_slug(&input.slug).await?,
+        };
+        let history = task_history::list_for_task(task.id)
+            .await
+            .map_err(|err| ToolInvocationError::Internal(err.to_string()))?;
+        let text = serialize_json(&json!({ "task": task.slug, "history": history }))?;
+        Ok(ToolInvocationResponse::text(text))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[schemars(description = "Lookup parameters for a task's change history.")]
+struct TasksHistoryInput {
+    /// Slug of the task whose history to fetch.
+    pub slug: String,
+}
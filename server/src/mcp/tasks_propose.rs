@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::db::task_proposal::{self as proposal_db, NewTaskProposal};
+use crate::system::queue::QueueCoordinator;
+
+use super::{
+    Agent, AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse,
+    parse_params, require_task_by_slug, roles_worker, schema_for_type, serialize_json,
+};
+
+#[derive(Default)]
+pub struct TasksProposeTool;
+
+#[async_trait]
+impl McpTool for TasksProposeTool {
+    fn name(&self) -> &'static str {
+        "tasks_propose"
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Propose Follow-up Task")
+    }
+
+    fn description(&self) -> &'static str {
+        "File a follow-up task (bug or chore outside your assignment) into the review inbox. Does not change your current task."
+    }
+
+    fn input_schema(&self) -> Value {
+        schema_for_type::<TasksProposeInput>()
+    }
+
+    fn allowed_roles(&self) -> &'static [AgentRole] {
+        roles_worker()
+    }
+
+    async fn call(
+        &self,
+        ctx: &ToolContext,
+        args: Value,
+    ) -> Result<ToolInvocationResponse, ToolInvocationError> {
+        let input: TasksProposeInput = parse_params(args)?;
+        let title = input.title.trim().to_string();
+        if title.is_empty() {
+            return Err(ToolInvocationError::InvalidParams(
+                "title must not be empty".to_string(),
+            ));
+        }
+        let suggested_group = input.suggested_group.trim().to_ascii_lowercase();
+        if !proposal_db::is_proposal_group(&suggested_group) {
+            return Err(ToolInvocationError::InvalidParams(format!(
+                "suggested_group must be one of: {}",
+                proposal_db::PROPOSAL_GROUPS.join(", ")
+            )));
+        }
+
+        let dependencies = input.dependencies.unwrap_or_default();
+        for slug in &dependencies {
+            require_task_by_slug(slug).await?;
+        }
+
+        let worker_id = match ctx.agent {
+            Agent::WorkerWithId(id) => Some(id),
+            _ => None,
+        };
+        let source_task_slug = worker_id
+            .and_then(|id| QueueCoordinator::global().assigned_task(id))
+            .and_then(|assignment| assignment.slug);
+
+        let proposal = proposal_db::create_proposal(NewTaskProposal {
+            proposer: ctx.agent.label(),
+            source_task_slug,
+            title,
+            description: input.description,
+            suggested_group,
+            dependencies,
+        })
+        .await
+        .map_err(|err| ToolInvocationError::Internal(err.to_string()))?;
+
+        QueueCoordinator::global().proposal_filed(&proposal);
+
+        let text = serialize_json(&json!({
+            "proposal": proposal,
+            "note": "Proposal filed for review. Continue with your assigned task.",
+        }))?;
+        Ok(ToolInvocationResponse::text(text))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[schemars(description = "Parameters for proposing a follow-up task for review.")]
+struct TasksProposeInput {
+    /// Short title for the proposed task.
+    pub title: String,
+    /// What is wrong or missing, where it lives, and how to verify the fix.
+    pub description: String,
+    /// Group the task belongs in: `bugs` or `chores`.
+    pub suggested_group: String,
+    /// Slugs of existing tasks that must be completed first.
+    pub dependencies: Option<Vec<String>>,
+}
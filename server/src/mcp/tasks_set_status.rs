@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::{Value, json};
 
-use crate::db::task as task_db;
+use crate::db::{task as task_db, task_history::TaskChangeOrigin};
 
 use super::{
     AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse,
@@ -46,7 +46,8 @@ impl McpTool for TasksSetStatusTool {
         if let Some(owner) = input.owner {
             update.owner = Some(owner);
         }
-        let updated = task_db::update_task(task.id, update)
+        let origin = TaskChangeOrigin::mcp(ctx.agent.label(), self.name());
+        let updated = task_db::update_task(task.id, update, &origin)
             .await
             .map_err(|err| ToolInvocationError::Internal(err.to_string()))?
             .ok_or_else(|| ToolInvocationError::NotFound(task.slug))?;
@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::{Value, json};
 
-use crate::db::task as task_db;
+use crate::db::{task as task_db, task_history::TaskChangeOrigin};
 
 use super::{
     AgentRole, McpTool, ToolContext, ToolInvocationError, ToolInvocationResponse,
@@ -77,6 +77,14 @@ impl McpTool for TasksUpdateTool {
             payload.description = Some(description);
             touched = true;
         }
+        if let Some(priority) = input.priority {
+            payload.priority = Some(priority);
+            touched = true;
+        }
+        if let Some(skills) = input.required_skills {
+            payload.required_skills = Some(skills);
+            touched = true;
+        }
 
         if !touched {
             return Err(ToolInvocationError::InvalidParams(
@@ -84,7 +92,8 @@ impl McpTool for TasksUpdateTool {
             ));
         }
 
-        let updated = task_db::update_task(existing.id, payload)
+        let origin = TaskChangeOrigin::mcp(ctx.agent.label(), self.name());
+        let updated = task_db::update_task(existing.id, payload, &origin)
             .await
             .map_err(|err| ToolInvocationError::Internal(err.to_string()))?
             .ok_or_else(|| ToolInvocationError::NotFound(existing.slug.clone()))?;
@@ -113,4 +122,8 @@ struct TasksUpdateInputPayload {
     pub owner: Option<String>,
     /// Optional updated description.
     pub description: Option<String>,
+    /// Optional scheduling priority (higher is assigned first).
+    pub priority: Option<i64>,
+    /// Optional replacement list of skills a worker must provide.
+    pub required_skills: Option<Vec<String>>,
 }
@@ -0,0 +1,76 @@
+/// Backend-neutral view of one thing an agent did during a turn. Every
+/// [`AgentBackend`](crate::system::agent_backend::AgentBackend) parses its own
+/// output format into these items.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgentItem {
+    /// The backend opened (or resumed) a session that later turns can continue.
+    SessionStarted {
+        session_id: String,
+    },
+    Message {
+        text: String,
+    },
+    Reasoning {
+        text: String,
+    },
+    Command {
+        command: String,
+        output: String,
+        exit_code: Option<i32>,
+        status: ItemStatus,
+    },
+    FileChanges {
+        changes: Vec<FileChange>,
+        status: ItemStatus,
+    },
+    ToolCall {
+        server: String,
+        tool: String,
+        status: ItemStatus,
+    },
+    WebSearch {
+        query: String,
+    },
+    TodoList {
+        items: Vec<TodoItem>,
+    },
+    Error {
+        message: String,
+    },
+    /// Token usage reported when a turn finished.
+    Usage {
+        input_tokens: i64,
+        cached_input_tokens: i64,
+        output_tokens: i64,
+    },
+    /// The turn as a whole failed, as opposed to a single item in it.
+    TurnFailed {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileChange {
+    pub path: String,
+    pub kind: FileChangeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileChangeKind {
+    Add,
+    Delete,
+    Update,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TodoItem {
+    pub text: String,
+    pub completed: bool,
+}
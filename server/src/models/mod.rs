@@ -1,3 +1,4 @@
+pub mod agent_items;
 pub mod codex_events;
 pub mod process;
 pub mod strategy;
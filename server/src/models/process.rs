@@ -36,6 +36,8 @@ pub struct RunMetadata {
     pub tags: Vec<String>,
     pub priority: RunPriority,
     pub issued_at: DateTime<Utc>,
+    /// Agent backend whose output format the run writes; `None` for non-agent runs.
+    pub backend: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -379,6 +381,7 @@ mod tests {
             tags: vec![],
             priority: RunPriority::Normal,
             issued_at: Utc::now(),
+            backend: None,
         };
 
         let mut state = MiddlewareState::default();
@@ -1,51 +1,150 @@
-use serde::{Deserialize, Serialize};
+use openapi::models::StrategyDefinition;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub enum Strategy {
-    Aggressive,
-    Moderate,
-    Economical,
-    BugSmash,
-    HotfixSwarm,
-    Planning,
-    WindDown,
+/// Resolved strategy: a built-in preset or a definition from config.json with
+/// every optional field filled in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Strategy {
+    pub name: String,
+    pub hint: String,
+    pub groups: Vec<String>,
+    /// With `scheduler` set this is the scheduler's budget; otherwise it caps
+    /// how many idle workers the orchestrator is hinted to assign.
+    pub busy_workers: Option<usize>,
+    pub allow_assignments: bool,
+    pub sequential_groups: bool,
+    pub scheduler: bool,
+    pub task_editing: bool,
+    pub tools: Option<Vec<String>>,
 }
 
 impl Strategy {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Strategy::Aggressive => "AGGRESSIVE",
-            Strategy::Moderate => "MODERATE",
-            Strategy::Economical => "ECONOMICAL",
-            Strategy::BugSmash => "BUG_SMASH",
-            Strategy::HotfixSwarm => "HOTFIX_SWARM",
-            Strategy::Planning => "PLANNING",
-            Strategy::WindDown => "WIND_DOWN",
+    pub fn render_hint(&self, focus: &str) -> String {
+        let busy = self
+            .busy_workers
+            .map(|count| count.to_string())
+            .unwrap_or_else(|| "all".to_string());
+        self.hint
+            .replace("{focus}", focus)
+            .replace("{busy_workers}", &busy)
+    }
+
+    /// Whether the orchestrator may use `tool` under this strategy.
+    pub fn allows_tool(&self, tool: &str) -> bool {
+        match &self.tools {
+            Some(tools) => tools.iter().any(|name| name == tool),
+            None => true,
         }
     }
-    pub fn render_as_hint(&self) -> &'static str {
-        match self {
-            Strategy::Aggressive => {
-                "Assign tasks from any task group besides `chores` or `bugs` to any available worker. Avoid task dependency clashes. Once all the task groups are done, *then* source tasks from `chores` or `bugs` task groups."
-            }
-            Strategy::Moderate => {
-                "Assign tasks from the focused task group. Avoid task dependency clashes."
-            }
-            Strategy::Economical => "Assign tasks from the focused task group.",
-            Strategy::BugSmash => "Assign tasks from the `bugs` task group.",
-            Strategy::HotfixSwarm => {
-                "CODE RED: We have an emergency production issue. You will see these instructions 4 times, one for each worker you will activate. See the tasks in the `hotfix` task group for specifics on the reported issue. You will need to delegate at least 4 workers. One handles frontend, one handles backend, one handles database, and the last one handles package dependencies. Inform each one of their assigned section, collect all 4 of their responses, and assign one of them to apply the fix."
-            }
-            Strategy::Planning => {
-                "Respond only to Quality Assurance with `STATUS_UPDATE` intent. If you receive messages from active workers during this time, encourage them to finish their assigned task. Do not assign new tasks to workers. Task and Task Group creation and editing is enabled in this mode."
-            }
-            Strategy::WindDown => {
-                "Respond only to Quality Assurance with `STATUS_UPDATE`. If you receive messages from active workers during this time, encourage them to finish their assigned task. Do not assign new tasks to workers."
-            }
+
+    pub fn to_definition(&self) -> StrategyDefinition {
+        StrategyDefinition {
+            name: self.name.clone(),
+            hint: self.hint.clone(),
+            groups: Some(self.groups.clone()),
+            busy_workers: self.busy_workers.map(|count| count as i64),
+            allow_assignments: Some(self.allow_assignments),
+            sequential_groups: Some(self.sequential_groups),
+            scheduler: Some(self.scheduler),
+            task_editing: Some(self.task_editing),
+            tools: self.tools.clone(),
         }
     }
 }
 
+impl From<StrategyDefinition> for Strategy {
+    fn from(value: StrategyDefinition) -> Self {
+        Self {
+            name: value.name.trim().to_ascii_uppercase(),
+            hint: value.hint,
+            groups: value.groups.unwrap_or_default(),
+            busy_workers: value
+                .busy_workers
+                .and_then(|count| usize::try_from(count).ok()),
+            allow_assignments: value.allow_assignments.unwrap_or(true),
+            sequential_groups: value.sequential_groups.unwrap_or(false),
+            scheduler: value.scheduler.unwrap_or(false),
+            task_editing: value.task_editing.unwrap_or(false),
+            tools: value.tools,
+        }
+    }
+}
+
+fn preset(name: &str, hint: &str) -> Strategy {
+    Strategy {
+        name: name.to_string(),
+        hint: hint.to_string(),
+        groups: Vec::new(),
+        busy_workers: None,
+        allow_assignments: true,
+        sequential_groups: false,
+        scheduler: false,
+        task_editing: false,
+        tools: None,
+    }
+}
+
+/// Built-in strategies. Config entries with the same name replace them.
+pub fn presets() -> Vec<Strategy> {
+    vec![
+        Strategy {
+            groups: vec!["chores".to_string(), "bugs".to_string()],
+            ..preset(
+                "AGGRESSIVE",
+                "Assign tasks from any task group besides `chores` or `bugs` to any available worker. Avoid task dependency clashes. Once all the task groups are done, *then* source tasks from `chores` or `bugs` task groups.",
+            )
+        },
+        Strategy {
+            groups: vec!["chores".to_string()],
+            busy_workers: Some(3),
+            scheduler: true,
+            ..preset(
+                "MODERATE",
+                "Assign tasks from the focused task group. Avoid task dependency clashes.",
+            )
+        },
+        Strategy {
+            busy_workers: Some(1),
+            sequential_groups: true,
+            scheduler: true,
+            ..preset("ECONOMICAL", "Assign tasks from the focused task group.")
+        },
+        Strategy {
+            groups: vec!["bugs".to_string()],
+            ..preset("BUG_SMASH", "Assign tasks from the `bugs` task group.")
+        },
+        Strategy {
+            groups: vec!["hotfix".to_string()],
+            ..preset(
+                "HOTFIX_SWARM",
+                "CODE RED: We have an emergency production issue. You will see these instructions 4 times, one for each worker you will activate. See the tasks in the `hotfix` task group for specifics on the reported issue. You will need to delegate at least 4 workers. One handles frontend, one handles backend, one handles database, and the last one handles package dependencies. Inform each one of their assigned section, collect all 4 of their responses, and assign one of them to apply the fix.",
+            )
+        },
+        Strategy {
+            groups: vec!["chores".to_string()],
+            busy_workers: Some(1),
+            ..preset(
+                "MAINTENANCE",
+                "Have one worker complete the tasks in the `chores` task group.",
+            )
+        },
+        Strategy {
+            allow_assignments: false,
+            task_editing: true,
+            ..preset(
+                "PLANNING",
+                "Respond only to Quality Assurance with `STATUS_UPDATE` intent. If you receive messages from active workers during this time, encourage them to finish their assigned task. Do not assign new tasks to workers. Task and Task Group creation and editing is enabled in this mode.",
+            )
+        },
+        Strategy {
+            allow_assignments: false,
+            ..preset(
+                "WIND_DOWN",
+                "Respond only to Quality Assurance with `STATUS_UPDATE`. If you receive messages from active workers during this time, encourage them to finish their assigned task. Do not assign new tasks to workers.",
+            )
+        },
+    ]
+}
+
 #[derive(Debug, Clone)]
 pub enum OrchestratorHint {
     AssignTask {
@@ -1,16 +1,14 @@
 use once_cell::sync::OnceCell;
-use openapi::models::Strategy;
 use tokio::sync::broadcast;
 
 #[derive(Debug, Clone)]
 pub enum RealtimeEvent {
     FeedEntry(openapi::models::Feed),
     FeedCleared,
-    QueueState {
-        paused: bool,
-    },
+    QueueState(openapi::models::QueueState),
+    QueueDrained,
     StrategyState {
-        id: Strategy,
+        id: String,
         focus: Vec<i64>,
     },
     WorkersSnapshot {
@@ -23,6 +21,9 @@ pub enum RealtimeEvent {
     OrchestratorThread {
         thread_id: Option<String>,
     },
+    QaInbox {
+        unread: Vec<openapi::models::QaInboxItem>,
+    },
 }
 
 static CHANNEL: OnceCell<broadcast::Sender<RealtimeEvent>> = OnceCell::new();
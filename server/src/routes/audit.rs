@@ -0,0 +1,36 @@
+use crate::db::{self, audit::AuditFilters};
+use axum::{Json, extract::Query, http::StatusCode};
+use openapi::models::AuditEntry;
+use serde::Deserialize;
+use tracing::error;
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditQueryParams {
+    pub actor: Option<String>,
+    pub action: Option<String>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+pub async fn list_audit(
+    Query(query): Query<AuditQueryParams>,
+) -> Result<Json<Vec<AuditEntry>>, StatusCode> {
+    let filters = AuditFilters {
+        actor: query.actor,
+        action: query.action,
+        since: query.since,
+        until: query.until,
+        limit: query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+    };
+    db::audit::list_entries(filters)
+        .await
+        .map(Json)
+        .map_err(|err| {
+            error!(?err, "failed to list audit entries");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
@@ -0,0 +1,388 @@
+use axum::{
+    extract::{MatchedPath, Query, Request},
+    http::{Method, StatusCode, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use openapi::models::ApiRole;
+use serde::Deserialize;
+use tower_http::cors::AllowOrigin;
+use tracing::warn;
+
+use crate::system::api_auth;
+
+/// Rejects callers whose token is missing (401) or whose role is too low (403) for
+/// the matched route, and records the caller as an [`api_auth::ApiCaller`] extension.
+/// Without configured tokens only viewer routes are served.
+pub async fn require_role(mut request: Request, next: Next) -> Response {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_default();
+    let Some(required) = required_role(request.method(), &path) else {
+        return next.run(request).await;
+    };
+
+    let Some(caller) = api_auth::authenticate(bearer_token(&request, &path).as_deref()) else {
+        return (StatusCode::UNAUTHORIZED, "missing or unknown API token").into_response();
+    };
+    if caller.role < required {
+        warn!(caller = %caller.name, role = %caller.role, %required, %path, "API request forbidden");
+        if !api_auth::tokens_configured() {
+            return (
+                StatusCode::FORBIDDEN,
+                format!("{required} role required; add api_tokens to config.json to enable it"),
+            )
+                .into_response();
+        }
+        return (StatusCode::FORBIDDEN, format!("{required} role required")).into_response();
+    }
+    request.extensions_mut().insert(caller);
+    next.run(request).await
+}
+
+pub fn allowed_origins() -> AllowOrigin {
+    AllowOrigin::predicate(|origin, _| origin.to_str().is_ok_and(api_auth::origin_allowed))
+}
+
+/// Minimum role for every route and method; `None` for routes that are public or
+/// authenticate themselves (`/mcp` uses per-run tokens). Keep in step with
+/// `build_routes`.
+const ROUTE_ROLES: &[(&str, &str, Option<ApiRole>)] = &[
+    ("GET", "/healthz", None),
+    ("GET", "/mcp", None),
+    ("POST", "/mcp", None),
+    ("GET", "/ws", Some(ApiRole::Viewer)),
+    ("GET", "/task-wizard/ws", Some(ApiRole::Operator)),
+    ("GET", "/config", Some(ApiRole::Admin)),
+    ("POST", "/config", Some(ApiRole::Admin)),
+    ("PUT", "/config", Some(ApiRole::Admin)),
+    ("DELETE", "/config", Some(ApiRole::Admin)),
+    ("GET", "/tasks", Some(ApiRole::Viewer)),
+    ("POST", "/tasks", Some(ApiRole::Operator)),
+    ("GET", "/tasks/{taskId}", Some(ApiRole::Viewer)),
+    ("PUT", "/tasks/{taskId}", Some(ApiRole::Operator)),
+    ("DELETE", "/tasks/{taskId}", Some(ApiRole::Operator)),
+    ("GET", "/tasks/{taskId}/history", Some(ApiRole::Viewer)),
+    ("GET", "/tasks/{taskId}/commit", Some(ApiRole::Viewer)),
+    ("GET", "/tasks/{taskId}/commit/diff", Some(ApiRole::Viewer)),
+    ("GET", "/git/status", Some(ApiRole::Viewer)),
+    ("GET", "/git/status/{worktreeId}", Some(ApiRole::Viewer)),
+    ("POST", "/git/commit/{worktreeId}", Some(ApiRole::Operator)),
+    ("POST", "/git/fast_forward_all", Some(ApiRole::Operator)),
+    ("GET", "/task-groups", Some(ApiRole::Viewer)),
+    ("POST", "/task-groups", Some(ApiRole::Operator)),
+    ("GET", "/task-groups/{taskGroupId}", Some(ApiRole::Viewer)),
+    ("PUT", "/task-groups/{taskGroupId}", Some(ApiRole::Operator)),
+    (
+        "DELETE",
+        "/task-groups/{taskGroupId}",
+        Some(ApiRole::Operator),
+    ),
+    (
+        "POST",
+        "/task-groups/{taskGroupId}/archive",
+        Some(ApiRole::Operator),
+    ),
+    ("GET", "/task-deps", Some(ApiRole::Viewer)),
+    ("POST", "/task-deps", Some(ApiRole::Operator)),
+    (
+        "DELETE",
+        "/task-deps/{taskId}/{dependsOnTaskId}",
+        Some(ApiRole::Operator),
+    ),
+    ("GET", "/task-proposals", Some(ApiRole::Viewer)),
+    (
+        "POST",
+        "/task-proposals/{proposalId}/accept",
+        Some(ApiRole::Operator),
+    ),
+    (
+        "POST",
+        "/task-proposals/{proposalId}/reject",
+        Some(ApiRole::Operator),
+    ),
+    ("GET", "/runs/{runId}/recording", Some(ApiRole::Viewer)),
+    ("GET", "/runs/{runId}/transcript", Some(ApiRole::Viewer)),
+    ("POST", "/runs/{runId}/replay", Some(ApiRole::Admin)),
+    ("GET", "/qa/inbox", Some(ApiRole::Viewer)),
+    ("POST", "/qa/inbox/{itemId}/read", Some(ApiRole::Operator)),
+    ("POST", "/qa/inbox/{itemId}/reply", Some(ApiRole::Operator)),
+    ("GET", "/message_queue", Some(ApiRole::Viewer)),
+    ("POST", "/message_queue", Some(ApiRole::Operator)),
+    ("DELETE", "/message_queue", Some(ApiRole::Operator)),
+    (
+        "DELETE",
+        "/message_queue/{messageId}",
+        Some(ApiRole::Operator),
+    ),
+    (
+        "PATCH",
+        "/message_queue/{messageId}/insert",
+        Some(ApiRole::Operator),
+    ),
+    (
+        "POST",
+        "/message_queue/{messageId}/approve",
+        Some(ApiRole::Operator),
+    ),
+    (
+        "POST",
+        "/message_queue/{messageId}/reject",
+        Some(ApiRole::Operator),
+    ),
+    (
+        "DELETE",
+        "/message_queue/to/{sender}",
+        Some(ApiRole::Operator),
+    ),
+    ("GET", "/queue", Some(ApiRole::Viewer)),
+    ("PUT", "/queue", Some(ApiRole::Operator)),
+    ("POST", "/queue/drain", Some(ApiRole::Operator)),
+    ("GET", "/feed", Some(ApiRole::Viewer)),
+    ("DELETE", "/feed", Some(ApiRole::Admin)),
+    ("GET", "/feed/{feedId}", Some(ApiRole::Viewer)),
+    ("POST", "/feed/retention", Some(ApiRole::Admin)),
+    ("GET", "/feed/archives", Some(ApiRole::Viewer)),
+    (
+        "POST",
+        "/feed/archives/{archiveName}/import",
+        Some(ApiRole::Admin),
+    ),
+    ("GET", "/audit", Some(ApiRole::Admin)),
+    ("GET", "/workers", Some(ApiRole::Viewer)),
+    ("POST", "/workers", Some(ApiRole::Admin)),
+    ("DELETE", "/workers/{workerId}", Some(ApiRole::Admin)),
+    ("POST", "/workers/{workerId}/pause", Some(ApiRole::Admin)),
+    ("POST", "/workers/{workerId}/resume", Some(ApiRole::Admin)),
+    (
+        "DELETE",
+        "/workers/{workerId}/session",
+        Some(ApiRole::Admin),
+    ),
+    (
+        "POST",
+        "/workers/{workerId}/terminate",
+        Some(ApiRole::Admin),
+    ),
+    ("POST", "/workers/{workerId}/exec", Some(ApiRole::Admin)),
+    (
+        "POST",
+        "/workers/{workerId}/exec/stream",
+        Some(ApiRole::Admin),
+    ),
+    ("GET", "/workers/{workerId}/terminal", Some(ApiRole::Admin)),
+    ("GET", "/strategy", Some(ApiRole::Viewer)),
+    ("PUT", "/strategy", Some(ApiRole::Operator)),
+    ("GET", "/strategies", Some(ApiRole::Viewer)),
+    ("GET", "/schedule", Some(ApiRole::Viewer)),
+    ("PUT", "/schedule/override", Some(ApiRole::Operator)),
+    ("DELETE", "/schedule/override", Some(ApiRole::Operator)),
+    ("DELETE", "/orchestrator/session", Some(ApiRole::Admin)),
+    ("POST", "/orchestrator/exec", Some(ApiRole::Admin)),
+    ("POST", "/orchestrator/exec/stream", Some(ApiRole::Admin)),
+    ("POST", "/orchestrator/terminate", Some(ApiRole::Admin)),
+];
+
+/// WebSocket upgrades, where browsers cannot set an `Authorization` header.
+const QUERY_TOKEN_ROUTES: [&str; 3] = ["/ws", "/task-wizard/ws", "/workers/{workerId}/terminal"];
+
+/// Role from [`ROUTE_ROLES`]; anything missing from the table is admin-only.
+fn required_role(method: &Method, path: &str) -> Option<ApiRole> {
+    ROUTE_ROLES
+        .iter()
+        .find(|(m, p, _)| *m == method.as_str() && *p == path)
+        .map_or(Some(ApiRole::Admin), |(_, _, role)| *role)
+}
+
+/// `Authorization: Bearer` header, or a `token` query parameter on the
+/// [`QUERY_TOKEN_ROUTES`] so tokens do not end up in access logs for ordinary requests.
+fn bearer_token(request: &Request, path: &str) -> Option<String> {
+    let header = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string());
+    if header.is_some() || request.method() != Method::GET || !QUERY_TOKEN_ROUTES.contains(&path) {
+        return header;
+    }
+    Query::<TokenQuery>::try_from_uri(request.uri())
+        .ok()
+        .and_then(|Query(query)| query.token)
+}
+
+#[derive(Deserialize)]
+struct TokenQuery {
+    token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http};
+    use tower::ServiceExt;
+
+    fn role(method: Method, path: &str) -> Option<ApiRole> {
+        required_role(&method, path)
+    }
+
+    #[test]
+    fn every_mutating_route_needs_at_least_operator() {
+        for (method, path, role) in ROUTE_ROLES {
+            if *method == "GET" || path.starts_with("/mcp") {
+                continue;
+            }
+            assert!(
+                role.is_some_and(|role| role >= ApiRole::Operator),
+                "{method} {path} is not protected"
+            );
+        }
+    }
+
+    #[test]
+    fn admin_routes() {
+        let admin = [
+            (Method::GET, "/config"),
+            (Method::POST, "/config"),
+            (Method::PUT, "/config"),
+            (Method::DELETE, "/config"),
+            (Method::GET, "/audit"),
+            (Method::POST, "/runs/{runId}/replay"),
+            (Method::DELETE, "/feed"),
+            (Method::POST, "/feed/retention"),
+            (Method::POST, "/feed/archives/{archiveName}/import"),
+            (Method::POST, "/workers"),
+            (Method::DELETE, "/workers/{workerId}"),
+            (Method::POST, "/workers/{workerId}/pause"),
+            (Method::POST, "/workers/{workerId}/resume"),
+            (Method::DELETE, "/workers/{workerId}/session"),
+            (Method::POST, "/workers/{workerId}/terminate"),
+            (Method::POST, "/workers/{workerId}/exec"),
+            (Method::POST, "/workers/{workerId}/exec/stream"),
+            (Method::GET, "/workers/{workerId}/terminal"),
+            (Method::DELETE, "/orchestrator/session"),
+            (Method::POST, "/orchestrator/exec"),
+            (Method::POST, "/orchestrator/exec/stream"),
+            (Method::POST, "/orchestrator/terminate"),
+        ];
+        for (method, path) in admin {
+            assert_eq!(
+                role(method.clone(), path),
+                Some(ApiRole::Admin),
+                "{method} {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn operator_routes() {
+        let operator = [
+            (Method::GET, "/task-wizard/ws"),
+            (Method::POST, "/tasks"),
+            (Method::PUT, "/tasks/{taskId}"),
+            (Method::DELETE, "/tasks/{taskId}"),
+            (Method::POST, "/git/commit/{worktreeId}"),
+            (Method::POST, "/git/fast_forward_all"),
+            (Method::POST, "/task-groups"),
+            (Method::PUT, "/task-groups/{taskGroupId}"),
+            (Method::DELETE, "/task-groups/{taskGroupId}"),
+            (Method::POST, "/task-groups/{taskGroupId}/archive"),
+            (Method::POST, "/task-deps"),
+            (Method::DELETE, "/task-deps/{taskId}/{dependsOnTaskId}"),
+            (Method::POST, "/task-proposals/{proposalId}/accept"),
+            (Method::POST, "/task-proposals/{proposalId}/reject"),
+            (Method::POST, "/qa/inbox/{itemId}/read"),
+            (Method::POST, "/qa/inbox/{itemId}/reply"),
+            (Method::POST, "/message_queue"),
+            (Method::DELETE, "/message_queue"),
+            (Method::DELETE, "/message_queue/{messageId}"),
+            (Method::PATCH, "/message_queue/{messageId}/insert"),
+            (Method::POST, "/message_queue/{messageId}/approve"),
+            (Method::POST, "/message_queue/{messageId}/reject"),
+            (Method::DELETE, "/message_queue/to/{sender}"),
+            (Method::PUT, "/queue"),
+            (Method::POST, "/queue/drain"),
+            (Method::PUT, "/strategy"),
+            (Method::PUT, "/schedule/override"),
+            (Method::DELETE, "/schedule/override"),
+        ];
+        for (method, path) in operator {
+            assert_eq!(
+                role(method.clone(), path),
+                Some(ApiRole::Operator),
+                "{method} {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn public_and_unknown_routes() {
+        assert_eq!(role(Method::GET, "/healthz"), None);
+        assert_eq!(role(Method::POST, "/mcp"), None);
+        assert_eq!(role(Method::GET, "/tasks"), Some(ApiRole::Viewer));
+        assert_eq!(role(Method::POST, "/healthz"), Some(ApiRole::Admin));
+        assert_eq!(role(Method::DELETE, "/strategies"), Some(ApiRole::Admin));
+        assert_eq!(role(Method::GET, ""), Some(ApiRole::Admin));
+    }
+
+    #[test]
+    fn query_tokens_only_on_websocket_routes() {
+        let request = |uri: &str| http::Request::get(uri).body(Body::empty()).unwrap();
+        assert_eq!(
+            bearer_token(&request("/ws?token=abc"), "/ws").as_deref(),
+            Some("abc")
+        );
+        assert_eq!(bearer_token(&request("/tasks?token=abc"), "/tasks"), None);
+
+        let post = http::Request::post("/ws?token=abc")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(bearer_token(&post, "/ws"), None);
+
+        let header = http::Request::get("/tasks")
+            .header(AUTHORIZATION, "Bearer xyz")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(bearer_token(&header, "/tasks").as_deref(), Some("xyz"));
+    }
+
+    #[tokio::test]
+    async fn without_tokens_only_viewer_routes_are_served() {
+        api_auth::reset();
+        let status = |request: http::Request<Body>| async {
+            super::super::build_routes()
+                .oneshot(request)
+                .await
+                .expect("route request")
+                .status()
+        };
+
+        let exec = http::Request::post("/workers/1/exec")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"command":"id"}"#))
+            .unwrap();
+        assert_eq!(status(exec).await, StatusCode::FORBIDDEN);
+
+        let terminal = http::Request::get("/workers/1/terminal")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(status(terminal).await, StatusCode::FORBIDDEN);
+
+        let config = http::Request::put("/config")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from("{}"))
+            .unwrap();
+        assert_eq!(status(config).await, StatusCode::FORBIDDEN);
+
+        let queue = http::Request::put("/queue")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"paused":false}"#))
+            .unwrap();
+        assert_eq!(status(queue).await, StatusCode::FORBIDDEN);
+
+        let health = http::Request::get("/healthz").body(Body::empty()).unwrap();
+        assert_eq!(status(health).await, StatusCode::OK);
+    }
+}
@@ -1,13 +1,24 @@
 use crate::{
-    config_sync,
+    config_sync, db, docker,
     globals::PROJECT_DIR,
-    system::{codex_config, dirty_staging::DirtyStagingAction as SystemDirtyStagingAction},
+    system::{
+        agent_backend, api_auth,
+        api_auth::ApiCaller,
+        audit, codex_config, container_limits, container_runtime,
+        dirty_staging::DirtyStagingAction as SystemDirtyStagingAction,
+        exec, feed_archive, review,
+        runner::{self, Persona, RunnerConfig},
+        schedule, strategy, worker_profiles,
+    },
 };
-use axum::{Json, http::StatusCode};
+use axum::{Extension, Json, http::StatusCode};
 use openapi::models::{
-    AppendFilesConfig, Config as WorkspaceConfig, DockerOverrides, config::DirtyStagingAction,
+    AgentBackends, AppendFilesConfig, Config as WorkspaceConfig, ContainerLimitsConfig,
+    DockerOverrides, EffectiveDockerArgs, ExecSettings, FeedRetention, ReviewMode,
+    config::{ContainerRuntime, DirtyStagingAction, SkillMismatch},
 };
-use serde_json::{Error as SerdeError, Value};
+use serde_json::{Error as SerdeError, Value, json};
+use std::collections::BTreeSet;
 use std::fs;
 use std::io::ErrorKind;
 use std::path::PathBuf;
@@ -86,11 +97,26 @@ fn default_config() -> WorkspaceConfig {
         commands: vec![],
         post_turn_checks: vec![],
         docker_overrides: Box::new(default_docker_overrides()),
+        container_limits: Some(Box::new(ContainerLimitsConfig::new())),
+        worker_profiles: Some(vec![]),
+        skill_mismatch: Some(SkillMismatch::Warn),
+        container_runtime: Some(ContainerRuntime::Docker),
+        api_tokens: Some(vec![]),
+        cors_origins: Some(vec![]),
+        exec: Some(Box::new(ExecSettings::new())),
+        feed_retention: Some(Box::new(FeedRetention::new())),
+        effective_docker_args: None,
+        agent_backends: Some(Box::new(AgentBackends::new())),
         dirty_staging_action: Some(DirtyStagingAction::Commit),
         on_staging_change: Some(vec![]),
         persistent_threads: Some(false),
         ghost_commits: Some(false),
         drift_manager: Some(false),
+        deterministic_scheduler: Some(false),
+        coalesce_messages: Some(false),
+        review_mode: Some(Box::new(ReviewMode::new())),
+        strategies: Some(vec![]),
+        schedule: Some(vec![]),
     }
 }
 
@@ -119,16 +145,14 @@ fn map_sync_error(err: config_sync::ConfigSyncError) -> (StatusCode, String) {
 }
 
 pub async fn get_config() -> Result<Json<WorkspaceConfig>, (StatusCode, String)> {
-    load_config_from_disk()
-        .map(|mut cfg| {
-            cfg.workspace_path = Some(PROJECT_DIR.as_str().to_string());
-            cfg
-        })
-        .map(Json)
-        .map_err(map_error)
+    let mut cfg = load_config_from_disk().map_err(map_error)?;
+    cfg.workspace_path = Some(PROJECT_DIR.as_str().to_string());
+    cfg.effective_docker_args = effective_docker_args().await.map(Box::new);
+    Ok(Json(cfg))
 }
 
 pub async fn create_config(
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<WorkspaceConfig>,
 ) -> Result<(StatusCode, Json<WorkspaceConfig>), (StatusCode, String)> {
     if config_exists() {
@@ -138,32 +162,84 @@ pub async fn create_config(
     validate_workspace_config(&payload)?;
     let mut to_disk = payload.clone();
     to_disk.workspace_path = None;
+    to_disk.effective_docker_args = None;
     write_config_to_disk(&to_disk).map_err(map_error)?;
     config_sync::reload_from_disk().map_err(map_sync_error)?;
+    if to_disk
+        .worker_profiles
+        .as_ref()
+        .is_some_and(|p| !p.is_empty())
+    {
+        tokio::spawn(docker::make_profile_images());
+    }
+    audit::record(
+        &caller,
+        "config.create",
+        json!({ "changed": changed_fields(None, &to_disk) }),
+    )
+    .await;
     let mut response = payload;
     response.workspace_path = Some(PROJECT_DIR.as_str().to_string());
+    response.effective_docker_args = effective_docker_args().await.map(Box::new);
     Ok((StatusCode::CREATED, Json(response)))
 }
 
 pub async fn update_config(
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<WorkspaceConfig>,
 ) -> Result<Json<WorkspaceConfig>, (StatusCode, String)> {
     validate_workspace_config(&payload)?;
     let mut to_disk = payload.clone();
     to_disk.workspace_path = None;
+    to_disk.effective_docker_args = None;
+    let previous = load_config_from_disk().ok();
     write_config_to_disk(&to_disk).map_err(map_error)?;
     config_sync::reload_from_disk().map_err(map_sync_error)?;
+    if previous.as_ref().map(|config| &config.worker_profiles) != Some(&to_disk.worker_profiles) {
+        // New or edited profiles need their images before a worker on them runs.
+        tokio::spawn(docker::make_profile_images());
+    }
+    audit::record(
+        &caller,
+        "config.update",
+        json!({ "changed": changed_fields(previous.as_ref(), &to_disk) }),
+    )
+    .await;
     let mut response = payload;
     response.workspace_path = Some(PROJECT_DIR.as_str().to_string());
+    response.effective_docker_args = effective_docker_args().await.map(Box::new);
     Ok(Json(response))
 }
 
-pub async fn delete_config() -> Result<StatusCode, (StatusCode, String)> {
+pub async fn delete_config(
+    Extension(caller): Extension<ApiCaller>,
+) -> Result<StatusCode, (StatusCode, String)> {
     delete_config_file().map_err(map_error)?;
     config_sync::clear_state().map_err(map_sync_error)?;
+    audit::record(&caller, "config.delete", json!({})).await;
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Top-level config fields that differ between `before` and `after`. Only names are
+/// audited so token secrets never reach the log.
+fn changed_fields(before: Option<&WorkspaceConfig>, after: &WorkspaceConfig) -> Vec<String> {
+    let as_object = |config: &WorkspaceConfig| match serde_json::to_value(config) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Default::default(),
+    };
+    let before = before.map(as_object).unwrap_or_default();
+    let after = as_object(after);
+    let mut changed: Vec<String> = after
+        .keys()
+        .chain(before.keys())
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect();
+    changed.sort();
+    changed.dedup();
+    changed
+}
+
 fn hydrate_new_fields(value: &mut Value) -> Result<bool, SerdeError> {
     let Some(object) = value.as_object_mut() else {
         return Ok(false);
@@ -193,6 +269,51 @@ fn hydrate_new_fields(value: &mut Value) -> Result<bool, SerdeError> {
         );
         changed = true;
     }
+    if !object.contains_key("container_limits") {
+        object.insert("container_limits".to_string(), serde_json::json!({}));
+        changed = true;
+    }
+    if !object.contains_key("worker_profiles") {
+        object.insert(
+            "worker_profiles".to_string(),
+            serde_json::Value::Array(vec![]),
+        );
+        changed = true;
+    }
+    if !object.contains_key("skill_mismatch") {
+        object.insert(
+            "skill_mismatch".to_string(),
+            serde_json::to_value(SkillMismatch::Warn)?,
+        );
+        changed = true;
+    }
+    if !object.contains_key("container_runtime") {
+        object.insert(
+            "container_runtime".to_string(),
+            serde_json::to_value(ContainerRuntime::Docker)?,
+        );
+        changed = true;
+    }
+    if !object.contains_key("api_tokens") {
+        object.insert("api_tokens".to_string(), serde_json::Value::Array(vec![]));
+        changed = true;
+    }
+    if !object.contains_key("cors_origins") {
+        object.insert("cors_origins".to_string(), serde_json::Value::Array(vec![]));
+        changed = true;
+    }
+    if !object.contains_key("exec") {
+        object.insert("exec".to_string(), serde_json::json!({}));
+        changed = true;
+    }
+    if !object.contains_key("feed_retention") {
+        object.insert("feed_retention".to_string(), serde_json::json!({}));
+        changed = true;
+    }
+    if !object.contains_key("agent_backends") {
+        object.insert("agent_backends".to_string(), serde_json::json!({}));
+        changed = true;
+    }
     if !object.contains_key("dirty_staging_action") {
         object.insert(
             "dirty_staging_action".to_string(),
@@ -222,13 +343,117 @@ fn hydrate_new_fields(value: &mut Value) -> Result<bool, SerdeError> {
         object.insert("drift_manager".to_string(), serde_json::Value::Bool(false));
         changed = true;
     }
+    if !object.contains_key("deterministic_scheduler") {
+        object.insert(
+            "deterministic_scheduler".to_string(),
+            serde_json::Value::Bool(false),
+        );
+        changed = true;
+    }
+    if !object.contains_key("coalesce_messages") {
+        object.insert(
+            "coalesce_messages".to_string(),
+            serde_json::Value::Bool(false),
+        );
+        changed = true;
+    }
+    if !object.contains_key("review_mode") {
+        object.insert("review_mode".to_string(), serde_json::json!({}));
+        changed = true;
+    }
+    if !object.contains_key("strategies") {
+        object.insert("strategies".to_string(), serde_json::Value::Array(vec![]));
+        changed = true;
+    }
+    if !object.contains_key("schedule") {
+        object.insert("schedule".to_string(), serde_json::Value::Array(vec![]));
+        changed = true;
+    }
 
     Ok(changed)
 }
 
 fn validate_workspace_config(config: &WorkspaceConfig) -> Result<(), (StatusCode, String)> {
     codex_config::validate_preferences(config.models.as_ref(), config.reasoning.as_ref())
-        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    if let Some(backends) = config.agent_backends.as_deref() {
+        agent_backend::validate(backends).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    }
+    if let Some(limits) = config.container_limits.as_deref() {
+        container_limits::validate(limits)
+            .map_err(|msg| (StatusCode::BAD_REQUEST, format!("container_limits: {msg}")))?;
+    }
+    worker_profiles::validate(config.worker_profiles.as_deref().unwrap_or_default())
+        .map_err(|msg| (StatusCode::BAD_REQUEST, format!("worker_profiles: {msg}")))?;
+    container_runtime::validate(
+        config.container_runtime.unwrap_or_default(),
+        &config.docker_overrides,
+        config
+            .container_limits
+            .as_deref()
+            .unwrap_or(&Default::default()),
+        config.worker_profiles.as_deref().unwrap_or_default(),
+    )
+    .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    if let Some(settings) = config.exec.as_deref() {
+        exec::validate(settings)
+            .map_err(|msg| (StatusCode::BAD_REQUEST, format!("exec: {msg}")))?;
+    }
+    if let Some(retention) = config.feed_retention.as_deref() {
+        feed_archive::validate(retention)
+            .map_err(|msg| (StatusCode::BAD_REQUEST, format!("feed_retention: {msg}")))?;
+    }
+    api_auth::validate(
+        config.api_tokens.as_deref().unwrap_or_default(),
+        config.cors_origins.as_deref().unwrap_or_default(),
+    )
+    .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    let strategies = config.strategies.as_deref().unwrap_or_default();
+    strategy::validate_definitions(strategies).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    schedule::validate_rules(config.schedule.as_deref().unwrap_or_default(), |name| {
+        strategy::is_defined(name, strategies)
+    })
+    .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    if let Some(mode) = config.review_mode.as_deref() {
+        review::validate(mode, |name| strategy::is_defined(name, strategies))
+            .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    }
+    Ok(())
+}
+
+/// Argv each persona currently launches with. `workers` holds every worker worktree
+/// plus any configured for one that does not exist yet; `worker` uses a placeholder
+/// `ws0` worktree so it reflects only the shared worker settings. `None` under the
+/// host runtime, where no container is started.
+async fn effective_docker_args() -> Option<EffectiveDockerArgs> {
+    if !container_runtime::current().uses_images() {
+        return None;
+    }
+    let container_args =
+        |persona| runner::plan_codex_run(persona, None, RunnerConfig::default()).docker_args;
+    let mut worker_ids: BTreeSet<i64> = db::worker::list_workers()
+        .await
+        .into_iter()
+        .map(|worker| worker.id)
+        .collect();
+    worker_ids.extend(container_limits::configured_workers());
+    worker_ids.extend(
+        worker_profiles::snapshot()
+            .iter()
+            .flat_map(|profile| &profile.workers)
+            .filter_map(|key| container_limits::worker_id_from_key(key)),
+    );
+    let workers = worker_ids
+        .into_iter()
+        .map(|id| (format!("ws{id}"), container_args(Persona::Worker(id))))
+        .collect();
+    let wizard_workspace = PathBuf::from(format!("{}/wizard", std::env::temp_dir().display()));
+    Some(EffectiveDockerArgs {
+        orchestrator: container_args(Persona::Orchestrator),
+        worker: container_args(Persona::Worker(0)),
+        wizard: runner::wizard_container_args(&wizard_workspace).unwrap_or_default(),
+        workers: Some(workers),
+    })
 }
 
 fn default_docker_overrides() -> DockerOverrides {
@@ -0,0 +1,117 @@
+use std::convert::Infallible;
+
+use async_stream::stream;
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, Sse},
+    },
+};
+use openapi::models::{ExecCommandInput, ExecResult};
+use serde_json::json;
+use tokio::sync::mpsc;
+use tracing::error;
+
+use crate::{
+    shared::shell::{ExecOutput, ShellError},
+    system::{
+        api_auth::ApiCaller,
+        audit,
+        exec::{self, ExecError, ExecPlan},
+        runner::Persona,
+    },
+};
+
+pub async fn run(
+    caller: &ApiCaller,
+    persona: Persona,
+    payload: ExecCommandInput,
+) -> Result<Json<ExecResult>, StatusCode> {
+    let plan = plan(persona, &payload)?;
+    record(caller, persona, &payload, false).await;
+    plan.run(None).await.map(Json).map_err(|err| {
+        error!(?err, ?persona, "failed to execute command");
+        status_for(&err)
+    })
+}
+
+/// `stdout` / `stderr` events while the command runs, then an `exit` event with the
+/// [`ExecResult`] (or an `error` event).
+pub async fn stream(caller: &ApiCaller, persona: Persona, payload: ExecCommandInput) -> Response {
+    let plan = match plan(persona, &payload) {
+        Ok(plan) => plan,
+        Err(status) => return status.into_response(),
+    };
+    record(caller, persona, &payload, true).await;
+    let (tx, mut rx) = mpsc::channel(64);
+    let task = tokio::spawn(plan.run(Some(tx)));
+    let events = stream! {
+        while let Some(chunk) = rx.recv().await {
+            let (event, text) = match chunk {
+                ExecOutput::Stdout(text) => ("stdout", text),
+                ExecOutput::Stderr(text) => ("stderr", text),
+            };
+            yield Ok::<Event, Infallible>(Event::default().event(event).data(text));
+        }
+        let event = match task.await {
+            Ok(Ok(result)) => Event::default()
+                .event("exit")
+                .data(serde_json::to_string(&result).unwrap_or_default()),
+            Ok(Err(err)) => {
+                error!(?err, ?persona, "failed to execute command");
+                Event::default()
+                    .event("error")
+                    .data(json!({"error": err.to_string()}).to_string())
+            }
+            Err(err) => Event::default()
+                .event("error")
+                .data(json!({"error": err.to_string()}).to_string()),
+        };
+        yield Ok(event);
+    };
+    Sse::new(events).into_response()
+}
+
+fn plan(persona: Persona, payload: &ExecCommandInput) -> Result<ExecPlan, StatusCode> {
+    let command = payload.command.trim();
+    if command.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    exec::plan(
+        persona,
+        command,
+        payload.cwd.as_deref(),
+        payload.timeout_secs,
+    )
+    .map_err(|err| {
+        error!(?err, ?persona, "invalid exec request");
+        status_for(&err)
+    })
+}
+
+async fn record(caller: &ApiCaller, persona: Persona, payload: &ExecCommandInput, stream: bool) {
+    let target = match persona {
+        Persona::Orchestrator => "orchestrator".to_string(),
+        Persona::Worker(id) => format!("ws{id}"),
+    };
+    let params = json!({
+        "target": target,
+        "command": payload.command.trim(),
+        "cwd": payload.cwd,
+        "timeout_secs": payload.timeout_secs,
+        "stream": stream,
+    });
+    audit::record(caller, "exec.run", params).await;
+}
+
+fn status_for(err: &ExecError) -> StatusCode {
+    match err {
+        ExecError::Shell(ShellError::InvalidCwd(_)) | ExecError::OutsideWorktree(_) => {
+            StatusCode::BAD_REQUEST
+        }
+        ExecError::SandboxUnavailable => StatusCode::CONFLICT,
+        ExecError::Shell(ShellError::Io(_)) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
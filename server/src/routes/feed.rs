@@ -1,33 +1,80 @@
 use crate::{
     db,
     realtime::{self, RealtimeEvent},
+    system::{
+        api_auth::ApiCaller,
+        audit,
+        feed_archive::{self, FeedArchiveError},
+    },
 };
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, Query},
     http::StatusCode,
 };
-use openapi::models::{Feed, FeedLevel, FeedOrderField};
+use openapi::models::{
+    Feed, FeedArchive, FeedArchiveImport, FeedItemKind, FeedLevel, FeedOrderField,
+    FeedRetentionResult,
+};
 use serde::Deserialize;
+use serde_json::json;
 use tracing::error;
 
+/// Cap on an explicit `limit`; without one the whole matching feed is returned.
+const MAX_FEED_LIMIT: i64 = 1000;
+
 #[allow(dead_code)]
 #[derive(Debug, Default, Deserialize)]
 pub struct FeedQueryParams {
     pub source: Option<String>,
     pub target: Option<String>,
     pub status: Option<FeedLevel>,
+    /// Comma-separated categories.
+    pub category: Option<String>,
+    pub before_id: Option<i64>,
+    pub after_id: Option<i64>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub q: Option<String>,
+    pub run_id: Option<String>,
+    pub task_slug: Option<String>,
+    pub turn_index: Option<i64>,
+    /// Comma-separated item kinds.
+    pub item_kind: Option<String>,
+    pub exit_code: Option<i32>,
+    pub file: Option<String>,
     pub order_by: Option<FeedOrderField>,
+    pub limit: Option<i64>,
 }
 
 pub async fn list_feed(
     Query(query): Query<FeedQueryParams>,
 ) -> Result<Json<Vec<Feed>>, StatusCode> {
+    let categories = split_list(query.category.as_deref())
+        .map(str::to_string)
+        .collect();
+    let item_kinds = split_list(query.item_kind.as_deref())
+        .map(|kind| serde_json::from_value::<FeedItemKind>(json!(kind)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
     let filters = db::feed::FeedFilters {
         source: query.source,
         target: query.target,
         level: query.status,
+        categories,
+        before_id: query.before_id,
+        after_id: query.after_id,
+        since: query.since,
+        until: query.until,
+        query: query.q,
+        run_id: query.run_id,
+        task_slug: query.task_slug,
+        turn_index: query.turn_index,
+        item_kinds,
+        exit_code: query.exit_code,
+        file: query.file,
         order_by: query.order_by,
+        limit: query.limit.map(|limit| limit.clamp(1, MAX_FEED_LIMIT)),
         include_raw: false,
     };
 
@@ -40,20 +87,42 @@ pub async fn list_feed(
     }
 }
 
+fn split_list(raw: Option<&str>) -> impl Iterator<Item = &str> {
+    raw.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
 pub async fn get_feed_entry(Path(feed_id): Path<i64>) -> Result<Json<Feed>, StatusCode> {
-    match db::feed::get_feed_entry(feed_id).await {
-        Ok(Some(feed)) => Ok(Json(feed)),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
+    let mut feed = match db::feed::get_feed_entry(feed_id).await {
+        Ok(Some(feed)) => feed,
+        Ok(None) => return Err(StatusCode::NOT_FOUND),
         Err(err) => {
             error!(?err, feed_id, "failed to fetch feed entry");
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    match db::feed::get_raw_blob(feed_id).await {
+        Ok(Some(blob)) => {
+            if let Some(raw) = feed_archive::read_raw(&blob).await {
+                feed.raw = raw;
+            }
+        }
+        Ok(None) => {}
+        Err(err) => {
+            error!(?err, feed_id, "failed to look up offloaded feed payload");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     }
+    Ok(Json(feed))
 }
 
-pub async fn delete_feed() -> StatusCode {
+pub async fn delete_feed(Extension(caller): Extension<ApiCaller>) -> StatusCode {
     match db::feed::delete_feed().await {
         Ok(_) => {
+            audit::record(&caller, "feed.delete", json!({})).await;
+            feed_archive::clear_blobs().await;
             if let Err(err) = db::session::clear_sessions().await {
                 error!(?err, "failed to clear codex sessions while clearing feed");
                 return StatusCode::INTERNAL_SERVER_ERROR;
@@ -70,3 +139,56 @@ pub async fn delete_feed() -> StatusCode {
         }
     }
 }
+
+pub async fn run_retention(
+    Extension(caller): Extension<ApiCaller>,
+) -> Result<Json<FeedRetentionResult>, StatusCode> {
+    match feed_archive::sweep().await {
+        Ok(result) => {
+            audit::record(
+                &caller,
+                "feed.retention",
+                json!({ "archived": result.archived, "archives": result.archives }),
+            )
+            .await;
+            Ok(Json(result))
+        }
+        Err(err) => {
+            error!(?err, "feed retention sweep failed");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+pub async fn list_archives() -> Result<Json<Vec<FeedArchive>>, StatusCode> {
+    match feed_archive::list_archives().await {
+        Ok(archives) => Ok(Json(archives)),
+        Err(err) => {
+            error!(?err, "failed to list feed archives");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+pub async fn import_archive(
+    Extension(caller): Extension<ApiCaller>,
+    Path(archive_name): Path<String>,
+) -> Result<Json<FeedArchiveImport>, StatusCode> {
+    match feed_archive::import(&archive_name).await {
+        Ok(imported) => {
+            audit::record(
+                &caller,
+                "feed.import",
+                json!({ "archive": archive_name, "imported": imported }),
+            )
+            .await;
+            Ok(Json(FeedArchiveImport::new(imported)))
+        }
+        Err(FeedArchiveError::NotFound(_)) => Err(StatusCode::NOT_FOUND),
+        Err(FeedArchiveError::InvalidName) => Err(StatusCode::BAD_REQUEST),
+        Err(err) => {
+            error!(?err, archive = %archive_name, "failed to import feed archive");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -1,23 +1,32 @@
+mod audit;
+mod auth;
 pub mod config;
+mod exec;
 mod feed;
 mod git;
 mod healthz;
 mod mcp;
 mod message_queue;
 mod orchestrator;
+mod qa;
 mod queue;
+mod run;
+mod schedule;
 mod strategy;
 mod task;
 mod task_dependency;
 mod task_group;
+mod task_proposal;
 mod task_wizard;
+mod terminal;
 mod worker;
 mod ws;
 
 use axum::{
     Router,
     http::Method,
-    routing::{delete, get, patch, post},
+    middleware,
+    routing::{delete, get, patch, post, put},
 };
 use tower_http::cors::{Any, CorsLayer};
 
@@ -30,7 +39,7 @@ pub fn build_routes() -> Router {
             Method::PATCH,
             Method::DELETE,
         ])
-        .allow_origin(Any)
+        .allow_origin(auth::allowed_origins())
         .allow_headers(Any);
 
     Router::new()
@@ -51,6 +60,7 @@ pub fn build_routes() -> Router {
                 .put(task::update_task)
                 .delete(task::delete_task),
         )
+        .route("/tasks/{taskId}/history", get(task::get_task_history))
         .route("/tasks/{taskId}/commit", get(git::get_task_commit_info))
         .route(
             "/tasks/{taskId}/commit/diff",
@@ -89,6 +99,21 @@ pub fn build_routes() -> Router {
             "/task-deps/{taskId}/{dependsOnTaskId}",
             delete(task_dependency::delete_task_dependency),
         )
+        .route("/task-proposals", get(task_proposal::list_task_proposals))
+        .route(
+            "/task-proposals/{proposalId}/accept",
+            post(task_proposal::accept_task_proposal),
+        )
+        .route(
+            "/task-proposals/{proposalId}/reject",
+            post(task_proposal::reject_task_proposal),
+        )
+        .route("/runs/{runId}/recording", get(run::get_run_recording))
+        .route("/runs/{runId}/transcript", get(run::get_run_transcript))
+        .route("/runs/{runId}/replay", post(run::replay_run))
+        .route("/qa/inbox", get(qa::list_qa_inbox))
+        .route("/qa/inbox/{itemId}/read", post(qa::mark_qa_item_read))
+        .route("/qa/inbox/{itemId}/reply", post(qa::reply_qa_item))
         .route(
             "/message_queue",
             get(message_queue::list_messages)
@@ -103,6 +128,14 @@ pub fn build_routes() -> Router {
             "/message_queue/{messageId}/insert",
             patch(message_queue::insert_message_relative),
         )
+        .route(
+            "/message_queue/{messageId}/approve",
+            post(message_queue::approve_message),
+        )
+        .route(
+            "/message_queue/{messageId}/reject",
+            post(message_queue::reject_message),
+        )
         .route(
             "/message_queue/to/{sender}",
             delete(message_queue::delete_messages_for_recipient),
@@ -111,13 +144,23 @@ pub fn build_routes() -> Router {
             "/queue",
             get(queue::get_queue_state).put(queue::update_queue_state),
         )
+        .route("/queue/drain", post(queue::drain_queue))
         .route("/feed", get(feed::list_feed).delete(feed::delete_feed))
         .route("/feed/{feedId}", get(feed::get_feed_entry))
+        .route("/feed/retention", post(feed::run_retention))
+        .route("/feed/archives", get(feed::list_archives))
+        .route(
+            "/feed/archives/{archiveName}/import",
+            post(feed::import_archive),
+        )
+        .route("/audit", get(audit::list_audit))
         .route(
             "/workers",
             get(worker::list_workers).post(worker::create_worker),
         )
         .route("/workers/{workerId}", delete(worker::delete_worker))
+        .route("/workers/{workerId}/pause", post(worker::pause_worker))
+        .route("/workers/{workerId}/resume", post(worker::resume_worker))
         .route(
             "/workers/{workerId}/session",
             delete(worker::delete_worker_session),
@@ -130,10 +173,24 @@ pub fn build_routes() -> Router {
             "/workers/{workerId}/exec",
             post(worker::exec_worker_command),
         )
+        .route(
+            "/workers/{workerId}/exec/stream",
+            post(worker::stream_worker_command),
+        )
+        .route(
+            "/workers/{workerId}/terminal",
+            get(terminal::websocket_handler),
+        )
         .route(
             "/strategy",
             get(strategy::get_active_strategy).put(strategy::update_active_strategy),
         )
+        .route("/strategies", get(strategy::list_strategies))
+        .route("/schedule", get(schedule::get_schedule))
+        .route(
+            "/schedule/override",
+            put(schedule::override_schedule).delete(schedule::clear_schedule_override),
+        )
         .route(
             "/orchestrator/session",
             delete(orchestrator::delete_orchestrator_session),
@@ -142,10 +199,15 @@ pub fn build_routes() -> Router {
             "/orchestrator/exec",
             post(orchestrator::exec_orchestrator_command),
         )
+        .route(
+            "/orchestrator/exec/stream",
+            post(orchestrator::stream_orchestrator_command),
+        )
         .route(
             "/orchestrator/terminate",
             post(orchestrator::terminate_orchestrator),
         )
         .route("/mcp", get(mcp::stream_mcp).post(mcp::handle_mcp_request))
+        .route_layer(middleware::from_fn(auth::require_role))
         .layer(cors)
 }
@@ -4,7 +4,7 @@ use async_stream::stream;
 use axum::{
     Json,
     body::Bytes,
-    http::{HeaderMap, HeaderValue, StatusCode},
+    http::{HeaderMap, HeaderValue, StatusCode, header::AUTHORIZATION},
     response::{
         IntoResponse, Response,
         sse::{Event, KeepAlive, Sse},
@@ -13,12 +13,15 @@ use axum::{
 use serde_json::json;
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 
-use crate::mcp::{self, Agent, AgentHeaderError, session::SessionManager};
+use crate::{
+    mcp::{self, Agent, McpAuthError, session::SessionManager},
+    system::mcp_tokens,
+};
 
 pub async fn handle_mcp_request(headers: HeaderMap, body: Bytes) -> impl IntoResponse {
     let agent = match resolve_agent(&headers) {
         Ok(agent) => agent,
-        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+        Err(err) => return (StatusCode::UNAUTHORIZED, err.to_string()).into_response(),
     };
 
     let session_id = match parse_session_id(&headers) {
@@ -43,7 +46,7 @@ pub async fn handle_mcp_request(headers: HeaderMap, body: Bytes) -> impl IntoRes
 pub async fn stream_mcp(headers: HeaderMap) -> impl IntoResponse {
     let agent = match resolve_agent(&headers) {
         Ok(agent) => agent,
-        Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+        Err(err) => return (StatusCode::UNAUTHORIZED, err.to_string()).into_response(),
     };
 
     let _ = agent;
@@ -78,13 +81,20 @@ pub async fn stream_mcp(headers: HeaderMap) -> impl IntoResponse {
     }
 }
 
-fn resolve_agent(headers: &HeaderMap) -> Result<Agent, AgentHeaderError> {
+/// Agent bound to the request's bearer token. Roles are never taken from the caller.
+fn resolve_agent(headers: &HeaderMap) -> Result<Agent, McpAuthError> {
     let raw = headers
-        .get("AGENT")
-        .ok_or(AgentHeaderError::Missing)?
+        .get(AUTHORIZATION)
+        .ok_or(McpAuthError::Missing)?
         .to_str()
-        .map_err(|_| AgentHeaderError::Invalid("non-utf8".to_string()))?;
-    Agent::parse(raw)
+        .map_err(|_| McpAuthError::Malformed)?;
+    let token = raw
+        .trim()
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or(McpAuthError::Malformed)?;
+    mcp_tokens::verify(token).ok_or(McpAuthError::Unknown)
 }
 
 fn parse_session_id(headers: &HeaderMap) -> Result<Option<String>, &'static str> {
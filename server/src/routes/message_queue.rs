@@ -1,15 +1,16 @@
 use crate::db;
-use crate::system::{events::SystemActor, queue::QueueCoordinator};
+use crate::system::{api_auth::ApiCaller, audit, events::SystemActor, queue::QueueCoordinator};
 use crate::threads;
 use crate::threads::database_manager::DatabaseManagerError;
 use crate::threads::queue_manager::QueueManagerError;
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, Query},
     http::StatusCode,
 };
-use openapi::models::{InsertMessage, Message};
+use openapi::models::{InsertMessage, Message, MessageApproveInput, MessageRejectInput};
 use serde::Deserialize;
+use serde_json::json;
 use tracing::error;
 
 #[allow(dead_code)]
@@ -24,6 +25,12 @@ pub struct EnqueueMessageInput {
     pub from: String,
     pub to: String,
     pub message: String,
+    #[serde(default)]
+    pub priority: Option<i64>,
+    #[serde(default)]
+    pub deliver_after: Option<i64>,
+    #[serde(default)]
+    pub ttl_seconds: Option<i64>,
 }
 
 pub async fn list_messages(
@@ -53,14 +60,28 @@ pub async fn enqueue_message(
     {
         return Err(StatusCode::BAD_REQUEST);
     }
+    if payload.ttl_seconds.is_some_and(|ttl| ttl < 1) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
 
     let from_actor = parse_actor(&payload.from).ok_or(StatusCode::BAD_REQUEST)?;
     let to_actor = parse_actor(&payload.to).ok_or(StatusCode::BAD_REQUEST)?;
 
+    let options = db::message_queue::MessageOptions {
+        priority: payload.priority.unwrap_or_default(),
+        deliver_after: payload.deliver_after,
+        ttl_seconds: payload.ttl_seconds,
+    };
+
     let handles = threads::thread_handles();
     match handles
         .queue
-        .enqueue_manual_message(from_actor, to_actor, payload.message.trim().to_string())
+        .enqueue_manual_message(
+            from_actor,
+            to_actor,
+            payload.message.trim().to_string(),
+            options,
+        )
         .await
     {
         Ok(message) => {
@@ -74,10 +95,13 @@ pub async fn enqueue_message(
     }
 }
 
-pub async fn delete_all_messages() -> StatusCode {
+pub async fn delete_all_messages(Extension(caller): Extension<ApiCaller>) -> StatusCode {
     let handles = threads::thread_handles();
     match handles.queue.delete_all_messages().await {
-        Ok(_) => StatusCode::NO_CONTENT,
+        Ok(_) => {
+            audit::record(&caller, "message_queue.clear", json!({})).await;
+            StatusCode::NO_CONTENT
+        }
         Err(err) => {
             error!(?err, "failed to clear message queue");
             StatusCode::INTERNAL_SERVER_ERROR
@@ -86,16 +110,19 @@ pub async fn delete_all_messages() -> StatusCode {
 }
 
 pub async fn insert_message_relative(
+    Extension(caller): Extension<ApiCaller>,
     Path(message_id): Path<i64>,
     Json(payload): Json<InsertMessage>,
 ) -> Result<Json<Vec<Message>>, StatusCode> {
-    let directive = match payload {
-        InsertMessage::InsertMessageOneOf(before) => {
-            db::message_queue::RelativePosition::Before(before.before)
-        }
-        InsertMessage::InsertMessageOneOf1(after) => {
-            db::message_queue::RelativePosition::After(after.after)
-        }
+    let (directive, details) = match payload {
+        InsertMessage::InsertMessageOneOf(before) => (
+            db::message_queue::RelativePosition::Before(before.before),
+            json!({ "message_id": message_id, "before": before.before }),
+        ),
+        InsertMessage::InsertMessageOneOf1(after) => (
+            db::message_queue::RelativePosition::After(after.after),
+            json!({ "message_id": message_id, "after": after.after }),
+        ),
     };
 
     let handles = threads::thread_handles();
@@ -104,7 +131,10 @@ pub async fn insert_message_relative(
         .insert_message_relative(message_id, directive)
         .await
     {
-        Ok(queue) => Ok(Json(queue)),
+        Ok(queue) => {
+            audit::record(&caller, "message_queue.reorder", details).await;
+            Ok(Json(queue))
+        }
         Err(err) if is_message_missing(&err) => Err(StatusCode::NOT_FOUND),
         Err(err) => {
             error!(?err, "failed to reorder message queue");
@@ -113,10 +143,21 @@ pub async fn insert_message_relative(
     }
 }
 
-pub async fn delete_message_by_id(Path(message_id): Path<i64>) -> StatusCode {
+pub async fn delete_message_by_id(
+    Extension(caller): Extension<ApiCaller>,
+    Path(message_id): Path<i64>,
+) -> StatusCode {
     let handles = threads::thread_handles();
     match handles.queue.delete_message_by_id(message_id).await {
-        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(true) => {
+            audit::record(
+                &caller,
+                "message_queue.delete",
+                json!({ "message_id": message_id }),
+            )
+            .await;
+            StatusCode::NO_CONTENT
+        }
         Ok(false) => StatusCode::NOT_FOUND,
         Err(err) => {
             error!(?err, message_id, "failed to delete queue message");
@@ -125,14 +166,25 @@ pub async fn delete_message_by_id(Path(message_id): Path<i64>) -> StatusCode {
     }
 }
 
-pub async fn delete_messages_for_recipient(Path(recipient): Path<String>) -> StatusCode {
+pub async fn delete_messages_for_recipient(
+    Extension(caller): Extension<ApiCaller>,
+    Path(recipient): Path<String>,
+) -> StatusCode {
     let handles = threads::thread_handles();
     match handles
         .queue
         .delete_messages_for_recipient(recipient.clone())
         .await
     {
-        Ok(_) => StatusCode::NO_CONTENT,
+        Ok(_) => {
+            audit::record(
+                &caller,
+                "message_queue.delete_for_recipient",
+                json!({ "recipient": recipient }),
+            )
+            .await;
+            StatusCode::NO_CONTENT
+        }
         Err(err) => {
             error!(?err, recipient, "failed to delete messages for recipient");
             StatusCode::INTERNAL_SERVER_ERROR
@@ -140,6 +192,74 @@ pub async fn delete_messages_for_recipient(Path(recipient): Path<String>) -> Sta
     }
 }
 
+pub async fn approve_message(
+    Extension(caller): Extension<ApiCaller>,
+    Path(message_id): Path<i64>,
+    payload: Option<Json<MessageApproveInput>>,
+) -> Result<Json<Message>, StatusCode> {
+    let body = payload
+        .and_then(|Json(input)| input.message)
+        .map(|message| message.trim().to_string())
+        .filter(|message| !message.is_empty());
+
+    let edited = body.is_some();
+    let handles = threads::thread_handles();
+    match handles.queue.approve_message(message_id, body).await {
+        Ok(message) => {
+            audit::record(
+                &caller,
+                "message_queue.approve",
+                json!({ "message_id": message_id, "edited": edited }),
+            )
+            .await;
+            Ok(Json(message))
+        }
+        Err(err) => Err(review_error_status(&err, message_id)),
+    }
+}
+
+pub async fn reject_message(
+    Extension(caller): Extension<ApiCaller>,
+    Path(message_id): Path<i64>,
+    Json(payload): Json<MessageRejectInput>,
+) -> StatusCode {
+    let reply = payload.reply.trim();
+    if reply.is_empty() {
+        return StatusCode::BAD_REQUEST;
+    }
+
+    let handles = threads::thread_handles();
+    match handles
+        .queue
+        .reject_message(message_id, reply.to_string())
+        .await
+    {
+        Ok(()) => {
+            audit::record(
+                &caller,
+                "message_queue.reject",
+                json!({ "message_id": message_id }),
+            )
+            .await;
+            StatusCode::NO_CONTENT
+        }
+        Err(err) => review_error_status(&err, message_id),
+    }
+}
+
+fn review_error_status(error: &QueueManagerError, message_id: i64) -> StatusCode {
+    match error {
+        QueueManagerError::Database(DatabaseManagerError::MessageQueue(
+            db::message_queue::MessageQueueError::NotHeld,
+        )) => StatusCode::CONFLICT,
+        err if is_message_missing(err) => StatusCode::NOT_FOUND,
+        err => {
+            error!(?err, message_id, "failed to review held message");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
 fn is_message_missing(error: &QueueManagerError) -> bool {
     match error {
         QueueManagerError::Database(DatabaseManagerError::MessageQueue(inner)) => matches!(
@@ -1,53 +1,53 @@
+use super::exec;
 use crate::{
     db,
-    globals::PROJECT_DIR,
     realtime::{self, RealtimeEvent},
-    shared::shell,
+    system::{api_auth::ApiCaller, audit, runner::Persona},
     threads,
     threads::queue_manager::QueueManagerError,
 };
-use axum::{Json, http::StatusCode};
+use axum::{Extension, Json, http::StatusCode, response::Response};
 use openapi::models::{ExecCommandInput, ExecResult};
-use std::path::{Path, PathBuf};
+use serde_json::json;
 use tracing::{error, warn};
 
-pub async fn delete_orchestrator_session() -> StatusCode {
+pub async fn delete_orchestrator_session(Extension(caller): Extension<ApiCaller>) -> StatusCode {
     if let Err(err) = db::session::delete_session("orchestrator").await {
         error!(?err, "failed to clear orchestrator session");
         StatusCode::INTERNAL_SERVER_ERROR
     } else {
+        audit::record(
+            &caller,
+            "session.reset",
+            json!({ "target": "orchestrator" }),
+        )
+        .await;
         realtime::publish(RealtimeEvent::OrchestratorThread { thread_id: None });
         StatusCode::NO_CONTENT
     }
 }
 
 pub async fn exec_orchestrator_command(
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<ExecCommandInput>,
 ) -> Result<Json<ExecResult>, StatusCode> {
-    let command = payload.command.trim();
-    if command.is_empty() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
-    let workspace_root = Path::new(PROJECT_DIR.as_str());
-    let staging: PathBuf = workspace_root.join("staging");
-    let working_dir = shell::resolve_working_dir(workspace_root, &staging, payload.cwd.as_deref())
-        .map_err(|err| {
-            error!(?err, "invalid working directory for orchestrator command");
-            StatusCode::BAD_REQUEST
-        })?;
-    let result = shell::run_shell_command(&working_dir, command)
-        .await
-        .map_err(|err| {
-            error!(?err, "failed to execute orchestrator command");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
-    Ok(Json(result))
+    exec::run(&caller, Persona::Orchestrator, payload).await
+}
+
+pub async fn stream_orchestrator_command(
+    Extension(caller): Extension<ApiCaller>,
+    Json(payload): Json<ExecCommandInput>,
+) -> Response {
+    exec::stream(&caller, Persona::Orchestrator, payload).await
 }
 
-pub async fn terminate_orchestrator() -> StatusCode {
+pub async fn terminate_orchestrator(Extension(caller): Extension<ApiCaller>) -> StatusCode {
     let handles = threads::thread_handles();
     match handles.queue.kill_orchestrator().await {
-        Ok(_) => StatusCode::ACCEPTED,
+        Ok(_) => {
+            audit::record(&caller, "run.kill", json!({ "target": "orchestrator" })).await;
+            StatusCode::ACCEPTED
+        }
         Err(QueueManagerError::OrchestratorNotRunning) => StatusCode::CONFLICT,
         Err(err) => {
             warn!(?err, "failed to terminate orchestrator process");
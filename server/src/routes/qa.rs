@@ -0,0 +1,115 @@
+use crate::{
+    db::{
+        self,
+        qa_inbox::{self as inbox_db, NewQaItem},
+    },
+    system::{api_auth::ApiCaller, audit, events::SystemActor, qa_inbox},
+    threads,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query},
+    http::StatusCode,
+};
+use openapi::models::{QaInboxItem, QaReplyInput, QaThread};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::error;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct QaInboxQuery {
+    pub unread: Option<bool>,
+}
+
+pub async fn list_qa_inbox(
+    Query(query): Query<QaInboxQuery>,
+) -> Result<Json<Vec<QaThread>>, StatusCode> {
+    let threads = inbox_db::list_threads(query.unread.unwrap_or(false))
+        .await
+        .map_err(|err| {
+            error!(?err, "failed to list QA inbox");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(Json(threads))
+}
+
+pub async fn mark_qa_item_read(Path(item_id): Path<i64>) -> Result<Json<QaInboxItem>, StatusCode> {
+    let item = require_item(item_id).await?;
+    inbox_db::mark_thread_read(&item).await.map_err(|err| {
+        error!(?err, item_id, "failed to mark QA inbox item read");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    qa_inbox::broadcast().await;
+    let item = require_item(item_id).await?;
+    Ok(Json(item))
+}
+
+pub async fn reply_qa_item(
+    Extension(caller): Extension<ApiCaller>,
+    Path(item_id): Path<i64>,
+    Json(payload): Json<QaReplyInput>,
+) -> Result<(StatusCode, Json<QaInboxItem>), StatusCode> {
+    let body = payload.message.trim();
+    if body.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let item = require_item(item_id).await?;
+    let recipient = SystemActor::from_label(&item.agent).ok_or_else(|| {
+        error!(item_id, agent = %item.agent, "QA inbox item has no routable agent");
+        StatusCode::UNPROCESSABLE_ENTITY
+    })?;
+
+    let mut message = body.to_string();
+    if let Some(slug) = item.task_slug.as_deref() {
+        message = format!("Re: {slug}\n\n{message}");
+    }
+    let handles = threads::thread_handles();
+    handles
+        .queue
+        .enqueue_manual_message(
+            SystemActor::QualityAssurance,
+            recipient,
+            message,
+            db::message_queue::MessageOptions::default(),
+        )
+        .await
+        .map_err(|err| {
+            error!(?err, item_id, "failed to enqueue QA reply");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let reply = inbox_db::insert_item(NewQaItem {
+        agent: item.agent.clone(),
+        task_id: item.task_id,
+        task_slug: item.task_slug.clone(),
+        message: body.to_string(),
+        from_qa: true,
+        reply_to: Some(item.id),
+    })
+    .await
+    .map_err(|err| {
+        error!(?err, item_id, "failed to store QA reply");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if let Err(err) = inbox_db::mark_thread_read(&item).await {
+        error!(?err, item_id, "failed to mark QA thread read after reply");
+    }
+    audit::record(
+        &caller,
+        "qa_inbox.reply",
+        json!({ "item_id": item.id, "reply_id": reply.id, "agent": item.agent }),
+    )
+    .await;
+    qa_inbox::broadcast().await;
+    Ok((StatusCode::CREATED, Json(reply)))
+}
+
+async fn require_item(item_id: i64) -> Result<QaInboxItem, StatusCode> {
+    inbox_db::get_item(item_id)
+        .await
+        .map_err(|err| {
+            error!(?err, item_id, "failed to load QA inbox item");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)
+}
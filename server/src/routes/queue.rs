@@ -1,15 +1,18 @@
-use crate::{system::queue::QueueCoordinator, threads};
-use axum::{Json, http::StatusCode};
+use crate::{
+    system::{api_auth::ApiCaller, audit, queue::QueueCoordinator},
+    threads,
+};
+use axum::{Extension, Json, http::StatusCode};
 use openapi::models::QueueState;
+use serde_json::json;
 use tracing::error;
 
 pub async fn get_queue_state() -> Json<QueueState> {
-    Json(QueueState {
-        paused: QueueCoordinator::global().is_paused(),
-    })
+    Json(QueueCoordinator::global().queue_state())
 }
 
 pub async fn update_queue_state(
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<QueueState>,
 ) -> Result<Json<QueueState>, StatusCode> {
     let handles = threads::thread_handles();
@@ -27,7 +30,27 @@ pub async fn update_queue_state(
         }
         coordinator.resume();
     }
-    Ok(Json(QueueState {
-        paused: coordinator.is_paused(),
-    }))
+    let action = if payload.paused {
+        "queue.pause"
+    } else {
+        "queue.resume"
+    };
+    audit::record(&caller, action, json!({})).await;
+    Ok(Json(coordinator.queue_state()))
+}
+
+pub async fn drain_queue(
+    Extension(caller): Extension<ApiCaller>,
+) -> Result<Json<QueueState>, StatusCode> {
+    let handles = threads::thread_handles();
+    let coordinator = QueueCoordinator::global();
+    // Mark the coordinator first so the queue manager's drained report
+    // cannot race ahead of it.
+    coordinator.start_drain();
+    if let Err(err) = handles.queue.drain().await {
+        error!(?err, "failed to drain queue manager");
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    audit::record(&caller, "queue.drain", json!({})).await;
+    Ok(Json(coordinator.queue_state()))
 }
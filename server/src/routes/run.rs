@@ -0,0 +1,109 @@
+use crate::{
+    models::process::RunId,
+    system::{
+        api_auth::ApiCaller,
+        audit, run_recordings,
+        run_transcript::{self, TranscriptFormat},
+    },
+    threads,
+    threads::queue_manager::QueueManagerError,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use openapi::models::RunReplay;
+use serde::Deserialize;
+use serde_json::json;
+use std::io::ErrorKind;
+use tracing::error;
+
+pub async fn get_run_recording(Path(run_id): Path<RunId>) -> Result<impl IntoResponse, StatusCode> {
+    let bytes = run_recordings::load_bytes(run_id)
+        .await
+        .map_err(|err| match err.kind() {
+            ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            _ => {
+                error!(?err, %run_id, "failed to read run recording");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })?;
+    let disposition = format!("attachment; filename=\"{run_id}.jsonl\"");
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-ndjson".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        bytes,
+    ))
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ReplayQueryParams {
+    /// Push the recording through live turn processing instead of a dry run.
+    pub apply: Option<bool>,
+}
+
+pub async fn replay_run(
+    Extension(caller): Extension<ApiCaller>,
+    Path(run_id): Path<RunId>,
+    Query(query): Query<ReplayQueryParams>,
+) -> Result<Json<RunReplay>, StatusCode> {
+    let apply = query.apply.unwrap_or(false);
+    let handles = threads::thread_handles();
+    match handles.queue.replay_run(run_id, apply).await {
+        Ok(replay) => {
+            audit::record(
+                &caller,
+                "run.replay",
+                json!({
+                    "run_id": run_id,
+                    "apply": apply,
+                    "agent": replay.agent,
+                    "feed_entries": replay.feed_entries,
+                }),
+            )
+            .await;
+            Ok(Json(replay))
+        }
+        Err(QueueManagerError::RecordingNotFound(_)) => Err(StatusCode::NOT_FOUND),
+        Err(QueueManagerError::Replay(reason)) => {
+            error!(%run_id, reason, "run replay rejected");
+            Err(StatusCode::UNPROCESSABLE_ENTITY)
+        }
+        Err(err) => {
+            error!(?err, %run_id, "failed to replay run");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TranscriptQueryParams {
+    /// `markdown` (default) or `html`.
+    pub format: Option<String>,
+}
+
+pub async fn get_run_transcript(
+    Path(run_id): Path<RunId>,
+    Query(query): Query<TranscriptQueryParams>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let (format, content_type) = match query.format.as_deref().unwrap_or("markdown") {
+        "markdown" | "md" => (TranscriptFormat::Markdown, "text/markdown; charset=utf-8"),
+        "html" => (TranscriptFormat::Html, "text/html; charset=utf-8"),
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+    let (meta, bytes) = run_recordings::load(run_id)
+        .await
+        .map_err(|err| match err.kind() {
+            ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            _ => {
+                error!(?err, %run_id, "failed to read run recording");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })?;
+    let transcript = run_transcript::render(&meta, &bytes, format);
+    Ok(([(header::CONTENT_TYPE, content_type)], transcript))
+}
@@ -0,0 +1,49 @@
+use crate::system::{api_auth::ApiCaller, audit, queue::QueueCoordinator, schedule};
+use axum::{Extension, Json, http::StatusCode};
+use chrono::{Local, TimeZone};
+use openapi::models::{ScheduleOverrideInput, ScheduleState};
+use serde_json::json;
+
+pub async fn get_schedule() -> Json<ScheduleState> {
+    Json(schedule::snapshot(Local::now()))
+}
+
+pub async fn override_schedule(
+    Extension(caller): Extension<ApiCaller>,
+    Json(payload): Json<ScheduleOverrideInput>,
+) -> Result<Json<ScheduleState>, StatusCode> {
+    let now = Local::now();
+    if payload.until <= now.timestamp() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    schedule::set_override(payload.until);
+    audit::record(
+        &caller,
+        "schedule.override",
+        json!({ "until": payload.until }),
+    )
+    .await;
+    let until = Local
+        .timestamp_opt(payload.until, 0)
+        .single()
+        .map(|at| at.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| payload.until.to_string());
+    QueueCoordinator::global().strategy_scheduled(
+        format!("Schedule suspended by operator until {until}"),
+        json!({ "override_until": payload.until }),
+    );
+    Ok(Json(schedule::snapshot(now)))
+}
+
+pub async fn clear_schedule_override(
+    Extension(caller): Extension<ApiCaller>,
+) -> Json<ScheduleState> {
+    if let Some(until) = schedule::clear_override() {
+        audit::record(&caller, "schedule.clear", json!({ "until": until })).await;
+        QueueCoordinator::global().strategy_scheduled(
+            "Schedule override cleared by operator".to_string(),
+            json!({ "override_until": until }),
+        );
+    }
+    Json(schedule::snapshot(Local::now()))
+}
@@ -1,17 +1,49 @@
+use crate::db::task_group;
 use crate::realtime::{self, RealtimeEvent};
-use crate::system::{queue::QueueCoordinator, strategy::StrategyState};
-use axum::Json;
-use openapi::models::ActiveStrategy;
-use tracing::info;
+use crate::system::{
+    api_auth::ApiCaller,
+    audit,
+    queue::QueueCoordinator,
+    strategy::{self, StrategyState},
+};
+use axum::{Extension, Json, http::StatusCode};
+use openapi::models::{ActiveStrategy, StrategyDefinition};
+use serde_json::json;
+use tracing::{info, warn};
 
 pub async fn get_active_strategy() -> Json<ActiveStrategy> {
     Json(StrategyState::global().snapshot())
 }
 
-pub async fn update_active_strategy(Json(payload): Json<ActiveStrategy>) -> Json<ActiveStrategy> {
-    let strategy = StrategyState::global().update(payload);
+pub async fn list_strategies() -> Json<Vec<StrategyDefinition>> {
+    Json(
+        strategy::list()
+            .iter()
+            .map(|strategy| strategy.to_definition())
+            .collect(),
+    )
+}
+
+pub async fn update_active_strategy(
+    Extension(caller): Extension<ApiCaller>,
+    Json(payload): Json<ActiveStrategy>,
+) -> Result<Json<ActiveStrategy>, StatusCode> {
+    let strategy = StrategyState::global().update(payload).map_err(|err| {
+        warn!(%err, "rejected strategy update");
+        StatusCode::UNPROCESSABLE_ENTITY
+    })?;
+    audit::record(
+        &caller,
+        "strategy.update",
+        json!({ "id": strategy.id, "focus": strategy.focus }),
+    )
+    .await;
+    let groups = task_group::list_task_groups().await.unwrap_or_else(|err| {
+        warn!(?err, "failed to load task groups");
+        Vec::new()
+    });
     let coordinator = QueueCoordinator::global();
-    let hints = coordinator.orchestrator_hints(&strategy);
+    let hints = coordinator.orchestrator_hints(&strategy, &groups);
     if !hints.is_empty() {
         info!("recording {} orchestrator hints", hints.len());
         coordinator.record_assignment_hint(&hints);
@@ -20,5 +52,5 @@ pub async fn update_active_strategy(Json(payload): Json<ActiveStrategy>) -> Json
         id: strategy.id.clone(),
         focus: strategy.focus.clone().unwrap_or_default(),
     });
-    Json(strategy)
+    Ok(Json(strategy))
 }
@@ -1,11 +1,12 @@
 use crate::db;
-use crate::db::task_group;
+use crate::db::{task_group, task_history::TaskChangeOrigin};
+use crate::system::api_auth::ApiCaller;
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, Query},
     http::StatusCode,
 };
-use openapi::models::{Task, TaskCreateInput, TaskStatus, TaskUpdateInput};
+use openapi::models::{Task, TaskCreateInput, TaskHistoryEntry, TaskStatus, TaskUpdateInput};
 use serde::Deserialize;
 use tracing::error;
 
@@ -32,6 +33,7 @@ pub async fn list_tasks(
 }
 
 pub async fn create_task(
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<TaskCreateInput>,
 ) -> Result<(StatusCode, Json<Task>), StatusCode> {
     task_group::get_task_group(payload.group_id)
@@ -52,10 +54,13 @@ pub async fn create_task(
             StatusCode::BAD_REQUEST
         })?;
 
-    let task = db::task::create_task(payload).await.map_err(|err| {
-        error!(?err, "failed to create task");
-        StatusCode::INTERNAL_SERVER_ERROR
-    })?;
+    let origin = TaskChangeOrigin::rest(caller.name);
+    let task = db::task::create_task(payload, &origin)
+        .await
+        .map_err(|err| {
+            error!(?err, "failed to create task");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
     Ok((StatusCode::CREATED, Json(task)))
 }
 
@@ -73,9 +78,11 @@ pub async fn get_task(Path(task_id): Path<i64>) -> Result<Json<Task>, StatusCode
 
 pub async fn update_task(
     Path(task_id): Path<i64>,
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<TaskUpdateInput>,
 ) -> Result<Json<Task>, StatusCode> {
-    let task = db::task::update_task(task_id, payload)
+    let origin = TaskChangeOrigin::rest(caller.name);
+    let task = db::task::update_task(task_id, payload, &origin)
         .await
         .map_err(|err| {
             error!(?err, task_id, "failed to update task");
@@ -86,6 +93,19 @@ pub async fn update_task(
     Ok(Json(task))
 }
 
+pub async fn get_task_history(
+    Path(task_id): Path<i64>,
+) -> Result<Json<Vec<TaskHistoryEntry>>, StatusCode> {
+    get_task(Path(task_id)).await?;
+    db::task_history::list_for_task(task_id)
+        .await
+        .map(Json)
+        .map_err(|err| {
+            error!(?err, task_id, "failed to load task history");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
 pub async fn delete_task(Path(task_id): Path<i64>) -> Result<StatusCode, StatusCode> {
     let deleted = db::task::delete_task(task_id).await.map_err(|err| {
         error!(?err, task_id, "failed to delete task");
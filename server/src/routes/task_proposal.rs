@@ -0,0 +1,99 @@
+use crate::{
+    db::{
+        task_history::TaskChangeOrigin,
+        task_proposal::{self as proposal_db, TaskProposalError, TaskProposalFilters},
+    },
+    system::{api_auth::ApiCaller, audit, events::SystemActor, queue::QueueCoordinator},
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query},
+    http::StatusCode,
+};
+use openapi::models::{
+    TaskProposal, TaskProposalAcceptInput, TaskProposalRejectInput, TaskProposalStatus,
+};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::error;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TaskProposalQuery {
+    pub status: Option<TaskProposalStatus>,
+    pub proposer: Option<String>,
+}
+
+pub async fn list_task_proposals(
+    Query(query): Query<TaskProposalQuery>,
+) -> Result<Json<Vec<TaskProposal>>, StatusCode> {
+    let filters = TaskProposalFilters {
+        status: query.status,
+        proposer: query.proposer,
+    };
+    let proposals = proposal_db::list_proposals(filters).await.map_err(|err| {
+        error!(?err, "failed to list task proposals");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(Json(proposals))
+}
+
+pub async fn accept_task_proposal(
+    Extension(caller): Extension<ApiCaller>,
+    Path(proposal_id): Path<i64>,
+    payload: Option<Json<TaskProposalAcceptInput>>,
+) -> Result<Json<TaskProposal>, StatusCode> {
+    let input = payload.map(|Json(input)| input).unwrap_or_default();
+    let overrides = json!({
+        "group_slug": input.group_slug,
+        "slug": input.slug,
+        "title": input.title.is_some(),
+        "description": input.description.is_some(),
+    });
+    let origin = TaskChangeOrigin::rest(caller.name.clone());
+    let proposal = proposal_db::accept_proposal(proposal_id, &caller.name, input, &origin)
+        .await
+        .map_err(|err| proposal_error_status(err, proposal_id))?;
+    QueueCoordinator::global().proposal_reviewed(SystemActor::System, &proposal);
+    audit::record(
+        &caller,
+        "task_proposal.accept",
+        json!({
+            "proposal_id": proposal_id,
+            "task_id": proposal.task_id,
+            "overrides": overrides,
+        }),
+    )
+    .await;
+    Ok(Json(proposal))
+}
+
+pub async fn reject_task_proposal(
+    Extension(caller): Extension<ApiCaller>,
+    Path(proposal_id): Path<i64>,
+    payload: Option<Json<TaskProposalRejectInput>>,
+) -> Result<Json<TaskProposal>, StatusCode> {
+    let note = payload.and_then(|Json(input)| input.note);
+    let proposal = proposal_db::reject_proposal(proposal_id, &caller.name, note)
+        .await
+        .map_err(|err| proposal_error_status(err, proposal_id))?;
+    QueueCoordinator::global().proposal_reviewed(SystemActor::System, &proposal);
+    audit::record(
+        &caller,
+        "task_proposal.reject",
+        json!({ "proposal_id": proposal_id }),
+    )
+    .await;
+    Ok(Json(proposal))
+}
+
+fn proposal_error_status(err: TaskProposalError, proposal_id: i64) -> StatusCode {
+    match err {
+        TaskProposalError::NotFound => StatusCode::NOT_FOUND,
+        TaskProposalError::AlreadyReviewed(_) => StatusCode::CONFLICT,
+        TaskProposalError::IneligibleGroup(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        TaskProposalError::Db(err) => {
+            error!(?err, proposal_id, "failed to review task proposal");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
@@ -1,19 +1,20 @@
 use crate::{
-    db::feed::{self, NewFeedEntry},
-    docker::DOCKER_IMAGE_WIZARD,
+    db::feed::{self, FeedEntryMeta, NewFeedEntry},
     globals::PROJECT_DIR,
-    models::process::{
-        KillReason, ProcessEvent, ProcessHandle, ProcessKillHandle, ProcessSpawnIntent,
-        ProcessStream, RunId, RunMetadata, RunPriority,
+    mcp::Agent,
+    models::{
+        agent_items::AgentItem,
+        process::{
+            KillReason, ProcessEvent, ProcessHandle, ProcessKillHandle, ProcessSpawnIntent,
+            ProcessStream, RunId, RunMetadata, RunPriority,
+        },
     },
     realtime::{self, RealtimeEvent},
-    shared::{
-        codex_exec::CodexExecBuilder,
-        docker::{DockerRunBuilder, ensure_default_mcp_url},
-    },
     system::{
-        codex_config::{self, AgentKind as CodexAgentKind},
-        docker_overrides,
+        agent_backend::{self, AgentBackend},
+        launcher::WizardLaunchRequest,
+        mcp_tokens,
+        runner::RunnerConfig,
     },
     threads,
 };
@@ -27,7 +28,7 @@ use openapi::models::FeedLevel;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use std::fs;
-use std::path::Path;
+use std::sync::Arc;
 use std::{env, path::PathBuf};
 use tempfile::TempDir;
 use tokio::{sync::mpsc, task::JoinHandle};
@@ -238,6 +239,7 @@ impl TaskWizardState {
             let kill = run.kill_handle.clone();
             let _ = kill.kill(KillReason::UserRequested).await;
             run.stream_task.abort();
+            mcp_tokens::revoke_run(run.run_id);
         }
         self.thread_id = None;
     }
@@ -306,10 +308,22 @@ async fn spawn_task_wizard_process(
     let workspace = TempDir::new().map_err(|err| TaskWizardError::SpawnFailed(err.to_string()))?;
     write_wizard_agents(&workspace)?;
 
-    let api_port = resolve_api_port();
-    let docker_command =
-        build_task_wizard_command(api_port, thread_id.as_deref(), workspace.path());
+    let backend = agent_backend::for_wizard();
     let run_id = Uuid::new_v4();
+    let mcp_token = mcp_tokens::mint(Agent::Wizard, run_id);
+    let request = WizardLaunchRequest {
+        session_id: thread_id.filter(|_| backend.supports_resume()),
+        workspace: workspace.path().to_path_buf(),
+        config: RunnerConfig {
+            api_port: Some(resolve_api_port()),
+            mcp_token: Some(mcp_token),
+            ..RunnerConfig::default()
+        },
+    };
+    let plan = backend.plan_wizard(&request).map_err(|err| {
+        mcp_tokens::revoke_run(run_id);
+        TaskWizardError::SpawnFailed(err.to_string())
+    })?;
     let metadata = RunMetadata {
         run_id,
         persona: format!("task_wizard:{session_id}"),
@@ -317,14 +331,17 @@ async fn spawn_task_wizard_process(
         tags: vec!["task_wizard".to_string()],
         priority: RunPriority::Normal,
         issued_at: Utc::now(),
+        backend: Some(backend.name().to_string()),
     };
 
     let intent = ProcessSpawnIntent {
         metadata,
-        program: docker_command[0].clone(),
-        args: docker_command[1..].to_vec(),
-        env: Vec::new(),
-        working_dir: PathBuf::from(PROJECT_DIR.as_str()),
+        program: plan.program,
+        args: plan.args,
+        env: plan.env,
+        working_dir: plan
+            .working_dir
+            .unwrap_or_else(|| PathBuf::from(PROJECT_DIR.as_str())),
         stream_stdout: true,
         stream_stderr: true,
         stdin: Some(format!("{prompt}\n").into_bytes()),
@@ -335,18 +352,22 @@ async fn spawn_task_wizard_process(
         .middleware
         .enqueue_spawn(intent)
         .await
-        .map_err(|err| TaskWizardError::SpawnFailed(err.to_string()))?;
+        .map_err(|err| {
+            mcp_tokens::revoke_run(run_id);
+            TaskWizardError::SpawnFailed(err.to_string())
+        })?;
 
-    let process_handle = handle_rx
-        .await
-        .map_err(|_| TaskWizardError::SpawnFailed("process handle channel closed".into()))?;
+    let process_handle = handle_rx.await.map_err(|_| {
+        mcp_tokens::revoke_run(run_id);
+        TaskWizardError::SpawnFailed("process handle channel closed".into())
+    })?;
 
     let ProcessHandle {
         run_id: _,
         events,
         kill,
     } = process_handle;
-    let stream_task = spawn_event_forwarder(events, ws_tx, internal_tx, run_id);
+    let stream_task = spawn_event_forwarder(events, ws_tx, internal_tx, run_id, backend);
 
     Ok(ActiveRun {
         run_id,
@@ -357,86 +378,6 @@ async fn spawn_task_wizard_process(
     })
 }
 
-fn build_task_wizard_command(
-    api_port: u16,
-    thread_id: Option<&str>,
-    workspace: &Path,
-) -> Vec<String> {
-    let builder = match thread_id {
-        Some(id) => CodexExecBuilder::resume().session_id(id.to_string()),
-        None => CodexExecBuilder::new(),
-    };
-
-    let launch_settings = codex_config::settings_for(CodexAgentKind::Wizard);
-
-    let enabled_tools = [
-        "tasks_list",
-        "tasks_get",
-        "tasks_create",
-        "tasks_update",
-        "tasks_set_status",
-        "task_groups_list",
-        "task_groups_get",
-        "task_groups_create",
-        "task_dependencies_get",
-        "task_dependencies_set",
-    ];
-
-    let tools_arg = format!(
-        "mcp_servers.robot_farm.enabled_tools=[{}]",
-        enabled_tools
-            .iter()
-            .map(|tool| format!("\"{tool}\""))
-            .collect::<Vec<_>>()
-            .join(",")
-    );
-
-    let default_mcp_url = ensure_default_mcp_url(api_port);
-
-    let codex_args = builder
-        .change_dir("/workspace")
-        .json(true)
-        .config_override("mcp_servers.robot_farm.enabled=true")
-        .config_override("mcp_servers.robot_farm.tool_timeout_sec=900")
-        .config_override(tools_arg)
-        .config_override(format!("mcp_servers.robot_farm.url=\"{default_mcp_url}\""))
-        .config_override("mcp_servers.robot_farm.http_headers.AGENT=\"wizard\"")
-        .config_override(format!("model=\"{}\"", launch_settings.model))
-        .config_override(format!(
-            "model_reasoning_effort=\"{}\"",
-            launch_settings.reasoning
-        ))
-        .skip_git_repo_check(true)
-        .build();
-
-    let mut docker_args = DockerRunBuilder::new(DOCKER_IMAGE_WIZARD)
-        .remove_container(true)
-        .interactive(true)
-        .attach("STDOUT")
-        .attach("STDERR")
-        .user("1000:1000")
-        .workdir("/workspace")
-        .command(codex_args)
-        .volume(workspace, "/workspace", Some("rw".into()))
-        .volume(
-            format!(
-                "{}/.codex",
-                env::var("HOME").unwrap_or_else(|_| "/home/codex".to_string())
-            ),
-            "/home/codex/.codex",
-            None,
-        )
-        .build();
-
-    docker_overrides::apply_overrides(
-        CodexAgentKind::Wizard,
-        &mut docker_args,
-        DOCKER_IMAGE_WIZARD,
-    );
-
-    docker_args
-}
-
 fn resolve_api_port() -> u16 {
     // Prefer the actual server PORT if set; fall back to ROBOT_FARM_API_PORT for backwards compat.
     let from_port_env = env::var("PORT").ok().and_then(|value| value.parse().ok());
@@ -456,6 +397,7 @@ fn spawn_event_forwarder(
     ws_tx: mpsc::Sender<Message>,
     internal_tx: mpsc::Sender<InternalEvent>,
     run_id: RunId,
+    backend: Arc<dyn AgentBackend>,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
         let mut buffer = JsonLineBuffer::default();
@@ -466,7 +408,8 @@ fn spawn_event_forwarder(
                 ProcessEvent::Output(chunk) => {
                     if matches!(chunk.stream, ProcessStream::Stdout) {
                         for line in buffer.ingest(&chunk.bytes) {
-                            forward_codex_line(
+                            forward_agent_line(
+                                backend.as_ref(),
                                 &line,
                                 run_id,
                                 &ws_tx,
@@ -493,7 +436,8 @@ fn spawn_event_forwarder(
                 }
                 ProcessEvent::Exit(exit) => {
                     if let Some(line) = buffer.flush() {
-                        forward_codex_line(
+                        forward_agent_line(
+                            backend.as_ref(),
                             &line,
                             run_id,
                             &ws_tx,
@@ -541,42 +485,36 @@ fn spawn_event_forwarder(
                 }
             }
         }
+        mcp_tokens::revoke_run(run_id);
     })
 }
 
-async fn forward_codex_line(
+/// Track the session id and last message through the backend, and pass the raw event
+/// on to the client.
+async fn forward_agent_line(
+    backend: &dyn AgentBackend,
     line: &str,
     run_id: RunId,
     ws_tx: &mpsc::Sender<Message>,
     internal_tx: &mpsc::Sender<InternalEvent>,
     last_agent_message: &mut Option<String>,
 ) {
+    for item in backend.parse_line(line) {
+        match item {
+            AgentItem::SessionStarted { session_id } => {
+                let _ = internal_tx
+                    .send(InternalEvent::ThreadStarted {
+                        run_id,
+                        thread_id: session_id,
+                    })
+                    .await;
+            }
+            AgentItem::Message { text } => *last_agent_message = Some(text),
+            _ => {}
+        }
+    }
     match serde_json::from_str::<Value>(line) {
         Ok(value) => {
-            if let Some(kind) = value.get("type").and_then(|v| v.as_str()) {
-                match kind {
-                    "thread.started" => {
-                        if let Some(thread_id) = value.get("thread_id").and_then(|v| v.as_str()) {
-                            let _ = internal_tx
-                                .send(InternalEvent::ThreadStarted {
-                                    run_id,
-                                    thread_id: thread_id.to_string(),
-                                })
-                                .await;
-                        }
-                    }
-                    "item.completed" => {
-                        if let Some(item) = value.get("item").and_then(|v| v.as_object()) {
-                            if item.get("type").and_then(|v| v.as_str()) == Some("agent_message") {
-                                if let Some(text) = item.get("text").and_then(|v| v.as_str()) {
-                                    *last_agent_message = Some(text.to_string());
-                                }
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-            }
             send_json(ws_tx, json!({"type":"codex_event","event": value})).await;
         }
         Err(err) => {
@@ -680,6 +618,8 @@ async fn persist_feed_entry(
         text: summarize(response),
         raw: serde_json::to_string(&raw).ok()?,
         category: "task_wizard".to_string(),
+        raw_blob: None,
+        meta: FeedEntryMeta::default(),
     };
 
     match feed::insert_feed_entry(entry).await {
@@ -0,0 +1,203 @@
+use axum::{
+    Extension,
+    extract::{
+        Path as AxumPath, Query,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::{error, info, warn};
+
+use crate::system::{
+    api_auth::ApiCaller,
+    audit,
+    terminal::{self, SessionClaim, TerminalError},
+};
+
+#[derive(Deserialize)]
+pub struct TerminalParams {
+    /// Open even while a Codex run is active on the worktree.
+    #[serde(default)]
+    force: bool,
+    #[serde(default = "default_cols")]
+    cols: u16,
+    #[serde(default = "default_rows")]
+    rows: u16,
+}
+
+fn default_cols() -> u16 {
+    120
+}
+
+fn default_rows() -> u16 {
+    32
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TerminalInbound {
+    Input { data: String },
+    Resize { cols: u16, rows: u16 },
+}
+
+/// PTY output goes out as binary frames. Clients send raw keystrokes as binary frames or
+/// `{"type":"input","data":...}`, and `{"type":"resize","cols":..,"rows":..}` on resize.
+pub async fn websocket_handler(
+    AxumPath(worker_id): AxumPath<i64>,
+    Query(params): Query<TerminalParams>,
+    Extension(caller): Extension<ApiCaller>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let claim = match admit(worker_id, params.force) {
+        Ok(claim) => claim,
+        Err(rejection) => return rejection.into_response(),
+    };
+    ws.on_upgrade(move |socket| handle_socket(socket, worker_id, params, caller, claim))
+}
+
+/// Claim a terminal on `wsN`, refusing while a run is active on it unless `force`.
+/// Once claimed, the queue starts no new run there until the claim is dropped.
+fn admit(worker_id: i64, force: bool) -> Result<SessionClaim, (StatusCode, String)> {
+    terminal::claim(worker_id, force).map_err(|err| {
+        let status = match err {
+            TerminalError::MissingWorktree(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::CONFLICT,
+        };
+        (status, err.to_string())
+    })
+}
+
+async fn handle_socket(
+    socket: WebSocket,
+    worker_id: i64,
+    params: TerminalParams,
+    caller: ApiCaller,
+    claim: SessionClaim,
+) {
+    let forced = claim.forced();
+    let (mut sender, mut receiver) = socket.split();
+    let (session, mut output) = match terminal::open(claim, params.cols, params.rows) {
+        Ok(opened) => opened,
+        Err(err) => {
+            error!(?err, worker_id, "failed to open terminal");
+            let message = json!({"type": "error", "message": err.to_string()}).to_string();
+            let _ = sender.send(Message::Text(message.into())).await;
+            let _ = sender.close().await;
+            return;
+        }
+    };
+    info!(
+        caller = %caller.name,
+        role = %caller.role,
+        worker_id,
+        forced,
+        "terminal session opened"
+    );
+    audit::record(
+        &caller,
+        "terminal.open",
+        json!({ "worker_id": worker_id, "forced": forced }),
+    )
+    .await;
+    let mut exit_code = None;
+    loop {
+        tokio::select! {
+            chunk = output.output.recv() => {
+                let Some(chunk) = chunk else {
+                    exit_code = (&mut output.exit).await.ok();
+                    break;
+                };
+                if sender.send(Message::Binary(chunk.into())).await.is_err() {
+                    break;
+                }
+            }
+            msg = receiver.next() => {
+                let input = match msg {
+                    Some(Ok(Message::Binary(bytes))) => bytes.to_vec(),
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<TerminalInbound>(&text) {
+                            Ok(TerminalInbound::Input { data }) => data.into_bytes(),
+                            Ok(TerminalInbound::Resize { cols, rows }) => {
+                                if let Err(err) = session.resize(cols, rows) {
+                                    warn!(?err, worker_id, "terminal resize failed");
+                                }
+                                continue;
+                            }
+                            Err(err) => {
+                                warn!(?err, worker_id, "ignoring malformed terminal message");
+                                continue;
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(err)) => {
+                        warn!(?err, worker_id, "terminal websocket receive error");
+                        break;
+                    }
+                    Some(Ok(_)) => continue,
+                };
+                if !session.write(input).await {
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Some(code) = exit_code {
+        let exit = json!({"type": "exit", "code": code}).to_string();
+        let _ = sender.send(Message::Text(exit.into())).await;
+    }
+    let _ = sender.close().await;
+    session.close().await;
+    info!(
+        caller = %caller.name,
+        worker_id,
+        ?exit_code,
+        "terminal session closed"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refuses_active_runs_unless_forced() {
+        assert!(terminal::begin_run(9_101));
+        let (status, message) = admit(9_101, false).err().expect("run is active");
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(message.contains("force=true"), "{message}");
+
+        // Forcing gets past the run check to the worktree check.
+        let (status, _) = admit(9_101, true).err().expect("worktree is missing");
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        terminal::end_run(9_101);
+    }
+
+    #[test]
+    fn missing_worktrees_are_not_found() {
+        let (status, message) = admit(9_102, false).err().expect("worktree is missing");
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(message.contains("ws9102"));
+    }
+
+    #[test]
+    fn parses_inbound_messages() {
+        let input: TerminalInbound =
+            serde_json::from_str(r#"{"type":"input","data":"ls\n"}"#).unwrap();
+        assert!(matches!(input, TerminalInbound::Input { data } if data == "ls\n"));
+
+        let resize: TerminalInbound =
+            serde_json::from_str(r#"{"type":"resize","cols":80,"rows":24}"#).unwrap();
+        assert!(matches!(
+            resize,
+            TerminalInbound::Resize { cols: 80, rows: 24 }
+        ));
+
+        assert!(serde_json::from_str::<TerminalInbound>(r#"{"type":"paste"}"#).is_err());
+    }
+}
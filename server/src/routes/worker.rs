@@ -1,15 +1,14 @@
+use super::exec;
 use crate::{
     db,
-    globals::PROJECT_DIR,
     realtime::{self, RealtimeEvent},
-    shared::shell,
-    system::queue::QueueCoordinator,
+    system::{api_auth::ApiCaller, audit, queue::QueueCoordinator, runner::Persona},
     threads,
     threads::queue_manager::QueueManagerError,
 };
-use axum::{Json, extract::Path as AxumPath, http::StatusCode};
-use openapi::models::{ExecCommandInput, ExecResult, Worker};
-use std::path::{Path, PathBuf};
+use axum::{Extension, Json, extract::Path as AxumPath, http::StatusCode, response::Response};
+use openapi::models::{ExecCommandInput, ExecResult, QueueState, Worker};
+use serde_json::json;
 use tracing::{error, info, warn};
 
 pub async fn list_workers() -> Json<Vec<Worker>> {
@@ -17,12 +16,15 @@ pub async fn list_workers() -> Json<Vec<Worker>> {
     Json(workers)
 }
 
-pub async fn create_worker() -> Result<(StatusCode, Json<Worker>), StatusCode> {
+pub async fn create_worker(
+    Extension(caller): Extension<ApiCaller>,
+) -> Result<(StatusCode, Json<Worker>), StatusCode> {
     match db::worker::create_worker().await {
         Ok(worker) => {
             QueueCoordinator::global().register_worker(worker.id);
             broadcast_worker_snapshot().await;
             info!(worker_id = worker.id, "created worker worktree");
+            audit::record(&caller, "worker.create", json!({ "worker_id": worker.id })).await;
             Ok((StatusCode::CREATED, Json(worker)))
         }
         Err(err) => {
@@ -32,17 +34,25 @@ pub async fn create_worker() -> Result<(StatusCode, Json<Worker>), StatusCode> {
     }
 }
 
-pub async fn delete_worker(AxumPath(_worker_id): AxumPath<i64>) -> StatusCode {
-    db::worker::delete_worker(_worker_id).await;
+pub async fn delete_worker(
+    AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
+) -> StatusCode {
+    db::worker::delete_worker(worker_id).await;
+    audit::record(&caller, "worker.delete", json!({ "worker_id": worker_id })).await;
     StatusCode::NO_CONTENT
 }
 
-pub async fn delete_worker_session(AxumPath(worker_id): AxumPath<i64>) -> StatusCode {
+pub async fn delete_worker_session(
+    AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
+) -> StatusCode {
     let owner = format!("ws{worker_id}");
     if let Err(err) = db::session::delete_session(&owner).await {
         warn!(?err, worker_id, "failed to clear worker session");
         StatusCode::INTERNAL_SERVER_ERROR
     } else {
+        audit::record(&caller, "session.reset", json!({ "target": owner })).await;
         realtime::publish(RealtimeEvent::WorkerThread {
             worker_id,
             thread_id: None,
@@ -53,34 +63,35 @@ pub async fn delete_worker_session(AxumPath(worker_id): AxumPath<i64>) -> Status
 
 pub async fn exec_worker_command(
     AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
     Json(payload): Json<ExecCommandInput>,
 ) -> Result<Json<ExecResult>, StatusCode> {
-    let command = payload.command.trim();
-    if command.is_empty() {
-        return Err(StatusCode::BAD_REQUEST);
-    }
-    let workspace_root = Path::new(PROJECT_DIR.as_str());
-    let workspace: PathBuf = workspace_root.join(format!("ws{worker_id}"));
-    let working_dir =
-        shell::resolve_working_dir(workspace_root, &workspace, payload.cwd.as_deref()).map_err(
-            |err| {
-                error!(?err, worker_id, "invalid working directory");
-                StatusCode::BAD_REQUEST
-            },
-        )?;
-    let result = shell::run_shell_command(&working_dir, command)
-        .await
-        .map_err(|err| {
-            error!(?err, worker_id, "failed to execute worker command");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
-    Ok(Json(result))
+    exec::run(&caller, Persona::Worker(worker_id), payload).await
 }
 
-pub async fn terminate_worker(AxumPath(worker_id): AxumPath<i64>) -> StatusCode {
+pub async fn stream_worker_command(
+    AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
+    Json(payload): Json<ExecCommandInput>,
+) -> Response {
+    exec::stream(&caller, Persona::Worker(worker_id), payload).await
+}
+
+pub async fn terminate_worker(
+    AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
+) -> StatusCode {
     let handles = threads::thread_handles();
     match handles.queue.kill_worker(worker_id).await {
-        Ok(_) => StatusCode::ACCEPTED,
+        Ok(_) => {
+            audit::record(
+                &caller,
+                "run.kill",
+                json!({ "target": format!("ws{worker_id}") }),
+            )
+            .await;
+            StatusCode::ACCEPTED
+        }
         Err(QueueManagerError::WorkerNotRunning(_)) => StatusCode::CONFLICT,
         Err(err) => {
             error!(?err, worker_id, "failed to terminate worker process");
@@ -89,6 +100,47 @@ pub async fn terminate_worker(AxumPath(worker_id): AxumPath<i64>) -> StatusCode
     }
 }
 
+pub async fn pause_worker(
+    AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
+) -> Result<Json<QueueState>, StatusCode> {
+    ensure_worker_exists(worker_id).await?;
+    let handles = threads::thread_handles();
+    if let Err(err) = handles.queue.pause_worker(worker_id).await {
+        error!(?err, worker_id, "failed to pause worker dispatch");
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let coordinator = QueueCoordinator::global();
+    coordinator.pause_worker(worker_id);
+    audit::record(&caller, "worker.pause", json!({ "worker_id": worker_id })).await;
+    Ok(Json(coordinator.queue_state()))
+}
+
+pub async fn resume_worker(
+    AxumPath(worker_id): AxumPath<i64>,
+    Extension(caller): Extension<ApiCaller>,
+) -> Result<Json<QueueState>, StatusCode> {
+    ensure_worker_exists(worker_id).await?;
+    let handles = threads::thread_handles();
+    if let Err(err) = handles.queue.resume_worker(worker_id).await {
+        error!(?err, worker_id, "failed to resume worker dispatch");
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let coordinator = QueueCoordinator::global();
+    coordinator.resume_worker(worker_id);
+    audit::record(&caller, "worker.resume", json!({ "worker_id": worker_id })).await;
+    Ok(Json(coordinator.queue_state()))
+}
+
+async fn ensure_worker_exists(worker_id: i64) -> Result<(), StatusCode> {
+    let workers = db::worker::list_workers().await;
+    if workers.iter().any(|worker| worker.id == worker_id) {
+        Ok(())
+    } else {
+        Err(StatusCode::NOT_FOUND)
+    }
+}
+
 async fn broadcast_worker_snapshot() {
     let workers = db::worker::list_workers().await;
     realtime::publish(RealtimeEvent::WorkersSnapshot { workers });
@@ -4,20 +4,39 @@ use crate::{
     system::{queue::QueueCoordinator, strategy::StrategyState},
 };
 use axum::{
-    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    extract::{
+        Query,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
     response::IntoResponse,
 };
 use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
 use serde_json::json;
 use tokio::select;
 use tokio::sync::broadcast;
-use tracing::debug;
+use tracing::{debug, error};
 
-pub async fn websocket_handler(ws: WebSocketUpgrade) -> impl IntoResponse {
-    ws.on_upgrade(handle_socket)
+/// Most entries replayed for `since_id`; clients page the rest with `GET /feed?after_id=`.
+const FEED_BACKFILL_LIMIT: i64 = 500;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct WsParams {
+    /// Last feed id the client already has; newer entries are replayed after the snapshots.
+    since_id: Option<i64>,
+}
+
+pub async fn websocket_handler(
+    Query(params): Query<WsParams>,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| handle_socket(socket, params.since_id))
 }
 
-async fn handle_socket(mut socket: WebSocket) {
+async fn handle_socket(mut socket: WebSocket, since_id: Option<i64>) {
+    // Subscribe before backfilling so nothing published in between is lost.
+    let mut feed_rx = realtime::subscribe();
+
     if let Err(error) = socket.send(Message::Text("ready".into())).await {
         debug!(?error, "failed to send websocket greeting");
         return;
@@ -35,8 +54,19 @@ async fn handle_socket(mut socket: WebSocket) {
         debug!(?error, "failed to send initial strategy state");
     }
 
+    if let Err(error) = send_qa_inbox(&mut socket).await {
+        debug!(?error, "failed to send initial QA inbox");
+    }
+
+    let mut backfilled_to = None;
+    if let Some(since_id) = since_id {
+        match send_feed_backfill(&mut socket, since_id).await {
+            Ok(last_id) => backfilled_to = last_id,
+            Err(error) => debug!(?error, "failed to send feed backfill"),
+        }
+    }
+
     let (mut sender, mut receiver) = socket.split();
-    let mut feed_rx = realtime::subscribe();
 
     loop {
         select! {
@@ -50,6 +80,9 @@ async fn handle_socket(mut socket: WebSocket) {
             event = feed_rx.recv() => {
                 match event {
                     Ok(RealtimeEvent::FeedEntry(entry)) => {
+                        if backfilled_to.is_some_and(|last_id| entry.id <= last_id) {
+                            continue;
+                        }
                         let payload = json!({"type": "feed_entry", "entry": entry});
                         if sender
                             .send(Message::Text(payload.to_string().into()))
@@ -69,8 +102,23 @@ async fn handle_socket(mut socket: WebSocket) {
                             break;
                         }
                     }
-                    Ok(RealtimeEvent::QueueState { paused }) => {
-                        let payload = json!({"type": "queue_state", "paused": paused});
+                    Ok(RealtimeEvent::QueueState(state)) => {
+                        let payload = json!({
+                            "type": "queue_state",
+                            "paused": state.paused,
+                            "draining": state.draining,
+                            "paused_workers": state.paused_workers,
+                        });
+                        if sender
+                            .send(Message::Text(payload.to_string().into()))
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(RealtimeEvent::QueueDrained) => {
+                        let payload = json!({"type": "queue_drained"});
                         if sender
                             .send(Message::Text(payload.to_string().into()))
                             .await
@@ -116,6 +164,16 @@ async fn handle_socket(mut socket: WebSocket) {
                             break;
                         }
                     }
+                    Ok(RealtimeEvent::QaInbox { unread }) => {
+                        let payload = json!({"type": "qa_inbox", "unread": unread});
+                        if sender
+                            .send(Message::Text(payload.to_string().into()))
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
                     Ok(RealtimeEvent::OrchestratorThread { thread_id }) => {
                         let payload = json!({
                             "type": "orchestrator_thread",
@@ -146,11 +204,70 @@ async fn send_worker_snapshot(socket: &mut WebSocket) -> Result<(), axum::Error>
     socket.send(Message::Text(payload.to_string().into())).await
 }
 
+/// Replay entries after `since_id` oldest first as `feed_entry` messages, then a
+/// `feed_backfill` marker saying where the replay stopped and whether it caught up.
+/// If the feed cannot be read, an `error` message replaces both.
+async fn send_feed_backfill(
+    socket: &mut WebSocket,
+    since_id: i64,
+) -> Result<Option<i64>, axum::Error> {
+    let entries = match db::feed::list_feed(db::feed::FeedFilters {
+        after_id: Some(since_id),
+        limit: Some(FEED_BACKFILL_LIMIT),
+        ..Default::default()
+    })
+    .await
+    {
+        Ok(entries) => entries,
+        Err(err) => {
+            error!(?err, since_id, "failed to load feed backfill");
+            let payload = json!({
+                "type": "error",
+                "scope": "feed_backfill",
+                "since_id": since_id,
+                "message": "failed to load missed feed entries; fetch them with GET /feed",
+            });
+            socket
+                .send(Message::Text(payload.to_string().into()))
+                .await?;
+            return Ok(None);
+        }
+    };
+    let complete = (entries.len() as i64) < FEED_BACKFILL_LIMIT;
+    let last_id = entries.first().map(|entry| entry.id);
+    for entry in entries.into_iter().rev() {
+        let payload = json!({"type": "feed_entry", "entry": entry});
+        socket
+            .send(Message::Text(payload.to_string().into()))
+            .await?;
+    }
+    let payload = json!({
+        "type": "feed_backfill",
+        "last_id": last_id.unwrap_or(since_id),
+        "complete": complete,
+    });
+    socket
+        .send(Message::Text(payload.to_string().into()))
+        .await?;
+    Ok(last_id)
+}
+
 async fn send_queue_state(socket: &mut WebSocket) -> Result<(), axum::Error> {
-    let paused = QueueCoordinator::global().is_paused();
+    let state = QueueCoordinator::global().queue_state();
     let payload = json!({
         "type": "queue_state",
-        "paused": paused,
+        "paused": state.paused,
+        "draining": state.draining,
+        "paused_workers": state.paused_workers,
+    });
+    socket.send(Message::Text(payload.to_string().into())).await
+}
+
+async fn send_qa_inbox(socket: &mut WebSocket) -> Result<(), axum::Error> {
+    let unread = db::qa_inbox::list_unread().await.unwrap_or_default();
+    let payload = json!({
+        "type": "qa_inbox",
+        "unread": unread,
     });
     socket.send(Message::Text(payload.to_string().into())).await
 }
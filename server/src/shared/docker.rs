@@ -1,19 +1,24 @@
-use once_cell::sync::Lazy;
+use crate::system::container_runtime;
+use openapi::models::ContainerLimits;
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use tracing::warn;
 
 #[derive(Debug, Default, Clone)]
 pub struct DockerRunBuilder {
+    program: Option<String>,
     image: Option<String>,
     command: Option<Vec<String>>,
+    name: Option<String>,
     remove_container: bool,
     interactive: bool,
+    tty: bool,
     attach_streams: Vec<String>,
     user: Option<String>,
+    userns: Option<String>,
     workdir: Option<PathBuf>,
     volumes: Vec<(PathBuf, PathBuf, Option<String>)>,
     env_vars: Vec<(String, String)>,
+    inherited_env: Vec<String>,
+    limits: ContainerLimits,
 }
 
 impl DockerRunBuilder {
@@ -24,6 +29,17 @@ impl DockerRunBuilder {
         }
     }
 
+    /// CLI to invoke instead of `docker` (e.g. `podman`).
+    pub fn program(mut self, program: impl Into<String>) -> Self {
+        self.program = Some(program.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     pub fn remove_container(mut self, enabled: bool) -> Self {
         self.remove_container = enabled;
         self
@@ -34,6 +50,11 @@ impl DockerRunBuilder {
         self
     }
 
+    pub fn tty(mut self, enabled: bool) -> Self {
+        self.tty = enabled;
+        self
+    }
+
     pub fn attach(mut self, stream: impl Into<String>) -> Self {
         self.attach_streams.push(stream.into());
         self
@@ -44,6 +65,11 @@ impl DockerRunBuilder {
         self
     }
 
+    pub fn userns(mut self, mode: impl Into<String>) -> Self {
+        self.userns = Some(mode.into());
+        self
+    }
+
     pub fn workdir(mut self, dir: impl Into<PathBuf>) -> Self {
         self.workdir = Some(dir.into());
         self
@@ -68,6 +94,19 @@ impl DockerRunBuilder {
         self
     }
 
+    /// Forward `key` from the CLI's own environment (`-e KEY`), keeping its value off
+    /// the argv.
+    pub fn inherit_env(mut self, key: impl Into<String>) -> Self {
+        self.inherited_env.push(key.into());
+        self
+    }
+
+    /// Resource and network limits; check them with [`validate_limits`] first.
+    pub fn limits(mut self, limits: ContainerLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn command<I, S>(mut self, command: I) -> Self
     where
         I: IntoIterator<Item = S>,
@@ -78,16 +117,26 @@ impl DockerRunBuilder {
     }
 
     pub fn build(self) -> Vec<String> {
-        let mut args = vec!["docker".to_string(), "run".to_string()];
+        let program = self.program.unwrap_or_else(|| "docker".to_string());
+        let mut args = vec![program, "run".to_string()];
 
         if self.interactive {
             args.push("-i".to_string());
         }
 
+        if self.tty {
+            args.push("-t".to_string());
+        }
+
         if self.remove_container {
             args.push("--rm".to_string());
         }
 
+        if let Some(name) = self.name {
+            args.push("--name".to_string());
+            args.push(name);
+        }
+
         for stream in self.attach_streams {
             args.push("-a".to_string());
             args.push(stream);
@@ -98,6 +147,10 @@ impl DockerRunBuilder {
             args.push(user);
         }
 
+        if let Some(userns) = self.userns {
+            args.push(format!("--userns={userns}"));
+        }
+
         if let Some(workdir) = self.workdir {
             args.push("--workdir".to_string());
             args.push(workdir.display().to_string());
@@ -118,6 +171,13 @@ impl DockerRunBuilder {
             args.push(format!("{key}={value}"));
         }
 
+        for key in self.inherited_env {
+            args.push("-e".to_string());
+            args.push(key);
+        }
+
+        args.extend(render_limits(self.limits));
+
         if let Some(image) = self.image {
             args.push(image);
         }
@@ -130,38 +190,190 @@ impl DockerRunBuilder {
     }
 }
 
-static DEFAULT_DOCKER_HOST: Lazy<String> = Lazy::new(resolve_default_docker_host);
+fn render_limits(limits: ContainerLimits) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(cpus) = limits.cpus {
+        args.push(format!("--cpus={cpus}"));
+    }
+    if let Some(memory) = limits.memory {
+        args.push(format!("--memory={memory}"));
+    }
+    if let Some(pids) = limits.pids_limit {
+        args.push(format!("--pids-limit={pids}"));
+    }
+    if let Some(network) = limits.network {
+        args.push(format!("--network={network}"));
+    }
+    if limits.read_only == Some(true) {
+        args.push("--read-only".to_string());
+    }
+    for mount in limits.tmpfs.unwrap_or_default() {
+        args.push("--tmpfs".to_string());
+        args.push(mount);
+    }
+    for host in limits.extra_hosts.unwrap_or_default() {
+        args.push("--add-host".to_string());
+        args.push(host);
+    }
+    args
+}
+
+/// Reject limits that `docker run` would refuse or that would splice extra flags
+/// into the argv.
+pub fn validate_limits(limits: &ContainerLimits) -> Result<(), String> {
+    if let Some(cpus) = limits.cpus.filter(|cpus| !cpus.is_finite() || *cpus <= 0.0) {
+        return Err(format!("cpus must be a positive number, got {cpus}"));
+    }
+    if let Some(memory) = limits.memory.as_deref().filter(|m| !is_memory_size(m)) {
+        return Err(format!(
+            "memory '{memory}' must be a positive size such as 512m or 2g"
+        ));
+    }
+    if let Some(pids) = limits.pids_limit.filter(|pids| *pids <= 0) {
+        return Err(format!("pids_limit must be positive, got {pids}"));
+    }
+    if let Some(network) = limits.network.as_deref().filter(|n| !is_network_name(n)) {
+        return Err(format!(
+            "network '{network}' must be none, bridge or a network name"
+        ));
+    }
+    for mount in limits.tmpfs.iter().flatten() {
+        let path = mount.split(':').next().unwrap_or_default();
+        if !path.starts_with('/') || mount.chars().any(char::is_whitespace) {
+            return Err(format!(
+                "tmpfs '{mount}' must be an absolute container path with optional :options"
+            ));
+        }
+    }
+    for entry in limits.extra_hosts.iter().flatten() {
+        let valid = entry.split_once(':').is_some_and(|(name, ip)| {
+            !name.is_empty()
+                && !ip.is_empty()
+                && !name.starts_with('-')
+                && !entry.chars().any(char::is_whitespace)
+        });
+        if !valid {
+            return Err(format!("extra host '{entry}' must look like name:ip"));
+        }
+    }
+    Ok(())
+}
+
+fn is_memory_size(value: &str) -> bool {
+    let digits = value.trim_end_matches(['b', 'k', 'm', 'g', 'B', 'K', 'M', 'G']);
+    value.len() - digits.len() <= 1
+        && !digits.is_empty()
+        && digits.chars().all(|c| c.is_ascii_digit())
+        && digits.parse::<u64>().is_ok_and(|n| n > 0)
+}
 
-/// Determine the MCP URL that containers should use when talking to the host
-/// server. On macOS with OrbStack we must use `host.orb.internal`, otherwise
-/// Docker's default hostname works.
+fn is_network_name(value: &str) -> bool {
+    !value.is_empty()
+        && !value.starts_with('-')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// Determine the MCP URL that agents should use when talking to the host
+/// server. The hostname follows the configured container runtime.
 pub fn ensure_default_mcp_url(port: u16) -> String {
-    format!("http://{}:{port}/mcp", DEFAULT_DOCKER_HOST.as_str())
+    format!(
+        "http://{}:{port}/mcp",
+        container_runtime::current().server_host()
+    )
 }
 
-fn resolve_default_docker_host() -> String {
-    if cfg!(target_os = "macos") {
-        match Command::new("docker").arg("context").arg("show").output() {
-            Ok(output) if output.status.success() => {
-                let context = String::from_utf8_lossy(&output.stdout).trim().to_string();
-                if context == "orbstack" {
-                    return "host.orb.internal".to_string();
-                }
-            }
-            Ok(output) => {
-                warn!(
-                    status = ?output.status.code(),
-                    "`docker context show` exited with non-zero status; using default hostname"
-                );
-            }
-            Err(err) => {
-                warn!(
-                    ?err,
-                    "failed to inspect docker context; using default hostname"
-                );
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_limits_before_image() {
+        let limits = ContainerLimits {
+            cpus: Some(1.5),
+            memory: Some("2g".into()),
+            pids_limit: Some(256),
+            network: Some("none".into()),
+            read_only: Some(true),
+            tmpfs: Some(vec!["/tmp:size=64m".into()]),
+            extra_hosts: Some(vec!["registry:10.0.0.5".into()]),
+        };
+        validate_limits(&limits).expect("limits are valid");
+        let args = DockerRunBuilder::new("image")
+            .limits(limits)
+            .command(["codex"])
+            .build();
+        assert_eq!(
+            args,
+            [
+                "docker",
+                "run",
+                "--cpus=1.5",
+                "--memory=2g",
+                "--pids-limit=256",
+                "--network=none",
+                "--read-only",
+                "--tmpfs",
+                "/tmp:size=64m",
+                "--add-host",
+                "registry:10.0.0.5",
+                "image",
+                "codex",
+            ]
+        );
     }
 
-    "host.docker.internal".to_string()
+    #[test]
+    fn inherited_env_carries_no_value() {
+        let args = DockerRunBuilder::new("image")
+            .env("HOME", "/home/codex")
+            .inherit_env("SECRET")
+            .build();
+        assert_eq!(
+            args,
+            [
+                "docker",
+                "run",
+                "-e",
+                "HOME=/home/codex",
+                "-e",
+                "SECRET",
+                "image"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_limits() {
+        let cases = [
+            ContainerLimits {
+                cpus: Some(0.0),
+                ..Default::default()
+            },
+            ContainerLimits {
+                memory: Some("2 gb".into()),
+                ..Default::default()
+            },
+            ContainerLimits {
+                pids_limit: Some(-1),
+                ..Default::default()
+            },
+            ContainerLimits {
+                network: Some("--privileged".into()),
+                ..Default::default()
+            },
+            ContainerLimits {
+                tmpfs: Some(vec!["tmp".into()]),
+                ..Default::default()
+            },
+            ContainerLimits {
+                extra_hosts: Some(vec!["registry".into()]),
+                ..Default::default()
+            },
+        ];
+        for limits in cases {
+            assert!(validate_limits(&limits).is_err(), "{limits:?} should fail");
+        }
+    }
 }
@@ -1,4 +1,5 @@
 pub mod codex_exec;
 pub mod docker;
 pub mod git;
+pub mod secret;
 pub mod shell;
@@ -0,0 +1,30 @@
+use std::hint::black_box;
+
+/// Compare two secrets without short-circuiting on the first differing byte, so
+/// response timing does not reveal how much of a guess was right. Only the length
+/// is allowed to leak.
+pub fn constant_time_eq(expected: &str, candidate: &str) -> bool {
+    let (expected, candidate) = (expected.as_bytes(), candidate.as_bytes());
+    if expected.len() != candidate.len() {
+        return false;
+    }
+    let diff = expected
+        .iter()
+        .zip(candidate)
+        .fold(0u8, |acc, (a, b)| acc | black_box(a ^ b));
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::constant_time_eq;
+
+    #[test]
+    fn matches_only_identical_secrets() {
+        assert!(constant_time_eq("s3cret", "s3cret"));
+        assert!(constant_time_eq("", ""));
+        assert!(!constant_time_eq("s3cret", "s3cres"));
+        assert!(!constant_time_eq("s3cret", "s3cre"));
+        assert!(!constant_time_eq("s3cret", "s3cret!"));
+    }
+}
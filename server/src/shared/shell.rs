@@ -1,9 +1,17 @@
 use openapi::models::ExecResult;
+use parking_lot::Mutex;
 use path_clean::PathClean;
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use tokio::process::Command;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::{Child, Command};
+use tokio::sync::mpsc;
+use tokio::time;
 
 #[derive(Debug, Error)]
 pub enum ShellError {
@@ -45,22 +53,190 @@ fn canonicalize_or_clean(path: &Path) -> PathBuf {
     fs::canonicalize(path).unwrap_or_else(|_| path.clean())
 }
 
-pub async fn run_shell_command(cwd: &Path, command: &str) -> Result<ExecResult, ShellError> {
-    let output = Command::new("bash")
-        .arg("-lc")
-        .arg(command)
-        .current_dir(cwd)
-        .output()
-        .await?;
+/// Caps applied by [`run_command`].
+#[derive(Clone, Copy, Debug)]
+pub struct ExecLimits {
+    pub timeout: Duration,
+    pub max_output_bytes: usize,
+}
+
+/// Output chunk forwarded while a command runs.
+#[derive(Clone, Debug)]
+pub enum ExecOutput {
+    Stdout(String),
+    Stderr(String),
+}
+
+#[derive(Default)]
+struct Capture {
+    bytes: Vec<u8>,
+    truncated: bool,
+}
+
+/// `bash -lc <command>`.
+pub fn shell_argv(command: &str) -> Vec<String> {
+    vec!["bash".to_string(), "-lc".to_string(), command.to_string()]
+}
+
+/// Run `argv`, killing it after `limits.timeout` and keeping at most
+/// `limits.max_output_bytes` of each stream. Chunks are sent to `output` as they arrive,
+/// and the command is killed early if the receiving side of `output` goes away.
+pub async fn run_command(
+    argv: &[String],
+    cwd: Option<&Path>,
+    command: &str,
+    limits: ExecLimits,
+    output: Option<mpsc::Sender<ExecOutput>>,
+) -> Result<ExecResult, ShellError> {
+    let (program, args) = argv
+        .split_first()
+        .ok_or_else(|| ShellError::Io(io::Error::new(io::ErrorKind::InvalidInput, "empty argv")))?;
+    let mut process = Command::new(program);
+    process
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // Own process group, so a timeout also reaches anything the shell started.
+    #[cfg(unix)]
+    process.process_group(0);
+    if let Some(cwd) = cwd {
+        process.current_dir(cwd);
+    }
+    let mut child = process.spawn()?;
+    let listener = output.clone();
 
-    let exit_code = output.status.code().unwrap_or_default();
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let stdout = Arc::new(Mutex::new(Capture::default()));
+    let stderr = Arc::new(Mutex::new(Capture::default()));
+    let readers = [
+        child.stdout.take().map(|pipe| {
+            tokio::spawn(capture(
+                pipe,
+                stdout.clone(),
+                limits,
+                output.clone(),
+                ExecOutput::Stdout,
+            ))
+        }),
+        child.stderr.take().map(|pipe| {
+            tokio::spawn(capture(
+                pipe,
+                stderr.clone(),
+                limits,
+                output,
+                ExecOutput::Stderr,
+            ))
+        }),
+    ];
+
+    let disconnected = async {
+        match &listener {
+            Some(listener) => listener.closed().await,
+            None => std::future::pending().await,
+        }
+    };
+    let (status, timed_out) = tokio::select! {
+        status = child.wait() => (Some(status?), false),
+        _ = time::sleep(limits.timeout) => {
+            kill_group(&mut child).await;
+            (None, true)
+        }
+        _ = disconnected => {
+            kill_group(&mut child).await;
+            (None, false)
+        }
+    };
+    for reader in readers.into_iter().flatten() {
+        // Grandchildren may keep the pipes open after a kill.
+        let abort = reader.abort_handle();
+        if time::timeout(READER_GRACE, reader).await.is_err() {
+            abort.abort();
+        }
+    }
 
+    let stdout = std::mem::take(&mut *stdout.lock());
+    let stderr = std::mem::take(&mut *stderr.lock());
     Ok(ExecResult {
         command: command.to_string(),
-        exit_code,
-        stdout,
-        stderr,
+        exit_code: status.and_then(|status| status.code()).unwrap_or(-1),
+        stdout: String::from_utf8_lossy(&stdout.bytes).to_string(),
+        stderr: String::from_utf8_lossy(&stderr.bytes).to_string(),
+        timed_out: Some(timed_out),
+        truncated: Some(stdout.truncated || stderr.truncated),
     })
 }
+
+const READER_GRACE: Duration = Duration::from_secs(2);
+
+/// Kill `child` and every process left in its group.
+async fn kill_group(child: &mut Child) {
+    #[cfg(unix)]
+    if let Some(pid) = child.id() {
+        let _ = Command::new("kill")
+            .args(["-KILL", "--", &format!("-{pid}")])
+            .status()
+            .await;
+    }
+    let _ = child.kill().await;
+}
+
+async fn capture(
+    mut pipe: impl AsyncRead + Unpin,
+    sink: Arc<Mutex<Capture>>,
+    limits: ExecLimits,
+    output: Option<mpsc::Sender<ExecOutput>>,
+    wrap: fn(String) -> ExecOutput,
+) {
+    let mut buf = [0u8; 8192];
+    // Bytes of a character split across reads, held back until it completes.
+    let mut pending = Vec::new();
+    loop {
+        let read = match pipe.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        let kept = {
+            let mut capture = sink.lock();
+            let room = limits.max_output_bytes.saturating_sub(capture.bytes.len());
+            let kept = read.min(room);
+            capture.bytes.extend_from_slice(&buf[..kept]);
+            capture.truncated |= kept < read;
+            kept
+        };
+        let Some(output) = output.as_ref().filter(|_| kept > 0) else {
+            continue;
+        };
+        pending.extend_from_slice(&buf[..kept]);
+        let complete = pending.len() - incomplete_tail(&pending);
+        if complete > 0 {
+            let rest = pending.split_off(complete);
+            let text = String::from_utf8_lossy(&pending).to_string();
+            pending = rest;
+            let _ = output.send(wrap(text)).await;
+        }
+    }
+    if let Some(output) = output.as_ref().filter(|_| !pending.is_empty()) {
+        let _ = output
+            .send(wrap(String::from_utf8_lossy(&pending).to_string()))
+            .await;
+    }
+}
+
+/// Length of a UTF-8 sequence at the end of `bytes` that is still missing bytes.
+fn incomplete_tail(bytes: &[u8]) -> usize {
+    for back in 1..=bytes.len().min(3) {
+        let byte = bytes[bytes.len() - back];
+        if byte & 0xC0 == 0x80 {
+            continue;
+        }
+        let width = match byte {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1,
+        };
+        return if width > back { back } else { 0 };
+    }
+    0
+}
@@ -0,0 +1,116 @@
+use crate::{
+    models::agent_items::AgentItem,
+    system::{
+        codex_backend::CodexBackend,
+        launcher::{LaunchError, LaunchPlan, LaunchRequest, WizardLaunchRequest},
+        runner::Persona,
+    },
+};
+use openapi::models::AgentBackends;
+use parking_lot::RwLock;
+use std::sync::{Arc, LazyLock};
+
+pub const DEFAULT_BACKEND: &str = "codex";
+
+/// An agent CLI (or model runner) the farm can drive. Implementations own
+/// everything specific to their tool: how a turn is launched, whether a
+/// session can be resumed, and how stdout maps onto [`AgentItem`]s.
+pub trait AgentBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Whether [`plan`](Self::plan) can continue a previous session. When false the
+    /// queue manager starts every turn fresh.
+    fn supports_resume(&self) -> bool;
+
+    fn plan(&self, request: &LaunchRequest) -> Result<LaunchPlan, LaunchError>;
+
+    /// Plan a task wizard turn. Backends that cannot drive the wizard keep this default.
+    fn plan_wizard(&self, _request: &WizardLaunchRequest) -> Result<LaunchPlan, LaunchError> {
+        Err(LaunchError::Unsupported(format!(
+            "agent backend '{}' cannot run the task wizard",
+            self.name()
+        )))
+    }
+
+    /// Parse one line of stdout. Lines that carry nothing of interest yield no items.
+    fn parse_line(&self, line: &str) -> Vec<AgentItem>;
+
+    /// Whether `line` belongs to the backend's event stream at all, as opposed to stray
+    /// output such as a wrapper script's logging.
+    fn is_event(&self, line: &str) -> bool {
+        !self.parse_line(line).is_empty()
+    }
+
+    /// JSON text of the `WorkerTurn`/`OrchestratorTurn` payload, when `item` carries it.
+    fn structured_output<'a>(&self, item: &'a AgentItem) -> Option<&'a str> {
+        match item {
+            AgentItem::Message { text } => Some(text),
+            _ => None,
+        }
+    }
+}
+
+static BACKENDS: LazyLock<Vec<Arc<dyn AgentBackend>>> =
+    LazyLock::new(|| vec![Arc::new(CodexBackend)]);
+
+static SELECTION: LazyLock<RwLock<AgentBackends>> =
+    LazyLock::new(|| RwLock::new(AgentBackends::new()));
+
+pub fn available() -> Vec<&'static str> {
+    BACKENDS.iter().map(|backend| backend.name()).collect()
+}
+
+pub fn get(name: &str) -> Option<Arc<dyn AgentBackend>> {
+    BACKENDS
+        .iter()
+        .find(|backend| backend.name().eq_ignore_ascii_case(name.trim()))
+        .cloned()
+}
+
+pub fn replace(selection: AgentBackends) {
+    *SELECTION.write() = selection;
+}
+
+pub fn reset() {
+    replace(AgentBackends::new());
+}
+
+pub fn snapshot() -> AgentBackends {
+    SELECTION.read().clone()
+}
+
+pub fn for_persona(persona: Persona) -> Arc<dyn AgentBackend> {
+    let selection = SELECTION.read();
+    let name = match persona {
+        Persona::Orchestrator => selection.orchestrator.as_deref(),
+        Persona::Worker(_) => selection.worker.as_deref(),
+    };
+    for_run(name)
+}
+
+/// The task wizard plans tasks for the orchestrator, so it shares its backend.
+pub fn for_wizard() -> Arc<dyn AgentBackend> {
+    for_persona(Persona::Orchestrator)
+}
+
+/// Backend named in a run's metadata. Recordings made before the backend was stored
+/// came from the default backend.
+pub fn for_run(name: Option<&str>) -> Arc<dyn AgentBackend> {
+    name.and_then(get)
+        .unwrap_or_else(|| get(DEFAULT_BACKEND).expect("default agent backend is registered"))
+}
+
+pub fn validate(selection: &AgentBackends) -> Result<(), String> {
+    for (persona, name) in [
+        ("orchestrator", selection.orchestrator.as_deref()),
+        ("worker", selection.worker.as_deref()),
+    ] {
+        if let Some(name) = name.filter(|name| get(name).is_none()) {
+            return Err(format!(
+                "agent backend '{name}' for {persona} is unknown (available: {})",
+                available().join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
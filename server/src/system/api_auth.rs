@@ -0,0 +1,142 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        LazyLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use openapi::models::{ApiRole, ApiToken};
+use parking_lot::RwLock;
+use tracing::warn;
+
+use crate::shared::secret::constant_time_eq;
+
+#[derive(Clone, Debug, Default)]
+struct AuthStore {
+    tokens: Vec<ApiToken>,
+    cors_origins: Vec<String>,
+}
+
+static STORE: LazyLock<RwLock<AuthStore>> = LazyLock::new(|| RwLock::new(AuthStore::default()));
+
+/// Set once an anonymous request has been let through since tokens last changed.
+static ANONYMOUS_SEEN: AtomicBool = AtomicBool::new(false);
+
+/// Who made an API request. Inserted into request extensions by the auth layer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiCaller {
+    pub name: String,
+    pub role: ApiRole,
+}
+
+impl ApiCaller {
+    /// Caller used while no tokens are configured. Read-only, so a fresh install
+    /// never hands out exec, terminals or config edits to whoever reaches the port.
+    fn anonymous() -> Self {
+        Self {
+            name: "anonymous".to_string(),
+            role: ApiRole::Viewer,
+        }
+    }
+}
+
+pub fn reset() {
+    replace(Vec::new(), Vec::new());
+}
+
+pub fn replace(tokens: Vec<ApiToken>, cors_origins: Vec<String>) {
+    if tokens.is_empty() {
+        warn!(
+            "no api_tokens configured; the API is read-only. \
+             Add api_tokens to config.json to drive the queue, edit config or run commands"
+        );
+    }
+    *STORE.write() = AuthStore {
+        tokens,
+        cors_origins,
+    };
+    ANONYMOUS_SEEN.store(false, Ordering::Relaxed);
+}
+
+/// Whether any API token is configured. Without one every caller is an anonymous viewer.
+pub fn tokens_configured() -> bool {
+    !STORE.read().tokens.is_empty()
+}
+
+/// Caller for `token`; anyone is an anonymous viewer while no tokens are
+/// configured, which is logged on the first such request after every config load.
+pub fn authenticate(token: Option<&str>) -> Option<ApiCaller> {
+    let store = STORE.read();
+    if store.tokens.is_empty() {
+        if !ANONYMOUS_SEEN.swap(true, Ordering::Relaxed) {
+            warn!("serving an unauthenticated API request read-only; no api_tokens are configured");
+        }
+        return Some(ApiCaller::anonymous());
+    }
+    let token = token?;
+    // Check every entry so timing does not reveal which token, if any, was close.
+    store
+        .tokens
+        .iter()
+        .fold(None, |found, entry| {
+            if constant_time_eq(&entry.token, token) {
+                Some(entry)
+            } else {
+                found
+            }
+        })
+        .map(|entry| ApiCaller {
+            name: entry.name.clone(),
+            role: entry.role,
+        })
+}
+
+/// Whether a browser on `origin` may call the API.
+pub fn origin_allowed(origin: &str) -> bool {
+    let store = STORE.read();
+    store.cors_origins.is_empty()
+        || store
+            .cors_origins
+            .iter()
+            .any(|allowed| allowed == "*" || allowed.trim_end_matches('/') == origin)
+}
+
+pub fn validate(tokens: &[ApiToken], cors_origins: &[String]) -> Result<(), String> {
+    let mut names = HashSet::new();
+    let mut secrets = HashSet::new();
+    for token in tokens {
+        if token.name.trim().is_empty() {
+            return Err("api_tokens: name must not be empty".to_string());
+        }
+        if !names.insert(token.name.as_str()) {
+            return Err(format!(
+                "api_tokens: '{}' is defined more than once",
+                token.name
+            ));
+        }
+        if token.token.len() < 16 || token.token.chars().any(char::is_whitespace) {
+            return Err(format!(
+                "api_tokens: token for '{}' must be at least 16 characters without whitespace",
+                token.name
+            ));
+        }
+        if !secrets.insert(token.token.as_str()) {
+            return Err(format!("api_tokens: token for '{}' is reused", token.name));
+        }
+    }
+    if !tokens.is_empty() && !tokens.iter().any(|token| token.role == ApiRole::Admin) {
+        return Err("api_tokens: at least one admin token is required".to_string());
+    }
+    for origin in cors_origins {
+        let valid = origin == "*"
+            || ((origin.starts_with("http://") || origin.starts_with("https://"))
+                && !origin.chars().any(char::is_whitespace));
+        if !valid {
+            return Err(format!(
+                "cors_origins: '{origin}' must be '*' or an http(s) origin"
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use serde_json::Value;
+use tracing::error;
+
+use crate::db::audit::{self, NewAuditEntry};
+
+use super::api_auth::ApiCaller;
+
+/// Record `action` by `caller`. Failures are logged rather than failing the
+/// request: the action has usually already taken effect.
+pub async fn record(caller: &ApiCaller, action: &str, params: Value) {
+    let entry = NewAuditEntry {
+        actor: caller.name.clone(),
+        role: caller.role,
+        action: action.to_string(),
+        params,
+    };
+    if let Err(err) = audit::insert_entry(entry).await {
+        error!(?err, actor = %caller.name, action, "failed to record audit entry");
+    }
+}
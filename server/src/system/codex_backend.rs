@@ -0,0 +1,148 @@
+use crate::{
+    models::{
+        agent_items::{AgentItem, FileChange, FileChangeKind, ItemStatus, TodoItem},
+        codex_events::{
+            CodexEvent, CommandExecutionStatus, FileChangeKind as CodexFileChangeKind,
+            McpToolCallStatus, PatchApplyStatus, TurnItemDetail,
+        },
+    },
+    system::{
+        agent_backend::AgentBackend,
+        launcher::{LaunchError, LaunchPlan, LaunchRequest, WizardLaunchRequest},
+        runner::{self, CommandPlan},
+    },
+};
+
+/// `codex exec --json`, inside the persona's image unless the host runtime is selected.
+pub struct CodexBackend;
+
+impl AgentBackend for CodexBackend {
+    fn name(&self) -> &'static str {
+        "codex"
+    }
+
+    fn supports_resume(&self) -> bool {
+        true
+    }
+
+    fn plan(&self, request: &LaunchRequest) -> Result<LaunchPlan, LaunchError> {
+        launch_plan(
+            self.name(),
+            runner::plan_codex_run(
+                request.persona,
+                request.session_id.as_deref(),
+                request.config.clone(),
+            ),
+        )
+    }
+
+    fn plan_wizard(&self, request: &WizardLaunchRequest) -> Result<LaunchPlan, LaunchError> {
+        launch_plan(
+            self.name(),
+            runner::plan_codex_wizard(
+                request.session_id.as_deref(),
+                &request.workspace,
+                request.config.clone(),
+            ),
+        )
+    }
+
+    fn parse_line(&self, line: &str) -> Vec<AgentItem> {
+        let Ok(event) = serde_json::from_str::<CodexEvent>(line) else {
+            return Vec::new();
+        };
+        match event {
+            CodexEvent::ThreadStarted { thread_id } => vec![AgentItem::SessionStarted {
+                session_id: thread_id,
+            }],
+            CodexEvent::ItemCompleted { item } => vec![convert_detail(item.detail)],
+            CodexEvent::TurnCompleted { usage } => vec![AgentItem::Usage {
+                input_tokens: usage.input_tokens,
+                cached_input_tokens: usage.cached_input_tokens,
+                output_tokens: usage.output_tokens,
+            }],
+            CodexEvent::TurnFailed { error } => vec![AgentItem::TurnFailed {
+                message: error.message,
+            }],
+            CodexEvent::Error { message } => vec![AgentItem::TurnFailed { message }],
+            CodexEvent::TurnStarted
+            | CodexEvent::ItemStarted { .. }
+            | CodexEvent::ItemUpdated { .. } => Vec::new(),
+        }
+    }
+
+    fn is_event(&self, line: &str) -> bool {
+        serde_json::from_str::<CodexEvent>(line).is_ok()
+    }
+}
+
+fn launch_plan(backend: &'static str, plan: CommandPlan) -> Result<LaunchPlan, LaunchError> {
+    let mut args = plan.docker_args;
+    args.extend(plan.codex_args);
+    if args.is_empty() {
+        return Err(LaunchError::EmptyCommand);
+    }
+    let program = args.remove(0);
+    Ok(LaunchPlan {
+        program,
+        args,
+        env: plan.env,
+        working_dir: plan.working_dir,
+        backend,
+    })
+}
+
+fn convert_detail(detail: TurnItemDetail) -> AgentItem {
+    match detail {
+        TurnItemDetail::AgentMessage { text } => AgentItem::Message { text },
+        TurnItemDetail::Reasoning { text } => AgentItem::Reasoning { text },
+        TurnItemDetail::CommandExecution(cmd) => AgentItem::Command {
+            command: cmd.command,
+            output: cmd.aggregated_output,
+            exit_code: cmd.exit_code,
+            status: match cmd.status {
+                CommandExecutionStatus::InProgress => ItemStatus::InProgress,
+                CommandExecutionStatus::Completed => ItemStatus::Completed,
+                CommandExecutionStatus::Failed => ItemStatus::Failed,
+            },
+        },
+        TurnItemDetail::FileChange(file_change) => AgentItem::FileChanges {
+            changes: file_change
+                .changes
+                .into_iter()
+                .map(|entry| FileChange {
+                    path: entry.path,
+                    kind: match entry.kind {
+                        CodexFileChangeKind::Add => FileChangeKind::Add,
+                        CodexFileChangeKind::Delete => FileChangeKind::Delete,
+                        CodexFileChangeKind::Update => FileChangeKind::Update,
+                    },
+                })
+                .collect(),
+            status: match file_change.status {
+                PatchApplyStatus::Completed => ItemStatus::Completed,
+                PatchApplyStatus::Failed => ItemStatus::Failed,
+            },
+        },
+        TurnItemDetail::McpToolCall(call) => AgentItem::ToolCall {
+            server: call.server,
+            tool: call.tool,
+            status: match call.status {
+                McpToolCallStatus::InProgress => ItemStatus::InProgress,
+                McpToolCallStatus::Completed => ItemStatus::Completed,
+                McpToolCallStatus::Failed => ItemStatus::Failed,
+            },
+        },
+        TurnItemDetail::WebSearch { query } => AgentItem::WebSearch { query },
+        TurnItemDetail::TodoList { items } => AgentItem::TodoList {
+            items: items
+                .into_iter()
+                .map(|item| TodoItem {
+                    text: item.text,
+                    completed: item.completed,
+                })
+                .collect(),
+        },
+        TurnItemDetail::ItemError { message } => AgentItem::Error { message },
+    }
+}
@@ -0,0 +1,108 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use openapi::models::{ContainerLimits, ContainerLimitsConfig};
+use parking_lot::RwLock;
+
+use super::{codex_config::AgentKind, worker_profiles};
+use crate::shared::docker::validate_limits;
+
+#[derive(Clone, Debug, Default)]
+struct LimitsStore {
+    orchestrator: ContainerLimits,
+    worker: ContainerLimits,
+    wizard: ContainerLimits,
+    workers: HashMap<i64, ContainerLimits>,
+}
+
+static STORE: LazyLock<RwLock<LimitsStore>> = LazyLock::new(|| RwLock::new(LimitsStore::default()));
+
+pub fn reset() {
+    *STORE.write() = LimitsStore::default();
+}
+
+/// Install `config`; call [`validate`] first; malformed worker keys are dropped.
+pub fn replace(config: ContainerLimitsConfig) {
+    *STORE.write() = LimitsStore {
+        orchestrator: config.orchestrator.map(|l| *l).unwrap_or_default(),
+        worker: config.worker.map(|l| *l).unwrap_or_default(),
+        wizard: config.wizard.map(|l| *l).unwrap_or_default(),
+        workers: config
+            .workers
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(key, limits)| worker_id_from_key(&key).map(|id| (id, limits)))
+            .collect(),
+    };
+}
+
+/// Limits for `kind`. For workers, the assigned profile's limits and then the
+/// worker-specific entry each replace the fields they set on top of the shared
+/// `worker` limits.
+pub fn limits_for(kind: AgentKind, worker_id: Option<i64>) -> ContainerLimits {
+    let store = STORE.read();
+    match kind {
+        AgentKind::Orchestrator => store.orchestrator.clone(),
+        AgentKind::Wizard => store.wizard.clone(),
+        AgentKind::Worker => {
+            let profile = worker_id
+                .and_then(worker_profiles::profile_for)
+                .and_then(|profile| profile.limits);
+            let base = match profile {
+                Some(profile) => merge(&store.worker, &profile),
+                None => store.worker.clone(),
+            };
+            match worker_id.and_then(|id| store.workers.get(&id)) {
+                Some(specific) => merge(&base, specific),
+                None => base,
+            }
+        }
+    }
+}
+
+/// Worker ids with their own entry, in ascending order.
+pub fn configured_workers() -> Vec<i64> {
+    let mut ids: Vec<i64> = STORE.read().workers.keys().copied().collect();
+    ids.sort_unstable();
+    ids
+}
+
+pub fn validate(config: &ContainerLimitsConfig) -> Result<(), String> {
+    for (persona, limits) in [
+        ("orchestrator", config.orchestrator.as_deref()),
+        ("worker", config.worker.as_deref()),
+        ("wizard", config.wizard.as_deref()),
+    ] {
+        if let Some(limits) = limits {
+            validate_limits(limits).map_err(|msg| format!("{persona}: {msg}"))?;
+        }
+    }
+    for (key, limits) in config.workers.iter().flatten() {
+        if worker_id_from_key(key).is_none() {
+            return Err(format!(
+                "workers: '{key}' is not a worktree name such as ws1"
+            ));
+        }
+        validate_limits(limits).map_err(|msg| format!("{key}: {msg}"))?;
+    }
+    Ok(())
+}
+
+/// Worker id for a worktree name such as `ws3`.
+pub fn worker_id_from_key(key: &str) -> Option<i64> {
+    key.strip_prefix("ws")?.parse().ok().filter(|id| *id > 0)
+}
+
+fn merge(base: &ContainerLimits, specific: &ContainerLimits) -> ContainerLimits {
+    ContainerLimits {
+        cpus: specific.cpus.or(base.cpus),
+        memory: specific.memory.clone().or_else(|| base.memory.clone()),
+        pids_limit: specific.pids_limit.or(base.pids_limit),
+        network: specific.network.clone().or_else(|| base.network.clone()),
+        read_only: specific.read_only.or(base.read_only),
+        tmpfs: specific.tmpfs.clone().or_else(|| base.tmpfs.clone()),
+        extra_hosts: specific
+            .extra_hosts
+            .clone()
+            .or_else(|| base.extra_hosts.clone()),
+    }
+}
@@ -0,0 +1,317 @@
+use crate::shared::docker::DockerRunBuilder;
+use openapi::models::config::ContainerRuntime as RuntimeKind;
+use openapi::models::{ContainerLimits, ContainerLimitsConfig, DockerOverrides, WorkerProfile};
+use parking_lot::RwLock;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::LazyLock;
+use tracing::warn;
+
+/// Where agent processes run. Image builds, `run` invocations and the hostname agents
+/// use to reach the MCP endpoint all go through the selected runtime.
+pub trait ContainerRuntime: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Whether agents run inside images built by `make_worker_image`.
+    fn uses_images(&self) -> bool {
+        true
+    }
+
+    /// `run` invocation for `image`, or `None` when agents run as host processes.
+    fn run_builder(&self, image: &str) -> Option<DockerRunBuilder>;
+
+    fn image_exists(&self, tag: &str) -> bool;
+
+    /// Build `tag` from `dockerfile` using `context` as the build context.
+    fn build_image(&self, context: &Path, dockerfile: &str, tag: &str) -> io::Result<()>;
+
+    /// Hostname an agent uses to reach this server.
+    fn server_host(&self) -> String;
+}
+
+pub struct DockerRuntime;
+
+impl ContainerRuntime for DockerRuntime {
+    fn name(&self) -> &'static str {
+        "docker"
+    }
+
+    fn run_builder(&self, image: &str) -> Option<DockerRunBuilder> {
+        Some(DockerRunBuilder::new(image))
+    }
+
+    fn image_exists(&self, tag: &str) -> bool {
+        cli_image_exists("docker", tag)
+    }
+
+    fn build_image(&self, context: &Path, dockerfile: &str, tag: &str) -> io::Result<()> {
+        cli_build("docker", context, dockerfile, tag)
+    }
+
+    fn server_host(&self) -> String {
+        DOCKER_HOST.clone()
+    }
+}
+
+static DOCKER_HOST: LazyLock<String> = LazyLock::new(resolve_docker_host);
+
+/// Hostname Docker containers use to reach the host. On macOS with OrbStack we
+/// must use `host.orb.internal`, otherwise Docker's default hostname works.
+fn resolve_docker_host() -> String {
+    if cfg!(target_os = "macos") {
+        match Command::new("docker").args(["context", "show"]).output() {
+            Ok(output) if output.status.success() => {
+                let context = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if context == "orbstack" {
+                    return "host.orb.internal".to_string();
+                }
+            }
+            Ok(output) => {
+                warn!(
+                    status = ?output.status.code(),
+                    "`docker context show` exited with non-zero status; using default hostname"
+                );
+            }
+            Err(err) => {
+                warn!(
+                    ?err,
+                    "failed to inspect docker context; using default hostname"
+                );
+            }
+        }
+    }
+
+    "host.docker.internal".to_string()
+}
+
+/// Rootless Podman. The invoking user is mapped onto the image's 1000:1000 account so
+/// files written into mounted worktrees stay owned by the host user.
+pub struct PodmanRuntime;
+
+impl ContainerRuntime for PodmanRuntime {
+    fn name(&self) -> &'static str {
+        "podman"
+    }
+
+    fn run_builder(&self, image: &str) -> Option<DockerRunBuilder> {
+        Some(
+            DockerRunBuilder::new(image)
+                .program("podman")
+                .userns("keep-id:uid=1000,gid=1000"),
+        )
+    }
+
+    fn image_exists(&self, tag: &str) -> bool {
+        cli_image_exists("podman", tag)
+    }
+
+    fn build_image(&self, context: &Path, dockerfile: &str, tag: &str) -> io::Result<()> {
+        cli_build("podman", context, dockerfile, tag)
+    }
+
+    fn server_host(&self) -> String {
+        "host.containers.internal".to_string()
+    }
+}
+
+/// Runs agents directly on the host with the worktree as cwd. No isolation; meant for
+/// trusted setups and tests.
+pub struct HostRuntime;
+
+impl ContainerRuntime for HostRuntime {
+    fn name(&self) -> &'static str {
+        "host"
+    }
+
+    fn uses_images(&self) -> bool {
+        false
+    }
+
+    fn run_builder(&self, _image: &str) -> Option<DockerRunBuilder> {
+        None
+    }
+
+    fn image_exists(&self, _tag: &str) -> bool {
+        true
+    }
+
+    fn build_image(&self, _context: &Path, _dockerfile: &str, _tag: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn server_host(&self) -> String {
+        "127.0.0.1".to_string()
+    }
+}
+
+static SELECTED: LazyLock<RwLock<RuntimeKind>> =
+    LazyLock::new(|| RwLock::new(RuntimeKind::default()));
+
+pub fn replace(kind: RuntimeKind) {
+    *SELECTED.write() = kind;
+}
+
+pub fn reset() {
+    replace(RuntimeKind::default());
+}
+
+pub fn selected() -> RuntimeKind {
+    *SELECTED.read()
+}
+
+pub fn current() -> &'static dyn ContainerRuntime {
+    match selected() {
+        RuntimeKind::Docker => &DockerRuntime,
+        RuntimeKind::Podman => &PodmanRuntime,
+        RuntimeKind::Host => &HostRuntime,
+    }
+}
+
+/// Rejects container-only settings under the host runtime, which would silently ignore
+/// them.
+pub fn validate(
+    kind: RuntimeKind,
+    overrides: &DockerOverrides,
+    limits: &ContainerLimitsConfig,
+    profiles: &[WorkerProfile],
+) -> Result<(), String> {
+    if kind != RuntimeKind::Host {
+        return Ok(());
+    }
+    let has_overrides = [
+        &overrides.orchestrator,
+        &overrides.worker,
+        &overrides.wizard,
+    ]
+    .iter()
+    .any(|args| !args.is_empty());
+    if has_overrides {
+        return Err("docker_overrides have no effect with the host runtime".to_string());
+    }
+    let unset = ContainerLimits::default();
+    let has_limits = [&limits.orchestrator, &limits.worker, &limits.wizard]
+        .into_iter()
+        .filter_map(|entry| entry.as_deref())
+        .chain(limits.workers.iter().flat_map(|workers| workers.values()))
+        .any(|entry| *entry != unset);
+    if has_limits {
+        return Err("container_limits have no effect with the host runtime".to_string());
+    }
+    if let Some(profile) = profiles.iter().find(|profile| {
+        profile
+            .limits
+            .as_deref()
+            .is_some_and(|limits| *limits != unset)
+    }) {
+        return Err(format!(
+            "worker profile '{}' sets limits, which have no effect with the host runtime",
+            profile.name
+        ));
+    }
+    Ok(())
+}
+
+fn cli_image_exists(cli: &str, tag: &str) -> bool {
+    Command::new(cli)
+        .args(["image", "inspect", tag])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+fn cli_build(cli: &str, context: &Path, dockerfile: &str, tag: &str) -> io::Result<()> {
+    let mut child = Command::new(cli)
+        .args([
+            "build",
+            "--tag",
+            tag,
+            "--build-arg",
+            "UID=1000",
+            "--build-arg",
+            "GID=1000",
+            "-f",
+            "-",
+        ])
+        .arg(context)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(dockerfile.as_bytes())?;
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "{cli} build for tag {tag} failed with {status}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_limit() -> ContainerLimits {
+        ContainerLimits {
+            memory: Some("2g".into()),
+            ..ContainerLimits::new()
+        }
+    }
+
+    #[test]
+    fn host_runtime_rejects_container_only_settings() {
+        let overrides = DockerOverrides::new(vec![], vec!["--gpus=all".into()], vec![]);
+        assert!(
+            validate(
+                RuntimeKind::Host,
+                &overrides,
+                &ContainerLimitsConfig::new(),
+                &[]
+            )
+            .is_err()
+        );
+
+        let limits = ContainerLimitsConfig {
+            workers: Some([("ws2".to_string(), memory_limit())].into()),
+            ..ContainerLimitsConfig::new()
+        };
+        assert!(validate(RuntimeKind::Host, &DockerOverrides::default(), &limits, &[]).is_err());
+
+        let profile = WorkerProfile {
+            limits: Some(Box::new(memory_limit())),
+            ..WorkerProfile::new("gpu".into(), vec!["ws1".into()])
+        };
+        let err = validate(
+            RuntimeKind::Host,
+            &DockerOverrides::default(),
+            &ContainerLimitsConfig::new(),
+            &[profile],
+        )
+        .unwrap_err();
+        assert!(err.contains("'gpu'"), "{err}");
+    }
+
+    #[test]
+    fn container_runtimes_and_empty_settings_pass() {
+        let overrides = DockerOverrides::new(vec!["--gpus=all".into()], vec![], vec![]);
+        let limits = ContainerLimitsConfig {
+            worker: Some(Box::new(memory_limit())),
+            ..ContainerLimitsConfig::new()
+        };
+        assert!(validate(RuntimeKind::Docker, &overrides, &limits, &[]).is_ok());
+        assert!(validate(RuntimeKind::Podman, &overrides, &limits, &[]).is_ok());
+
+        let empty = ContainerLimitsConfig {
+            orchestrator: Some(Box::new(ContainerLimits::new())),
+            ..ContainerLimitsConfig::new()
+        };
+        assert!(validate(RuntimeKind::Host, &DockerOverrides::default(), &empty, &[]).is_ok());
+    }
+}
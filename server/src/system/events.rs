@@ -80,6 +80,8 @@ pub enum SystemEventCategory {
     User,
     Merge,
     Routing,
+    Proposal,
+    Review,
 }
 
 impl SystemEventCategory {
@@ -91,6 +93,8 @@ impl SystemEventCategory {
             SystemEventCategory::User => "user",
             SystemEventCategory::Merge => "merge",
             SystemEventCategory::Routing => "routing",
+            SystemEventCategory::Proposal => "proposal",
+            SystemEventCategory::Review => "review",
         }
     }
 }
@@ -0,0 +1,252 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::Duration,
+};
+
+use openapi::models::{ExecResult, ExecSettings, exec_settings::Mode};
+use parking_lot::RwLock;
+use thiserror::Error;
+use tokio::{process::Command, sync::mpsc};
+use tracing::warn;
+use uuid::Uuid;
+
+use super::{
+    codex_config::AgentKind,
+    container_limits, container_runtime,
+    runner::{self, Persona},
+};
+use crate::{
+    globals::PROJECT_DIR,
+    shared::shell::{self, ExecLimits, ExecOutput, ShellError},
+};
+
+const DEFAULT_TIMEOUT_SECS: u64 = 300;
+const DEFAULT_MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+/// Where sandboxed commands and terminals see the worktree.
+pub(crate) const CONTAINER_WORKSPACE: &str = "/workspace";
+
+static SETTINGS: LazyLock<RwLock<ExecSettings>> =
+    LazyLock::new(|| RwLock::new(ExecSettings::new()));
+
+#[derive(Debug, Error)]
+pub enum ExecError {
+    #[error(transparent)]
+    Shell(#[from] ShellError),
+    #[error("working directory {0} is outside the worktree")]
+    OutsideWorktree(String),
+    #[error(
+        "sandbox exec needs the docker or podman runtime; set exec.mode to host to run on the host"
+    )]
+    SandboxUnavailable,
+}
+
+pub fn replace(settings: ExecSettings) {
+    *SETTINGS.write() = settings;
+}
+
+pub fn reset() {
+    replace(ExecSettings::new());
+}
+
+pub fn validate(settings: &ExecSettings) -> Result<(), String> {
+    if settings.timeout_secs.is_some_and(|secs| secs < 1) {
+        return Err("timeout_secs must be at least 1".to_string());
+    }
+    if settings.max_output_bytes.is_some_and(|bytes| bytes < 1) {
+        return Err("max_output_bytes must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+/// A command resolved against its worktree and ready to run.
+pub struct ExecPlan {
+    argv: Vec<String>,
+    cwd: Option<PathBuf>,
+    command: String,
+    limits: ExecLimits,
+    container: Option<String>,
+}
+
+/// Resolve `command` for `persona`'s worktree (`staging` for the orchestrator).
+/// `timeout_secs` may only shorten the configured timeout.
+pub fn plan(
+    persona: Persona,
+    command: &str,
+    cwd: Option<&str>,
+    timeout_secs: Option<i32>,
+) -> Result<ExecPlan, ExecError> {
+    let settings = SETTINGS.read().clone();
+    let workspace_root = Path::new(PROJECT_DIR.as_str());
+    let worktree = match persona {
+        Persona::Orchestrator => workspace_root.join("staging"),
+        Persona::Worker(id) => workspace_root.join(format!("ws{id}")),
+    };
+    let working_dir = shell::resolve_working_dir(workspace_root, &worktree, cwd)?;
+
+    let configured = settings
+        .timeout_secs
+        .map_or(DEFAULT_TIMEOUT_SECS, |secs| secs.max(1) as u64);
+    let timeout = timeout_secs.map_or(configured, |secs| (secs.max(1) as u64).min(configured));
+    let limits = ExecLimits {
+        timeout: Duration::from_secs(timeout),
+        max_output_bytes: settings
+            .max_output_bytes
+            .map_or(DEFAULT_MAX_OUTPUT_BYTES, |bytes| bytes.max(1) as usize),
+    };
+
+    if settings.mode.unwrap_or_default() == Mode::Host {
+        return Ok(ExecPlan {
+            argv: shell::shell_argv(command),
+            cwd: Some(working_dir),
+            command: command.to_string(),
+            limits,
+            container: None,
+        });
+    }
+
+    let worktree = std::fs::canonicalize(&worktree).unwrap_or(worktree);
+    let relative = working_dir
+        .strip_prefix(&worktree)
+        .map_err(|_| ExecError::OutsideWorktree(working_dir.display().to_string()))?;
+    let builder = container_runtime::current()
+        .run_builder(&runner::image_for(persona))
+        .ok_or(ExecError::SandboxUnavailable)?;
+    let (kind, worker_id) = match persona {
+        Persona::Orchestrator => (AgentKind::Orchestrator, None),
+        Persona::Worker(id) => (AgentKind::Worker, Some(id)),
+    };
+    let name = format!("robot-farm-exec-{}", Uuid::new_v4().simple());
+    let builder = builder
+        .name(&name)
+        .remove_container(true)
+        .user("1000:1000")
+        .workdir(Path::new(CONTAINER_WORKSPACE).join(relative))
+        .volume(&worktree, CONTAINER_WORKSPACE, Some("rw".into()));
+    let builder = git_dir_mounts(&worktree)
+        .into_iter()
+        .fold(builder, |builder, (host, container)| {
+            builder.volume(host, container, Some("ro".into()))
+        });
+    let argv = builder
+        .env("HOME", "/home/codex")
+        .limits(container_limits::limits_for(kind, worker_id))
+        .command(shell::shell_argv(command))
+        .build();
+
+    Ok(ExecPlan {
+        argv,
+        cwd: None,
+        command: command.to_string(),
+        limits,
+        container: Some(name),
+    })
+}
+
+impl ExecPlan {
+    /// Run to completion, forwarding output chunks to `output` as they arrive. Dropping
+    /// the receiver cancels the command.
+    pub async fn run(
+        self,
+        output: Option<mpsc::Sender<ExecOutput>>,
+    ) -> Result<ExecResult, ExecError> {
+        let listener = output.clone();
+        let result = shell::run_command(
+            &self.argv,
+            self.cwd.as_deref(),
+            &self.command,
+            self.limits,
+            output,
+        )
+        .await?;
+        let cancelled = listener.is_some_and(|listener| listener.is_closed());
+        if let Some(name) = self
+            .container
+            .filter(|_| result.timed_out == Some(true) || cancelled)
+        {
+            // Killing the CLI client leaves the container running.
+            remove_container(&self.argv[0], &name).await;
+        }
+        Ok(result)
+    }
+}
+
+/// Read-only mounts that let git run inside the sandbox without letting the command
+/// rewrite history: the repository's `.git` for `staging`, or the shared git dir (at its
+/// host path, where the worktree's `.git` file points) for a worker worktree.
+pub(crate) fn git_dir_mounts(worktree: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let dot_git = worktree.join(".git");
+    if dot_git.is_dir() {
+        return vec![(dot_git, Path::new(CONTAINER_WORKSPACE).join(".git"))];
+    }
+    let Some(git_dir) = std::fs::read_to_string(&dot_git).ok().and_then(|contents| {
+        contents
+            .trim()
+            .strip_prefix("gitdir:")
+            .map(|dir| worktree.join(dir.trim()))
+    }) else {
+        return Vec::new();
+    };
+    let common = match std::fs::read_to_string(git_dir.join("commondir")) {
+        Ok(relative) => git_dir.join(relative.trim()),
+        Err(_) => git_dir.clone(),
+    };
+    let common = std::fs::canonicalize(&common).unwrap_or(common);
+    vec![(common.clone(), common)]
+}
+
+/// `rm -f` a container whose client was killed; killing the CLI leaves it running.
+pub(crate) async fn remove_container(program: &str, name: &str) {
+    let removed = Command::new(program)
+        .args(["rm", "-f", name])
+        .output()
+        .await
+        .is_ok_and(|output| output.status.success());
+    if !removed {
+        warn!(container = %name, "failed to remove container");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mounts_the_repository_git_dir_for_staging() {
+        let staging = tempfile::tempdir().unwrap();
+        std::fs::create_dir(staging.path().join(".git")).unwrap();
+
+        assert_eq!(
+            git_dir_mounts(staging.path()),
+            [(
+                staging.path().join(".git"),
+                PathBuf::from("/workspace/.git")
+            )]
+        );
+    }
+
+    #[test]
+    fn mounts_the_shared_git_dir_for_worker_worktrees() {
+        let project = tempfile::tempdir().unwrap();
+        let common = project.path().join("staging/.git");
+        let git_dir = common.join("worktrees/ws1");
+        std::fs::create_dir_all(&git_dir).unwrap();
+        std::fs::write(git_dir.join("commondir"), "../..\n").unwrap();
+        let worktree = project.path().join("ws1");
+        std::fs::create_dir(&worktree).unwrap();
+        std::fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", git_dir.display()),
+        )
+        .unwrap();
+
+        let common = std::fs::canonicalize(common).unwrap();
+        assert_eq!(git_dir_mounts(&worktree), [(common.clone(), common)]);
+    }
+
+    #[test]
+    fn skips_directories_outside_git() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(git_dir_mounts(dir.path()).is_empty());
+    }
+}
@@ -5,6 +5,8 @@ pub struct FeatureToggles {
     pub persistent_threads: bool,
     pub ghost_commits: bool,
     pub drift_manager: bool,
+    pub deterministic_scheduler: bool,
+    pub coalesce_messages: bool,
 }
 
 impl Default for FeatureToggles {
@@ -13,6 +15,8 @@ impl Default for FeatureToggles {
             persistent_threads: false,
             ghost_commits: false,
             drift_manager: false,
+            deterministic_scheduler: false,
+            coalesce_messages: false,
         }
     }
 }
@@ -48,3 +52,11 @@ pub fn ghost_commits() -> bool {
 pub fn drift_manager() -> bool {
     snapshot().drift_manager
 }
+
+pub fn deterministic_scheduler() -> bool {
+    snapshot().deterministic_scheduler
+}
+
+pub fn coalesce_messages() -> bool {
+    snapshot().coalesce_messages
+}
@@ -0,0 +1,408 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    io::{self, BufRead, BufReader, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use chrono::Utc;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use openapi::models::{Feed, FeedArchive, FeedRetention, FeedRetentionResult};
+use parking_lot::RwLock;
+use thiserror::Error;
+use tokio::{
+    fs::{self, OpenOptions},
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+    sync::Mutex,
+};
+use tracing::{error, info, warn};
+
+use crate::{
+    db::feed::{self, RawBlob, RetentionCutoffs},
+    models::process::RunId,
+    routes::config::CONFIG_DIR,
+};
+
+const SWEEP_BATCH: i64 = 5000;
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const SECONDS_PER_DAY: i64 = 86_400;
+/// How long restored entries stay when `imported_max_age_days` is unset.
+const DEFAULT_IMPORTED_MAX_AGE_DAYS: i32 = 7;
+
+static SETTINGS: LazyLock<RwLock<FeedRetention>> =
+    LazyLock::new(|| RwLock::new(FeedRetention::new()));
+/// Serializes blob appends against blob removal.
+static BLOB_LOCK: Mutex<()> = Mutex::const_new(());
+/// One sweep at a time, whether scheduled or requested over REST.
+static SWEEP_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Debug, Error)]
+pub enum FeedArchiveError {
+    #[error("database error: {0}")]
+    Db(#[from] sqlx::Error),
+    #[error("archive io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("archive {0} not found")]
+    NotFound(String),
+    #[error("invalid archive name")]
+    InvalidName,
+}
+
+pub fn reset() {
+    *SETTINGS.write() = FeedRetention::new();
+}
+
+/// Install `settings`; call [`validate`] first.
+pub fn replace(settings: FeedRetention) {
+    *SETTINGS.write() = settings;
+}
+
+pub fn current() -> FeedRetention {
+    SETTINGS.read().clone()
+}
+
+pub fn validate(settings: &FeedRetention) -> Result<(), String> {
+    if settings.max_age_days.is_some_and(|days| days < 1) {
+        return Err("max_age_days must be at least 1".into());
+    }
+    if settings.max_rows.is_some_and(|rows| rows < 1) {
+        return Err("max_rows must be at least 1".into());
+    }
+    if settings.raw_offload_bytes.is_some_and(|bytes| bytes < 1) {
+        return Err("raw_offload_bytes must be at least 1".into());
+    }
+    if settings.imported_max_age_days.is_some_and(|days| days < 1) {
+        return Err("imported_max_age_days must be at least 1".into());
+    }
+    let mut seen = HashSet::new();
+    for rule in settings.categories.iter().flatten() {
+        let category = rule.category.trim();
+        if category.is_empty() {
+            return Err("category rules need a category".into());
+        }
+        if !seen.insert(category) {
+            return Err(format!("category {category} has more than one rule"));
+        }
+        if rule.max_age_days < 1 {
+            return Err(format!("{category}: max_age_days must be at least 1"));
+        }
+    }
+    Ok(())
+}
+
+pub fn blobs_dir() -> PathBuf {
+    Path::new(CONFIG_DIR.as_str()).join("feed-blobs")
+}
+
+pub fn archives_dir() -> PathBuf {
+    Path::new(CONFIG_DIR.as_str()).join("feed-archive")
+}
+
+/// Move `raw` into the run's blob file when it exceeds `raw_offload_bytes`.
+/// Returns what to store inline and where the payload went; on any failure the
+/// payload stays inline.
+pub async fn offload_raw(run_id: Option<RunId>, raw: &str) -> (String, Option<RawBlob>) {
+    let threshold = SETTINGS.read().raw_offload_bytes;
+    let (Some(threshold), Some(run_id)) = (threshold, run_id) else {
+        return (raw.to_string(), None);
+    };
+    if raw.len() <= threshold as usize {
+        return (raw.to_string(), None);
+    }
+    let file = format!("{run_id}.raw");
+    match append_blob(&file, raw).await {
+        Ok(offset) => (
+            String::new(),
+            Some(RawBlob {
+                file,
+                offset,
+                len: raw.len() as i64,
+            }),
+        ),
+        Err(err) => {
+            warn!(?err, %run_id, "failed to offload raw feed payload; keeping it inline");
+            (raw.to_string(), None)
+        }
+    }
+}
+
+async fn append_blob(file: &str, raw: &str) -> io::Result<i64> {
+    let _guard = BLOB_LOCK.lock().await;
+    fs::create_dir_all(blobs_dir()).await?;
+    let mut handle = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(blobs_dir().join(file))
+        .await?;
+    let offset = handle.metadata().await?.len() as i64;
+    handle.write_all(raw.as_bytes()).await?;
+    handle.write_all(b"\n").await?;
+    handle.flush().await?;
+    Ok(offset)
+}
+
+/// The payload `blob` points at, or `None` if its file is gone or truncated.
+pub async fn read_raw(blob: &RawBlob) -> Option<String> {
+    let read = async {
+        let mut handle = fs::File::open(blobs_dir().join(&blob.file)).await?;
+        handle.seek(SeekFrom::Start(blob.offset as u64)).await?;
+        let mut buf = vec![0u8; blob.len as usize];
+        handle.read_exact(&mut buf).await?;
+        String::from_utf8(buf).map_err(io::Error::other)
+    };
+    match read.await {
+        Ok(raw) => Some(raw),
+        Err(err) => {
+            warn!(?err, file = %blob.file, "failed to read offloaded feed payload");
+            None
+        }
+    }
+}
+
+/// Drop every blob file; used when the feed itself is cleared.
+pub async fn clear_blobs() {
+    let _guard = BLOB_LOCK.lock().await;
+    match fs::remove_dir_all(blobs_dir()).await {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => warn!(?err, "failed to remove feed blobs"),
+    }
+}
+
+fn cutoffs(settings: &FeedRetention, now: i64) -> RetentionCutoffs {
+    RetentionCutoffs {
+        before_ts: settings
+            .max_age_days
+            .map(|days| now - i64::from(days) * SECONDS_PER_DAY),
+        categories: settings
+            .categories
+            .iter()
+            .flatten()
+            .map(|rule| {
+                (
+                    rule.category.trim().to_string(),
+                    now - i64::from(rule.max_age_days) * SECONDS_PER_DAY,
+                )
+            })
+            .collect(),
+        keep_rows: settings.max_rows,
+        imported_before: Some(
+            now - i64::from(
+                settings
+                    .imported_max_age_days
+                    .unwrap_or(DEFAULT_IMPORTED_MAX_AGE_DAYS),
+            ) * SECONDS_PER_DAY,
+        ),
+    }
+}
+
+/// Archive and delete every entry the current policy expires, in batches of
+/// [`SWEEP_BATCH`], one gzip JSONL file per batch, then drop expired restored entries.
+pub async fn sweep() -> Result<FeedRetentionResult, FeedArchiveError> {
+    let _guard = SWEEP_LOCK.lock().await;
+    let cutoffs = cutoffs(&current(), Utc::now().timestamp());
+    let mut result = FeedRetentionResult::new(0, Vec::new(), 0);
+    result.imports_removed = feed::delete_expired_imports(&cutoffs).await? as i64;
+
+    loop {
+        let batch = feed::list_expired(&cutoffs, SWEEP_BATCH).await?;
+        let (Some(first), Some(last)) = (batch.first(), batch.last()) else {
+            break;
+        };
+        let name = format!("feed-{}-{}.jsonl.gz", first.0.id, last.0.id);
+
+        let mut entries = Vec::with_capacity(batch.len());
+        let mut blob_files = BTreeSet::new();
+        for (mut entry, blob) in batch {
+            if let Some(blob) = blob {
+                if let Some(raw) = read_raw(&blob).await {
+                    entry.raw = raw;
+                }
+                blob_files.insert(blob.file);
+            }
+            entries.push(entry);
+        }
+
+        write_archive(&name, &entries).await?;
+        let ids: Vec<i64> = entries.iter().map(|entry| entry.id).collect();
+        result.archived += feed::delete_entries(&ids).await? as i64;
+        result.archives.push(name);
+        remove_unused_blobs(blob_files).await?;
+    }
+
+    Ok(result)
+}
+
+async fn write_archive(name: &str, entries: &[Feed]) -> Result<(), FeedArchiveError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for entry in entries {
+        serde_json::to_writer(&mut encoder, entry).map_err(io::Error::other)?;
+        encoder.write_all(b"\n")?;
+    }
+    let bytes = encoder.finish()?;
+    fs::create_dir_all(archives_dir()).await?;
+    // Write then rename so a crash never leaves a partial archive behind a deleted batch.
+    let path = archives_dir().join(name);
+    let partial = path.with_extension("gz.partial");
+    fs::write(&partial, bytes).await?;
+    fs::rename(&partial, &path).await?;
+    Ok(())
+}
+
+async fn remove_unused_blobs(files: BTreeSet<String>) -> Result<(), FeedArchiveError> {
+    let _guard = BLOB_LOCK.lock().await;
+    for file in files {
+        if feed::blob_in_use(&file).await? {
+            continue;
+        }
+        match fs::remove_file(blobs_dir().join(&file)).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(())
+}
+
+/// Archive files, newest first.
+pub async fn list_archives() -> Result<Vec<FeedArchive>, FeedArchiveError> {
+    let mut dir = match fs::read_dir(archives_dir()).await {
+        Ok(dir) => dir,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut archives = Vec::new();
+    while let Some(item) = dir.next_entry().await? {
+        let name = item.file_name().to_string_lossy().into_owned();
+        if !is_archive_name(&name) {
+            continue;
+        }
+        let metadata = item.metadata().await?;
+        let created_at = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or_default();
+        archives.push(FeedArchive::new(name, metadata.len() as i64, created_at));
+    }
+    archives.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| b.name.cmp(&a.name))
+    });
+    Ok(archives)
+}
+
+/// Restore archive `name` into the feed. Restored entries keep their ids, are skipped
+/// by archiving sweeps and are deleted `imported_max_age_days` after the import.
+/// Returns how many rows were inserted.
+pub async fn import(name: &str) -> Result<i64, FeedArchiveError> {
+    if !is_archive_name(name) {
+        return Err(FeedArchiveError::InvalidName);
+    }
+    let path = archives_dir().join(name);
+    let bytes = match fs::read(&path).await {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(FeedArchiveError::NotFound(name.to_string()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let entries = tokio::task::spawn_blocking(move || decode_archive(&bytes))
+        .await
+        .map_err(io::Error::other)??;
+    let imported = feed::import_entries(entries, name).await?;
+    Ok(imported as i64)
+}
+
+fn decode_archive(bytes: &[u8]) -> io::Result<Vec<Feed>> {
+    let mut entries = Vec::new();
+    for line in BufReader::new(GzDecoder::new(bytes)).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(serde_json::from_str(&line).map_err(io::Error::other)?);
+    }
+    Ok(entries)
+}
+
+fn is_archive_name(name: &str) -> bool {
+    name.starts_with("feed-")
+        && name.ends_with(".jsonl.gz")
+        && !name.contains(['/', '\\'])
+        && !name.contains("..")
+}
+
+/// Run [`sweep`] hourly in the background.
+pub fn spawn_sweeper() {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match sweep().await {
+                Ok(result) if result.archived > 0 || result.imports_removed > 0 => {
+                    info!(
+                        archived = result.archived,
+                        archives = ?result.archives,
+                        imports_removed = result.imports_removed,
+                        "feed retention sweep archived entries"
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => error!(?err, "feed retention sweep failed"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapi::models::FeedRetentionRule;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn restored_entries_expire_after_a_week_by_default() {
+        let cutoffs = cutoffs(&FeedRetention::new(), NOW);
+        assert_eq!(
+            cutoffs,
+            RetentionCutoffs {
+                imported_before: Some(NOW - 7 * SECONDS_PER_DAY),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn cutoffs_follow_the_configured_limits() {
+        let settings = FeedRetention {
+            max_age_days: Some(30),
+            max_rows: Some(10_000),
+            categories: Some(vec![FeedRetentionRule::new("status".to_string(), 3)]),
+            imported_max_age_days: Some(2),
+            ..FeedRetention::new()
+        };
+        assert_eq!(
+            cutoffs(&settings, NOW),
+            RetentionCutoffs {
+                before_ts: Some(NOW - 30 * SECONDS_PER_DAY),
+                categories: vec![("status".to_string(), NOW - 3 * SECONDS_PER_DAY)],
+                keep_rows: Some(10_000),
+                imported_before: Some(NOW - 2 * SECONDS_PER_DAY),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_a_zero_import_window() {
+        let settings = FeedRetention {
+            imported_max_age_days: Some(0),
+            ..FeedRetention::new()
+        };
+        assert!(validate(&settings).is_err());
+    }
+}
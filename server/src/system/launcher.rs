@@ -0,0 +1,91 @@
+use crate::system::{
+    agent_backend,
+    runner::{Persona, RunnerConfig},
+};
+use parking_lot::RwLock;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock};
+use thiserror::Error;
+
+/// Inputs for starting one agent turn.
+#[derive(Debug, Clone)]
+pub struct LaunchRequest {
+    pub persona: Persona,
+    pub session_id: Option<String>,
+    pub config: RunnerConfig,
+}
+
+/// Inputs for one task wizard turn, which works in a scratch `workspace` rather than
+/// a worktree.
+#[derive(Debug, Clone)]
+pub struct WizardLaunchRequest {
+    pub session_id: Option<String>,
+    pub workspace: PathBuf,
+    pub config: RunnerConfig,
+}
+
+/// Process the queue manager spawns for an agent turn. The prompt is written to stdin
+/// and stdout is parsed by the named backend.
+#[derive(Debug, Clone)]
+pub struct LaunchPlan {
+    pub program: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    /// Directory to spawn in; the project root when unset.
+    pub working_dir: Option<PathBuf>,
+    /// [`AgentBackend`](agent_backend::AgentBackend) whose format stdout is in.
+    pub backend: &'static str,
+}
+
+#[derive(Debug, Error)]
+pub enum LaunchError {
+    #[error("launch command is empty")]
+    EmptyCommand,
+    #[error("{0}")]
+    Unsupported(String),
+    #[error("no scripted turn left for {0}")]
+    ScriptExhausted(String),
+    #[error("launcher IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub trait AgentLauncher: Send + Sync {
+    fn name(&self) -> &str;
+    fn plan(&self, request: LaunchRequest) -> Result<LaunchPlan, LaunchError>;
+}
+
+/// Default launcher: hands the turn to the [`AgentBackend`](agent_backend::AgentBackend) configured for the persona.
+pub struct BackendLauncher;
+
+impl AgentLauncher for BackendLauncher {
+    fn name(&self) -> &str {
+        "backend"
+    }
+
+    fn plan(&self, mut request: LaunchRequest) -> Result<LaunchPlan, LaunchError> {
+        let backend = agent_backend::for_persona(request.persona);
+        if !backend.supports_resume() {
+            request.session_id = None;
+        }
+        backend.plan(&request)
+    }
+}
+
+static LAUNCHER: LazyLock<RwLock<Arc<dyn AgentLauncher>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(BackendLauncher)));
+
+pub fn current() -> Arc<dyn AgentLauncher> {
+    LAUNCHER.read().clone()
+}
+
+/// Swap the launcher used for every subsequent agent turn.
+pub fn install(launcher: Arc<dyn AgentLauncher>) {
+    *LAUNCHER.write() = launcher;
+}
+
+pub fn label(persona: Persona) -> String {
+    match persona {
+        Persona::Orchestrator => "orchestrator".to_string(),
+        Persona::Worker(id) => format!("ws{id}"),
+    }
+}
@@ -0,0 +1,57 @@
+use std::{collections::HashMap, env, sync::LazyLock};
+
+use parking_lot::RwLock;
+use uuid::Uuid;
+
+use crate::{mcp::Agent, models::process::RunId, shared::secret::constant_time_eq};
+
+/// Operator-held token that authenticates external QA clients.
+const QA_TOKEN_ENV: &str = "ROBOT_FARM_QA_TOKEN";
+
+/// Variable a run's token travels in, so it never appears on a command line.
+pub const TOKEN_ENV: &str = "ROBOT_FARM_MCP_TOKEN";
+
+#[derive(Clone, Debug)]
+struct Grant {
+    agent: Agent,
+    run_id: RunId,
+}
+
+static GRANTS: LazyLock<RwLock<HashMap<String, Grant>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Mint a secret that authenticates `agent` for the lifetime of `run_id`.
+pub fn mint(agent: Agent, run_id: RunId) -> String {
+    let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    GRANTS
+        .write()
+        .insert(token.clone(), Grant { agent, run_id });
+    token
+}
+
+/// Drop every token minted for `run_id`.
+pub fn revoke_run(run_id: RunId) {
+    GRANTS.write().retain(|_, grant| grant.run_id != run_id);
+}
+
+/// Agent a bearer token was minted for, if it is still live.
+pub fn verify(token: &str) -> Option<Agent> {
+    if let Some(grant) = GRANTS.read().get(token) {
+        return Some(grant.agent.clone());
+    }
+    env::var(QA_TOKEN_ENV)
+        .ok()
+        .filter(|expected| !expected.is_empty() && constant_time_eq(expected, token))
+        .map(|_| Agent::Qa)
+}
+
+/// Codex `-c` override that sends the bearer token read from [`TOKEN_ENV`] on every
+/// MCP request.
+pub fn bearer_env_override() -> String {
+    format!("mcp_servers.robot_farm.bearer_token_env_var=\"{TOKEN_ENV}\"")
+}
+
+/// Process environment entry carrying `token` to the run.
+pub fn token_env(token: &str) -> (String, String) {
+    (TOKEN_ENV.to_string(), token.to_string())
+}
@@ -0,0 +1,265 @@
+use crate::{
+    ai::schemas::{OrchestratorTurn, WorkerTurn},
+    globals::PROJECT_DIR,
+    models::codex_events::{
+        CodexEvent, FileChangeEntry, FileChangeItem, FileChangeKind, PatchApplyStatus, TokenUsage,
+        TurnItem, TurnItemDetail,
+    },
+    system::{
+        agent_backend,
+        launcher::{self, AgentLauncher, LaunchError, LaunchPlan, LaunchRequest},
+        runner::Persona,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+/// Shell run for each scripted turn: save the prompt next to the fixture, then replay it.
+const REPLAY_SCRIPT: &str = "cat > \"$0.prompt\"; cat \"$0\"";
+
+/// One agent turn replayed by [`ScriptedLauncher`].
+#[derive(Debug, Clone)]
+pub struct ScriptedTurn {
+    source: TurnSource,
+    writes: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+enum TurnSource {
+    /// Pre-recorded JSONL emitted verbatim.
+    Jsonl(String),
+    /// JSONL rendered at launch time.
+    Rendered {
+        reasoning: Vec<String>,
+        message: String,
+    },
+}
+
+impl ScriptedTurn {
+    pub fn worker(turn: &WorkerTurn) -> Self {
+        Self::rendered(serde_json::to_string(turn).expect("worker turn serializes"))
+    }
+
+    pub fn orchestrator(turn: &OrchestratorTurn) -> Self {
+        Self::rendered(serde_json::to_string(turn).expect("orchestrator turn serializes"))
+    }
+
+    /// Replay a recorded `codex exec --json` transcript as-is.
+    pub fn jsonl(raw: impl Into<String>) -> Self {
+        Self {
+            source: TurnSource::Jsonl(raw.into()),
+            writes: Vec::new(),
+        }
+    }
+
+    fn rendered(message: String) -> Self {
+        Self {
+            source: TurnSource::Rendered {
+                reasoning: Vec::new(),
+                message,
+            },
+            writes: Vec::new(),
+        }
+    }
+
+    pub fn reasoning(mut self, text: impl Into<String>) -> Self {
+        if let TurnSource::Rendered { reasoning, .. } = &mut self.source {
+            reasoning.push(text.into());
+        }
+        self
+    }
+
+    /// Write `contents` to `path` (relative to the agent workspace) when the turn launches.
+    pub fn write_file(mut self, path: impl Into<String>, contents: impl Into<String>) -> Self {
+        self.writes.push((path.into(), contents.into()));
+        self
+    }
+
+    fn apply_writes(&self, workspace: &Path) -> Result<Vec<FileChangeEntry>, LaunchError> {
+        let mut changes = Vec::with_capacity(self.writes.len());
+        for (relative, contents) in &self.writes {
+            let target = workspace.join(relative);
+            let kind = if target.exists() {
+                FileChangeKind::Update
+            } else {
+                FileChangeKind::Add
+            };
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, contents)?;
+            changes.push(FileChangeEntry {
+                path: relative.clone(),
+                kind,
+            });
+        }
+        Ok(changes)
+    }
+
+    fn render(&self, thread_id: &str, changes: Vec<FileChangeEntry>) -> String {
+        let (reasoning, message) = match &self.source {
+            TurnSource::Jsonl(raw) => return raw.clone(),
+            TurnSource::Rendered { reasoning, message } => (reasoning, message),
+        };
+
+        let mut items: Vec<TurnItemDetail> = reasoning
+            .iter()
+            .map(|text| TurnItemDetail::Reasoning { text: text.clone() })
+            .collect();
+        if !changes.is_empty() {
+            items.push(TurnItemDetail::FileChange(FileChangeItem {
+                changes,
+                status: PatchApplyStatus::Completed,
+            }));
+        }
+        items.push(TurnItemDetail::AgentMessage {
+            text: message.clone(),
+        });
+
+        let mut events = vec![
+            CodexEvent::ThreadStarted {
+                thread_id: thread_id.to_string(),
+            },
+            CodexEvent::TurnStarted,
+        ];
+        events.extend(items.into_iter().enumerate().map(|(index, detail)| {
+            CodexEvent::ItemCompleted {
+                item: TurnItem {
+                    id: format!("item_{index}"),
+                    detail,
+                },
+            }
+        }));
+        events.push(CodexEvent::TurnCompleted {
+            usage: TokenUsage {
+                input_tokens: 0,
+                cached_input_tokens: 0,
+                output_tokens: 0,
+            },
+        });
+
+        events
+            .iter()
+            .map(|event| serde_json::to_string(event).expect("codex event serializes") + "\n")
+            .collect()
+    }
+}
+
+/// Launcher that replays scripted turns instead of running Codex, so the full
+/// queue → orchestrator → worker → post-turn loop runs without Docker or network.
+pub struct ScriptedLauncher {
+    scripts: Mutex<HashMap<String, VecDeque<ScriptedTurn>>>,
+    launched: Mutex<Vec<PathBuf>>,
+    fixtures: TempDir,
+}
+
+impl ScriptedLauncher {
+    pub fn new() -> Result<Self, LaunchError> {
+        Ok(Self {
+            scripts: Mutex::new(HashMap::new()),
+            launched: Mutex::new(Vec::new()),
+            fixtures: TempDir::new()?,
+        })
+    }
+
+    /// Load `<dir>/<agent>/*.jsonl` (e.g. `orchestrator/01.jsonl`, `ws1/01.jsonl`),
+    /// replaying each agent's files in name order.
+    pub fn from_fixture_dir(dir: &Path) -> Result<Self, LaunchError> {
+        let launcher = Self::new()?;
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let agent = entry.file_name().to_string_lossy().to_string();
+            let mut files: Vec<PathBuf> = fs::read_dir(entry.path())?
+                .filter_map(|file| file.ok().map(|file| file.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+                .collect();
+            files.sort();
+            for file in files {
+                launcher.push(&agent, ScriptedTurn::jsonl(fs::read_to_string(file)?));
+            }
+        }
+        Ok(launcher)
+    }
+
+    /// Queue a turn for `agent` (`orchestrator` or `ws<N>`).
+    pub fn push(&self, agent: &str, turn: ScriptedTurn) {
+        self.scripts
+            .lock()
+            .entry(agent.to_ascii_lowercase())
+            .or_default()
+            .push_back(turn);
+    }
+
+    pub fn remaining(&self, agent: &str) -> usize {
+        self.scripts
+            .lock()
+            .get(&agent.to_ascii_lowercase())
+            .map_or(0, VecDeque::len)
+    }
+
+    /// Prompts received so far, in launch order, for turns whose process has run.
+    pub fn prompts(&self) -> Vec<String> {
+        self.launched
+            .lock()
+            .iter()
+            .filter_map(|fixture| fs::read_to_string(fixture.with_extension("jsonl.prompt")).ok())
+            .collect()
+    }
+
+    fn workspace(persona: Persona) -> PathBuf {
+        let root = PathBuf::from(PROJECT_DIR.as_str());
+        match persona {
+            Persona::Orchestrator => root.join("staging"),
+            Persona::Worker(id) => root.join(format!("ws{id}")),
+        }
+    }
+}
+
+impl AgentLauncher for ScriptedLauncher {
+    fn name(&self) -> &str {
+        "scripted"
+    }
+
+    fn plan(&self, request: LaunchRequest) -> Result<LaunchPlan, LaunchError> {
+        let agent = launcher::label(request.persona);
+        let turn = self
+            .scripts
+            .lock()
+            .get_mut(&agent)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| LaunchError::ScriptExhausted(agent.clone()))?;
+
+        let changes = turn.apply_writes(&Self::workspace(request.persona))?;
+        let thread_id = request
+            .session_id
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        let fixture = self
+            .fixtures
+            .path()
+            .join(format!("{agent}-{}.jsonl", Uuid::new_v4()));
+        fs::write(&fixture, turn.render(&thread_id, changes))?;
+        self.launched.lock().push(fixture.clone());
+
+        Ok(LaunchPlan {
+            program: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                REPLAY_SCRIPT.to_string(),
+                fixture.display().to_string(),
+            ],
+            env: Vec::new(),
+            working_dir: None,
+            // Fixtures are written as `codex exec --json` events.
+            backend: agent_backend::DEFAULT_BACKEND,
+        })
+    }
+}
@@ -1,12 +1,32 @@
+pub mod agent_backend;
+pub mod api_auth;
+pub mod audit;
+pub mod codex_backend;
 pub mod codex_config;
+pub mod container_limits;
+pub mod container_runtime;
 pub mod dirty_staging;
 pub mod docker_overrides;
 pub mod events;
+pub mod exec;
 pub mod features;
+pub mod feed_archive;
+pub mod launcher;
+pub mod mcp_tokens;
+pub mod mock_agent;
+pub mod qa_inbox;
 pub mod queue;
+pub mod review;
+pub mod run_recordings;
+pub mod run_transcript;
 pub mod runner;
+pub mod schedule;
+pub mod scheduler;
+pub mod skills;
 pub mod staging_hooks;
 pub mod strategy;
+pub mod terminal;
+pub mod worker_profiles;
 
 pub fn init_system_state() {
     strategy::StrategyState::init_global();
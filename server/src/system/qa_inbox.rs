@@ -0,0 +1,50 @@
+use openapi::models::{Message, QaInboxItem};
+use tracing::warn;
+
+use crate::db::{
+    DbResult,
+    qa_inbox::{self, NewQaItem},
+    task as task_db,
+};
+use crate::realtime::{self, RealtimeEvent};
+
+use super::{events::SystemActor, queue::QueueCoordinator};
+
+/// File a queued message addressed to Quality Assurance, tagging it with the
+/// task the sending worker is assigned so replies stay threaded.
+pub async fn receive(message: &Message) -> DbResult<QaInboxItem> {
+    let (task_id, task_slug) = match SystemActor::from_label(&message.from) {
+        Some(SystemActor::Worker(worker_id)) => {
+            match QueueCoordinator::global().assigned_task(worker_id) {
+                Some(assigned) => {
+                    let slug = match assigned.slug {
+                        Some(slug) => Some(slug),
+                        None => task_db::get_task(assigned.task_id)
+                            .await?
+                            .map(|task| task.slug),
+                    };
+                    (Some(assigned.task_id), slug)
+                }
+                None => (None, None),
+            }
+        }
+        _ => (None, None),
+    };
+    qa_inbox::insert_item(NewQaItem {
+        agent: message.from.trim().to_string(),
+        task_id,
+        task_slug,
+        message: message.message.clone(),
+        from_qa: false,
+        reply_to: None,
+    })
+    .await
+}
+
+/// Push the current unread items to realtime subscribers.
+pub async fn broadcast() {
+    match qa_inbox::list_unread().await {
+        Ok(unread) => realtime::publish(RealtimeEvent::QaInbox { unread }),
+        Err(err) => warn!(?err, "failed to load unread QA inbox items"),
+    }
+}
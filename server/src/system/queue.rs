@@ -1,7 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
 use once_cell::sync::OnceCell;
-use openapi::models::{ActiveStrategy, FeedLevel, Strategy as ApiStrategy};
+use openapi::models::{
+    ActiveStrategy, FeedLevel, Message, QueueState as QueueStateModel, TaskGroup, TaskProposal,
+    TaskProposalStatus,
+};
 use parking_lot::RwLock;
 use serde_json::json;
 
@@ -21,6 +24,8 @@ struct QueueState {
     known_workers: HashSet<i64>,
     events: Vec<SystemEvent>,
     paused: bool,
+    draining: bool,
+    paused_workers: HashSet<i64>,
 }
 
 impl Default for QueueState {
@@ -30,6 +35,8 @@ impl Default for QueueState {
             known_workers: HashSet::new(),
             events: Vec::new(),
             paused: true,
+            draining: false,
+            paused_workers: HashSet::new(),
         }
     }
 }
@@ -61,6 +68,7 @@ impl QueueCoordinator {
         let mut guard = self.inner.write();
         guard.known_workers.remove(&worker_id);
         guard.assignments.remove(&worker_id);
+        guard.paused_workers.remove(&worker_id);
     }
 
     pub fn assigned_task(&self, worker_id: i64) -> Option<AssignedTask> {
@@ -84,6 +92,21 @@ impl QueueCoordinator {
         Ok(())
     }
 
+    /// Known workers without an assignment that are not paused, lowest id first.
+    pub fn idle_workers(&self) -> Vec<i64> {
+        let guard = self.inner.read();
+        let mut idle: Vec<i64> = guard
+            .known_workers
+            .iter()
+            .copied()
+            .filter(|worker| {
+                !guard.assignments.contains_key(worker) && !guard.paused_workers.contains(worker)
+            })
+            .collect();
+        idle.sort_unstable();
+        idle
+    }
+
     pub fn clear_assignment(&self, worker_id: i64) {
         self.inner.write().assignments.remove(&worker_id);
     }
@@ -99,29 +122,93 @@ impl QueueCoordinator {
 
     pub fn pause(&self) {
         let mut guard = self.inner.write();
-        if guard.paused {
+        if guard.paused && !guard.draining {
             return;
         }
         guard.paused = true;
+        guard.draining = false;
         drop(guard);
-        realtime::publish(RealtimeEvent::QueueState { paused: true });
+        self.publish_state();
     }
 
     pub fn resume(&self) {
         let mut guard = self.inner.write();
-        if !guard.paused {
+        if !guard.paused && !guard.draining {
             return;
         }
         guard.paused = false;
+        guard.draining = false;
         drop(guard);
-        realtime::publish(RealtimeEvent::QueueState { paused: false });
+        self.publish_state();
+    }
+
+    /// Stop handing out new turns; the queue manager pauses once in-flight work settles.
+    pub fn start_drain(&self) {
+        let mut guard = self.inner.write();
+        if guard.paused || guard.draining {
+            return;
+        }
+        guard.draining = true;
+        drop(guard);
+        self.publish_state();
+    }
+
+    pub fn finish_drain(&self) {
+        let mut guard = self.inner.write();
+        guard.paused = true;
+        guard.draining = false;
+        drop(guard);
+        self.publish_state();
+        realtime::publish(RealtimeEvent::QueueDrained);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.inner.read().draining
+    }
+
+    pub fn pause_worker(&self, worker_id: i64) {
+        if self.inner.write().paused_workers.insert(worker_id) {
+            self.publish_state();
+        }
+    }
+
+    pub fn resume_worker(&self, worker_id: i64) {
+        if self.inner.write().paused_workers.remove(&worker_id) {
+            self.publish_state();
+        }
+    }
+
+    pub fn is_worker_paused(&self, worker_id: i64) -> bool {
+        self.inner.read().paused_workers.contains(&worker_id)
+    }
+
+    pub fn queue_state(&self) -> QueueStateModel {
+        let guard = self.inner.read();
+        let mut paused_workers: Vec<i64> = guard.paused_workers.iter().copied().collect();
+        paused_workers.sort_unstable();
+        QueueStateModel {
+            paused: guard.paused,
+            draining: Some(guard.draining),
+            paused_workers: Some(paused_workers),
+        }
+    }
+
+    fn publish_state(&self) {
+        realtime::publish(RealtimeEvent::QueueState(self.queue_state()));
     }
 
     pub fn is_paused(&self) -> bool {
         self.inner.read().paused
     }
 
-    pub fn orchestrator_hints(&self, strategy: &ActiveStrategy) -> Vec<OrchestratorHint> {
+    /// Suggested orchestrator actions for idle workers. `from_groups` lists the
+    /// eligible group slugs (see [`strategy::eligible_group_slugs`](super::strategy::eligible_group_slugs))
+    /// whose required skills the worker provides; workers that match none are left out.
+    pub fn orchestrator_hints(
+        &self,
+        strategy: &ActiveStrategy,
+        groups: &[TaskGroup],
+    ) -> Vec<OrchestratorHint> {
         let guard = self.inner.read();
         if guard.paused {
             return Vec::new();
@@ -130,43 +217,46 @@ impl QueueCoordinator {
             .known_workers
             .iter()
             .copied()
-            .filter(|worker| !guard.assignments.contains_key(worker))
+            .filter(|worker| {
+                !guard.assignments.contains_key(worker) && !guard.paused_workers.contains(worker)
+            })
             .collect();
 
         if idle_workers.is_empty() {
             return Vec::new();
         }
 
-        let focus = strategy
-            .focus
-            .clone()
-            .unwrap_or_default()
-            .into_iter()
-            .map(|group| group.to_string())
-            .collect::<Vec<_>>();
-
-        match strategy.id {
-            ApiStrategy::Aggressive | ApiStrategy::HotfixSwarm | ApiStrategy::BugSmash => {
-                idle_workers
-                    .into_iter()
-                    .map(|worker| OrchestratorHint::AssignTask {
-                        to_worker: worker,
-                        from_groups: focus.clone(),
-                    })
-                    .collect()
-            }
-            ApiStrategy::Moderate | ApiStrategy::Economical => idle_workers
-                .into_iter()
-                .map(|worker| OrchestratorHint::AssignTask {
-                    to_worker: worker,
-                    from_groups: focus.clone(),
-                })
-                .collect(),
-            ApiStrategy::Planning | ApiStrategy::WindDown => idle_workers
+        let definition = super::strategy::resolve(&strategy.id);
+        if !definition.allow_assignments {
+            return idle_workers
                 .into_iter()
                 .map(|worker| OrchestratorHint::SendSupport { to_worker: worker })
-                .collect(),
+                .collect();
         }
+
+        let focus = super::strategy::eligible_group_slugs(strategy, &definition, groups);
+        let group_skills = super::skills::group_requirements(groups);
+
+        // Scheduler strategies spend `busy_workers` themselves; the orchestrator
+        // is told about every idle worker when it has to step in.
+        let busy = guard.assignments.len();
+        let slots = match definition.busy_workers {
+            Some(target) if !definition.scheduler => target.saturating_sub(busy),
+            _ => idle_workers.len(),
+        };
+        idle_workers
+            .into_iter()
+            .filter_map(|worker| {
+                let from_groups = super::skills::eligible_groups(worker, &focus, &group_skills);
+                (focus.is_empty() || !from_groups.is_empty()).then_some(
+                    OrchestratorHint::AssignTask {
+                        to_worker: worker,
+                        from_groups,
+                    },
+                )
+            })
+            .take(slots)
+            .collect()
     }
 
     pub fn record_assignment_hint(&self, hints: &[OrchestratorHint]) {
@@ -192,6 +282,59 @@ impl QueueCoordinator {
         self.record_event(event);
     }
 
+    pub fn message_expired(&self, message: &Message) {
+        let source = SystemActor::from_label(&message.from).unwrap_or(SystemActor::System);
+        let target = SystemActor::from_label(&message.to).unwrap_or(SystemActor::System);
+        let event = SystemEvent::new(
+            FeedLevel::Warning,
+            source,
+            target,
+            SystemEventCategory::Queue,
+            format!(
+                "Queue message #{} to {} expired before delivery",
+                message.id, message.to
+            ),
+            json!({ "message_id": message.id, "message": message.message }),
+        );
+        self.record_event(event);
+    }
+
+    pub fn message_held(&self, message: &Message, reason: &str) {
+        let source = SystemActor::from_label(&message.from).unwrap_or(SystemActor::System);
+        let event = SystemEvent::new(
+            FeedLevel::Info,
+            source,
+            SystemActor::QualityAssurance,
+            SystemEventCategory::Review,
+            format!(
+                "Queue message #{} from {} to {} held for review: {reason}",
+                message.id, message.from, message.to
+            ),
+            json!({ "message_id": message.id, "reason": reason }),
+        );
+        self.record_event(event);
+    }
+
+    pub fn message_reviewed(&self, message: &Message, verdict: &str, note: Option<&str>) {
+        let target = SystemActor::from_label(&message.from).unwrap_or(SystemActor::System);
+        let mut summary = format!(
+            "Quality Assurance {verdict} queue message #{} to {}",
+            message.id, message.to
+        );
+        if let Some(note) = note {
+            summary.push_str(&format!(" ({note})"));
+        }
+        let event = SystemEvent::new(
+            FeedLevel::Info,
+            SystemActor::QualityAssurance,
+            target,
+            SystemEventCategory::Review,
+            summary,
+            json!({ "message_id": message.id, "verdict": verdict }),
+        );
+        self.record_event(event);
+    }
+
     pub fn validation_failed(&self, worker_id: i64, explanation: impl Into<String>) {
         let summary = explanation.into();
         let event = SystemEvent::new(
@@ -204,6 +347,82 @@ impl QueueCoordinator {
         );
         self.record_event(event);
     }
+
+    pub fn proposal_filed(&self, proposal: &TaskProposal) {
+        let source = SystemActor::from_label(&proposal.proposer).unwrap_or(SystemActor::System);
+        let event = SystemEvent::new(
+            FeedLevel::Info,
+            source,
+            SystemActor::QualityAssurance,
+            SystemEventCategory::Proposal,
+            format!(
+                "{} proposed a {} task: {}",
+                proposal.proposer, proposal.suggested_group, proposal.title
+            ),
+            json!({ "proposal_id": proposal.id }),
+        );
+        self.record_event(event);
+    }
+
+    pub fn proposal_reviewed(&self, source: SystemActor, proposal: &TaskProposal) {
+        let verdict = match proposal.status {
+            TaskProposalStatus::Accepted => "accepted",
+            TaskProposalStatus::Rejected => "rejected",
+            TaskProposalStatus::Pending => return,
+        };
+        let reviewer = proposal.reviewer.clone().unwrap_or_else(|| source.label());
+        let mut summary = format!(
+            "{reviewer} {verdict} proposal #{}: {}",
+            proposal.id, proposal.title
+        );
+        if let Some(note) = proposal.review_note.as_deref() {
+            summary.push_str(&format!(" ({note})"));
+        }
+        let target = SystemActor::from_label(&proposal.proposer).unwrap_or(SystemActor::System);
+        let event = SystemEvent::new(
+            FeedLevel::Info,
+            source,
+            target,
+            SystemEventCategory::Proposal,
+            summary,
+            json!({ "proposal_id": proposal.id, "task_id": proposal.task_id }),
+        );
+        self.record_event(event);
+    }
+
+    pub fn skill_mismatch(
+        &self,
+        worker_id: i64,
+        task_slug: &str,
+        missing: &[String],
+        rejected: bool,
+    ) {
+        let outcome = if rejected { "rejected" } else { "allowed" };
+        let event = SystemEvent::new(
+            FeedLevel::Warning,
+            SystemActor::Orchestrator,
+            SystemActor::Worker(worker_id),
+            SystemEventCategory::Routing,
+            format!(
+                "Assignment of {task_slug} to ws{worker_id} {outcome}: missing skill(s) {}",
+                missing.join(", ")
+            ),
+            json!({ "worker": worker_id, "task": task_slug, "missing_skills": missing, "rejected": rejected }),
+        );
+        self.record_event(event);
+    }
+
+    pub fn strategy_scheduled(&self, summary: String, details: serde_json::Value) {
+        let event = SystemEvent::new(
+            FeedLevel::Info,
+            SystemActor::System,
+            SystemActor::Orchestrator,
+            SystemEventCategory::Strategy,
+            summary,
+            details,
+        );
+        self.record_event(event);
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -211,3 +430,78 @@ pub enum QueueError {
     #[error("worker already has an assignment")]
     WorkerBusy,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinator() -> QueueCoordinator {
+        QueueCoordinator {
+            inner: RwLock::new(QueueState::default()),
+        }
+    }
+
+    #[test]
+    fn drain_runs_only_from_a_running_queue_and_ends_paused() {
+        let queue = coordinator();
+        queue.start_drain();
+        assert!(queue.is_paused());
+        assert!(!queue.is_draining(), "a paused queue has nothing to drain");
+
+        queue.resume();
+        queue.start_drain();
+        queue.start_drain();
+        assert!(!queue.is_paused());
+        assert!(queue.is_draining());
+        assert_eq!(queue.queue_state().draining, Some(true));
+
+        queue.finish_drain();
+        assert!(queue.is_paused());
+        assert!(!queue.is_draining());
+        assert_eq!(queue.queue_state().draining, Some(false));
+    }
+
+    #[test]
+    fn pause_and_resume_cancel_a_drain() {
+        let queue = coordinator();
+        queue.resume();
+        queue.start_drain();
+        queue.pause();
+        assert!(queue.is_paused());
+        assert!(!queue.is_draining());
+
+        queue.resume();
+        queue.start_drain();
+        queue.resume();
+        assert!(!queue.is_paused());
+        assert!(!queue.is_draining());
+    }
+
+    #[test]
+    fn paused_workers_are_never_idle() {
+        let queue = coordinator();
+        for worker_id in [3, 1, 2] {
+            queue.register_worker(worker_id);
+        }
+        queue.pause_worker(2);
+        assert_eq!(queue.idle_workers(), vec![1, 3]);
+
+        queue.assign_task(1, 10, None).expect("assign ws1");
+        queue.pause_worker(3);
+        assert!(queue.idle_workers().is_empty());
+        assert_eq!(queue.queue_state().paused_workers, Some(vec![2, 3]));
+
+        // A paused worker keeps its assignment but gets no new one when it ends.
+        queue.pause_worker(1);
+        queue.clear_assignment(1);
+        assert!(queue.idle_workers().is_empty());
+
+        queue.resume_worker(2);
+        assert_eq!(queue.idle_workers(), vec![2]);
+        assert!(!queue.is_worker_paused(2));
+
+        queue.unregister_worker(3);
+        assert!(!queue.is_worker_paused(3));
+        assert_eq!(queue.queue_state().paused_workers, Some(vec![1]));
+    }
+}
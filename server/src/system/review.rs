@@ -0,0 +1,220 @@
+use std::sync::LazyLock;
+
+use openapi::models::ReviewMode;
+use parking_lot::RwLock;
+
+use super::events::SystemActor;
+
+static REVIEW_MODE: LazyLock<RwLock<ReviewMode>> = LazyLock::new(|| RwLock::new(ReviewMode::new()));
+
+pub fn replace(mode: ReviewMode) {
+    *REVIEW_MODE.write() = mode;
+}
+
+pub fn reset() {
+    replace(ReviewMode::new());
+}
+
+pub fn snapshot() -> ReviewMode {
+    REVIEW_MODE.read().clone()
+}
+
+/// True when any rule can hold a message.
+pub fn is_enabled(mode: &ReviewMode) -> bool {
+    mode.orchestrator_to_worker.unwrap_or(false)
+        || mode
+            .task_groups
+            .as_ref()
+            .is_some_and(|groups| !groups.is_empty())
+        || mode
+            .strategies
+            .as_ref()
+            .is_some_and(|names| !names.is_empty())
+}
+
+pub fn watches_groups(mode: &ReviewMode) -> bool {
+    mode.task_groups
+        .as_ref()
+        .is_some_and(|groups| !groups.is_empty())
+}
+
+/// Reason a message should wait for Quality Assurance, if any rule matches.
+/// `group_slug` is the task group of the worker on either end of the message.
+pub fn hold_reason(
+    mode: &ReviewMode,
+    from: Option<SystemActor>,
+    to: Option<SystemActor>,
+    active_strategy: &str,
+    group_slug: Option<&str>,
+) -> Option<String> {
+    let involves_qa =
+        |actor: Option<SystemActor>| matches!(actor, Some(SystemActor::QualityAssurance));
+    if involves_qa(from) || involves_qa(to) {
+        return None;
+    }
+    if let Some(name) = mode
+        .strategies
+        .iter()
+        .flatten()
+        .find(|name| name.trim().eq_ignore_ascii_case(active_strategy))
+    {
+        return Some(format!("{} strategy is active", name.trim()));
+    }
+    if mode.orchestrator_to_worker.unwrap_or(false)
+        && matches!(from, Some(SystemActor::Orchestrator))
+        && matches!(to, Some(SystemActor::Worker(_)))
+    {
+        return Some("orchestrator message to a worker".to_string());
+    }
+    let slug = group_slug?;
+    mode.task_groups
+        .iter()
+        .flatten()
+        .find(|group| group.trim().eq_ignore_ascii_case(slug))
+        .map(|group| format!("touches task group {}", group.trim()))
+}
+
+/// Reason to hold a task the deterministic scheduler assigns. The scheduler
+/// stands in for the orchestrator, so `orchestrator_to_worker` covers it too.
+pub fn scheduler_hold_reason(mode: &ReviewMode) -> Option<String> {
+    mode.orchestrator_to_worker
+        .unwrap_or(false)
+        .then(|| "scheduler assignment to a worker".to_string())
+}
+
+pub fn validate(mode: &ReviewMode, is_strategy: impl Fn(&str) -> bool) -> Result<(), String> {
+    for name in mode.strategies.iter().flatten() {
+        if !is_strategy(name) {
+            return Err(format!("review_mode: unknown strategy '{name}'"));
+        }
+    }
+    if mode
+        .task_groups
+        .iter()
+        .flatten()
+        .any(|group| group.trim().is_empty())
+    {
+        return Err("review_mode: task group slugs must not be empty".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORCH: Option<SystemActor> = Some(SystemActor::Orchestrator);
+    const QA: Option<SystemActor> = Some(SystemActor::QualityAssurance);
+    const WS1: Option<SystemActor> = Some(SystemActor::Worker(1));
+
+    type Case = (
+        Option<SystemActor>,
+        Option<SystemActor>,
+        &'static str,
+        Option<&'static str>,
+        Option<&'static str>,
+    );
+
+    /// Runs each `(from, to, strategy, group, expected reason)` row against `mode`.
+    fn check(mode: &ReviewMode, cases: &[Case]) {
+        for (index, (from, to, strategy, group, expected)) in cases.iter().enumerate() {
+            assert_eq!(
+                hold_reason(mode, *from, *to, strategy, *group).as_deref(),
+                *expected,
+                "case {index}: {from:?} -> {to:?} under {strategy} in {group:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn default_mode_holds_nothing() {
+        let mode = ReviewMode::new();
+        assert!(!is_enabled(&mode));
+        check(
+            &mode,
+            &[
+                (ORCH, WS1, "MODERATE", Some("bugs"), None),
+                (WS1, ORCH, "HOTFIX_SWARM", None, None),
+                (None, WS1, "MODERATE", None, None),
+            ],
+        );
+        assert_eq!(scheduler_hold_reason(&mode), None);
+    }
+
+    #[test]
+    fn orchestrator_to_worker_mode_holds_only_that_direction() {
+        let mode = ReviewMode {
+            orchestrator_to_worker: Some(true),
+            ..ReviewMode::new()
+        };
+        assert!(is_enabled(&mode));
+        let held = Some("orchestrator message to a worker");
+        check(
+            &mode,
+            &[
+                (ORCH, WS1, "MODERATE", None, held),
+                (ORCH, WS1, "MODERATE", Some("bugs"), held),
+                (WS1, ORCH, "MODERATE", None, None),
+                (ORCH, QA, "MODERATE", None, None),
+                (QA, WS1, "MODERATE", None, None),
+                (None, WS1, "MODERATE", None, None),
+            ],
+        );
+        assert_eq!(
+            scheduler_hold_reason(&mode).as_deref(),
+            Some("scheduler assignment to a worker")
+        );
+    }
+
+    #[test]
+    fn task_group_mode_holds_messages_touching_listed_groups() {
+        let mode = ReviewMode {
+            task_groups: Some(vec![" Bugs ".to_string()]),
+            ..ReviewMode::new()
+        };
+        assert!(is_enabled(&mode));
+        assert!(watches_groups(&mode));
+        let held = Some("touches task group Bugs");
+        check(
+            &mode,
+            &[
+                (ORCH, WS1, "MODERATE", Some("bugs"), held),
+                (WS1, ORCH, "MODERATE", Some("BUGS"), held),
+                (ORCH, WS1, "MODERATE", Some("chores"), None),
+                (ORCH, WS1, "MODERATE", None, None),
+                (QA, WS1, "MODERATE", Some("bugs"), None),
+                (WS1, QA, "MODERATE", Some("bugs"), None),
+            ],
+        );
+        assert_eq!(scheduler_hold_reason(&mode), None);
+    }
+
+    #[test]
+    fn strategy_mode_holds_everything_but_qa_while_the_strategy_is_active() {
+        let mode = ReviewMode {
+            strategies: Some(vec!["HOTFIX_SWARM".to_string()]),
+            orchestrator_to_worker: Some(true),
+            ..ReviewMode::new()
+        };
+        assert!(is_enabled(&mode));
+        assert!(!watches_groups(&mode));
+        let held = Some("HOTFIX_SWARM strategy is active");
+        check(
+            &mode,
+            &[
+                (ORCH, WS1, "hotfix_swarm", None, held),
+                (WS1, ORCH, "HOTFIX_SWARM", Some("hotfix"), held),
+                (None, ORCH, "HOTFIX_SWARM", None, held),
+                (QA, WS1, "HOTFIX_SWARM", None, None),
+                (WS1, ORCH, "MODERATE", None, None),
+                (
+                    ORCH,
+                    WS1,
+                    "MODERATE",
+                    None,
+                    Some("orchestrator message to a worker"),
+                ),
+            ],
+        );
+    }
+}
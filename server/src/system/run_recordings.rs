@@ -0,0 +1,165 @@
+use crate::models::process::{RunId, RunMetadata, RunPriority};
+use crate::routes::config::CONFIG_DIR;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tokio::fs::{self, File};
+use tokio::io::AsyncWriteExt;
+use tracing::{error, info};
+
+/// Recordings untouched for this long are deleted by [`prune`].
+pub const RECORDING_RETENTION: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+const PRUNE_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Sidecar written next to each recording so a replay knows which agent produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingMeta {
+    pub run_id: RunId,
+    pub persona: String,
+    pub tags: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    /// Agent backend that wrote the recording; absent in recordings made before it was
+    /// stored, which all came from the default backend.
+    #[serde(default)]
+    pub backend: Option<String>,
+    /// Prompt written to the agent's stdin.
+    #[serde(default)]
+    pub prompt: Option<String>,
+    /// Filled in once the process is gone; absent while it runs.
+    #[serde(default)]
+    pub exit: Option<RecordingExit>,
+}
+
+/// How a recorded run ended.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingExit {
+    /// `exited`, `killed` or `failed`.
+    pub reason: String,
+    pub code: Option<i32>,
+    /// Kill reason or wait error, when there is one.
+    pub detail: Option<String>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl RecordingMeta {
+    pub fn to_run_metadata(&self) -> RunMetadata {
+        RunMetadata {
+            run_id: self.run_id,
+            persona: self.persona.clone(),
+            workspace_root: PathBuf::from(CONFIG_DIR.as_str()),
+            tags: self.tags.clone(),
+            priority: RunPriority::Normal,
+            issued_at: self.started_at,
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+/// Raw stdout of one agent run, appended as it streams.
+pub struct RunRecording {
+    file: File,
+}
+
+impl RunRecording {
+    pub async fn create(metadata: &RunMetadata, prompt: Option<&[u8]>) -> io::Result<Self> {
+        fs::create_dir_all(recordings_dir()).await?;
+        let meta = RecordingMeta {
+            run_id: metadata.run_id,
+            persona: metadata.persona.clone(),
+            tags: metadata.tags.clone(),
+            started_at: metadata.issued_at,
+            backend: metadata.backend.clone(),
+            prompt: prompt.map(|bytes| String::from_utf8_lossy(bytes).into_owned()),
+            exit: None,
+        };
+        write_meta(&meta).await?;
+        let file = File::create(recording_path(metadata.run_id)).await?;
+        Ok(Self { file })
+    }
+
+    pub async fn append(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.file.write_all(bytes).await?;
+        self.file.flush().await
+    }
+}
+
+pub fn recordings_dir() -> PathBuf {
+    Path::new(CONFIG_DIR.as_str()).join("runs")
+}
+
+pub fn recording_path(run_id: RunId) -> PathBuf {
+    recordings_dir().join(format!("{run_id}.jsonl"))
+}
+
+fn meta_path(run_id: RunId) -> PathBuf {
+    recordings_dir().join(format!("{run_id}.meta.json"))
+}
+
+async fn write_meta(meta: &RecordingMeta) -> io::Result<()> {
+    let serialized = serde_json::to_vec_pretty(meta).map_err(io::Error::other)?;
+    fs::write(meta_path(meta.run_id), serialized).await
+}
+
+/// Note how the run ended in its sidecar.
+pub async fn record_exit(run_id: RunId, exit: RecordingExit) -> io::Result<()> {
+    let raw_meta = fs::read(meta_path(run_id)).await?;
+    let mut meta: RecordingMeta = serde_json::from_slice(&raw_meta).map_err(io::Error::other)?;
+    meta.exit = Some(exit);
+    write_meta(&meta).await
+}
+
+pub async fn load(run_id: RunId) -> io::Result<(RecordingMeta, Vec<u8>)> {
+    let raw_meta = fs::read(meta_path(run_id)).await?;
+    let meta: RecordingMeta = serde_json::from_slice(&raw_meta).map_err(io::Error::other)?;
+    let bytes = fs::read(recording_path(run_id)).await?;
+    Ok((meta, bytes))
+}
+
+pub async fn load_bytes(run_id: RunId) -> io::Result<Vec<u8>> {
+    fs::read(recording_path(run_id)).await
+}
+
+/// Delete recordings and sidecars last written before `now - max_age`. Returns how many
+/// files were removed.
+pub async fn prune(max_age: Duration) -> io::Result<usize> {
+    let Some(cutoff) = SystemTime::now().checked_sub(max_age) else {
+        return Ok(0);
+    };
+    let mut entries = match fs::read_dir(recordings_dir()).await {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err),
+    };
+    let mut removed = 0;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.ends_with(".jsonl") && !name.ends_with(".meta.json") {
+            continue;
+        }
+        let metadata = entry.metadata().await?;
+        if !metadata.is_file() || metadata.modified()? >= cutoff {
+            continue;
+        }
+        fs::remove_file(entry.path()).await?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Run [`prune`] with [`RECORDING_RETENTION`] every few hours in the background.
+pub fn spawn_pruner() {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+        loop {
+            interval.tick().await;
+            match prune(RECORDING_RETENTION).await {
+                Ok(removed) if removed > 0 => info!(removed, "pruned old run recordings"),
+                Ok(_) => {}
+                Err(err) => error!(?err, "run recording prune failed"),
+            }
+        }
+    });
+}
@@ -0,0 +1,378 @@
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::{
+    ai::schemas::{OrchestratorTurn, WorkerTurn},
+    models::agent_items::{AgentItem, FileChangeKind, ItemStatus},
+    system::{agent_backend, run_recordings::RecordingMeta},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    Markdown,
+    Html,
+}
+
+/// Format-neutral pieces of a transcript, rendered by [`to_markdown`] or [`to_html`].
+enum Block {
+    Heading(u8, String),
+    Paragraph(String),
+    /// `label: value` pairs.
+    Facts(Vec<(&'static str, String)>),
+    List(Vec<String>),
+    Code {
+        lang: &'static str,
+        text: String,
+    },
+}
+
+/// Render a recorded run: prompt, every completed item in order, the structured
+/// turn, token usage and how the process ended. The recording is parsed by the
+/// backend that wrote it.
+pub fn render(meta: &RecordingMeta, recording: &[u8], format: TranscriptFormat) -> String {
+    let blocks = build(meta, recording);
+    let title = format!("Run {}", meta.run_id);
+    match format {
+        TranscriptFormat::Markdown => to_markdown(&blocks),
+        TranscriptFormat::Html => to_html(&title, &blocks),
+    }
+}
+
+fn build(meta: &RecordingMeta, recording: &[u8]) -> Vec<Block> {
+    let mut blocks = vec![Block::Heading(1, format!("Run {}", meta.run_id))];
+    let mut facts = vec![
+        ("Agent", meta.persona.clone()),
+        ("Started", meta.started_at.to_rfc3339()),
+    ];
+
+    let backend = agent_backend::for_run(meta.backend.as_deref());
+    let mut items = Vec::new();
+    let mut final_turn = None;
+    let mut usage: Option<[i64; 3]> = None;
+    let mut failures = Vec::new();
+    let mut unparsed = 0;
+
+    for line in String::from_utf8_lossy(recording).lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if !backend.is_event(line) {
+            unparsed += 1;
+            continue;
+        }
+        for item in backend.parse_line(line) {
+            let structured = backend
+                .structured_output(&item)
+                .filter(|text| is_structured_turn(text))
+                .map(str::to_string);
+            if structured.is_some() {
+                final_turn = structured;
+                continue;
+            }
+            match item {
+                AgentItem::SessionStarted { session_id } => facts.push(("Thread", session_id)),
+                AgentItem::Usage {
+                    input_tokens,
+                    cached_input_tokens,
+                    output_tokens,
+                } => {
+                    let [input, cached, output] = usage.unwrap_or_default();
+                    usage = Some([
+                        input + input_tokens,
+                        cached + cached_input_tokens,
+                        output + output_tokens,
+                    ]);
+                }
+                AgentItem::TurnFailed { message } => failures.push(message),
+                item => items.push(item),
+            }
+        }
+    }
+    facts.push(("Backend", backend.name().to_string()));
+    blocks.push(Block::Facts(facts));
+
+    blocks.push(Block::Heading(2, "Prompt".into()));
+    match meta.prompt.as_deref() {
+        Some(prompt) => blocks.push(Block::Code {
+            lang: "text",
+            text: prompt.trim_end().to_string(),
+        }),
+        None => blocks.push(Block::Paragraph("No prompt was recorded.".into())),
+    }
+
+    blocks.push(Block::Heading(2, "Activity".into()));
+    if items.is_empty() {
+        blocks.push(Block::Paragraph("The run produced no items.".into()));
+    }
+    for item in items {
+        item_blocks(item, &mut blocks);
+    }
+    if unparsed > 0 {
+        blocks.push(Block::Paragraph(format!(
+            "{unparsed} line(s) of output were not agent events and are omitted."
+        )));
+    }
+
+    blocks.push(Block::Heading(2, "Final turn".into()));
+    match final_turn {
+        Some(text) => blocks.push(Block::Code {
+            lang: "json",
+            text: pretty_json(&text),
+        }),
+        None => blocks.push(Block::Paragraph(
+            "The run did not return a structured turn.".into(),
+        )),
+    }
+
+    blocks.push(Block::Heading(2, "Token usage".into()));
+    match usage {
+        Some([input, cached, output]) => blocks.push(Block::Facts(vec![
+            ("Input tokens", input.to_string()),
+            ("Cached input tokens", cached.to_string()),
+            ("Output tokens", output.to_string()),
+        ])),
+        None => blocks.push(Block::Paragraph("No usage was reported.".into())),
+    }
+
+    blocks.push(Block::Heading(2, "Exit".into()));
+    match meta.exit.as_ref() {
+        Some(exit) => {
+            let mut facts = vec![("Reason", exit.reason.clone())];
+            if let Some(code) = exit.code {
+                facts.push(("Exit code", code.to_string()));
+            }
+            if let Some(detail) = exit.detail.as_ref() {
+                facts.push(("Detail", detail.clone()));
+            }
+            facts.push(("Finished", exit.finished_at.to_rfc3339()));
+            blocks.push(Block::Facts(facts));
+        }
+        None => blocks.push(Block::Paragraph(
+            "No exit was recorded; the run may still be in progress.".into(),
+        )),
+    }
+    if !failures.is_empty() {
+        blocks.push(Block::List(failures));
+    }
+
+    blocks
+}
+
+fn item_blocks(item: AgentItem, blocks: &mut Vec<Block>) {
+    match item {
+        AgentItem::Message { text } => {
+            blocks.push(Block::Heading(3, "Message".into()));
+            blocks.push(Block::Paragraph(text));
+        }
+        AgentItem::Reasoning { text } => {
+            blocks.push(Block::Heading(3, "Reasoning".into()));
+            blocks.push(Block::Paragraph(text));
+        }
+        AgentItem::Command {
+            command,
+            output,
+            exit_code,
+            status,
+        } => {
+            blocks.push(Block::Heading(3, "Command".into()));
+            blocks.push(Block::Code {
+                lang: "sh",
+                text: command,
+            });
+            let mut facts = vec![("Status", status_label(status).to_string())];
+            if let Some(code) = exit_code {
+                facts.push(("Exit code", code.to_string()));
+            }
+            blocks.push(Block::Facts(facts));
+            if !output.trim().is_empty() {
+                blocks.push(Block::Code {
+                    lang: "text",
+                    text: output.trim_end().to_string(),
+                });
+            }
+        }
+        AgentItem::FileChanges { changes, status } => {
+            let status = match status {
+                ItemStatus::Failed => "failed",
+                ItemStatus::InProgress | ItemStatus::Completed => "applied",
+            };
+            blocks.push(Block::Heading(3, format!("File changes ({status})")));
+            blocks.push(Block::List(
+                changes
+                    .into_iter()
+                    .map(|entry| {
+                        let kind = match entry.kind {
+                            FileChangeKind::Add => "add",
+                            FileChangeKind::Delete => "delete",
+                            FileChangeKind::Update => "update",
+                        };
+                        format!("{kind} {}", entry.path)
+                    })
+                    .collect(),
+            ));
+        }
+        AgentItem::ToolCall {
+            server,
+            tool,
+            status,
+        } => {
+            blocks.push(Block::Heading(3, "MCP tool call".into()));
+            blocks.push(Block::Facts(vec![
+                ("Tool", format!("{server}::{tool}")),
+                ("Status", status_label(status).to_string()),
+            ]));
+        }
+        AgentItem::WebSearch { query } => {
+            blocks.push(Block::Heading(3, "Web search".into()));
+            blocks.push(Block::Paragraph(query));
+        }
+        AgentItem::TodoList { items } => {
+            blocks.push(Block::Heading(3, "Todo list".into()));
+            blocks.push(Block::List(
+                items
+                    .into_iter()
+                    .map(|item| {
+                        let mark = if item.completed { "[x]" } else { "[ ]" };
+                        format!("{mark} {}", item.text)
+                    })
+                    .collect(),
+            ));
+        }
+        AgentItem::Error { message } => {
+            blocks.push(Block::Heading(3, "Error".into()));
+            blocks.push(Block::Paragraph(message));
+        }
+        AgentItem::SessionStarted { .. }
+        | AgentItem::Usage { .. }
+        | AgentItem::TurnFailed { .. } => {}
+    }
+}
+
+fn status_label(status: ItemStatus) -> &'static str {
+    match status {
+        ItemStatus::InProgress => "in progress",
+        ItemStatus::Completed => "completed",
+        ItemStatus::Failed => "failed",
+    }
+}
+
+fn is_structured_turn(text: &str) -> bool {
+    serde_json::from_str::<WorkerTurn>(text).is_ok()
+        || serde_json::from_str::<OrchestratorTurn>(text).is_ok()
+}
+
+fn pretty_json(text: &str) -> String {
+    serde_json::from_str::<Value>(text)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| text.to_string())
+}
+
+fn to_markdown(blocks: &[Block]) -> String {
+    let mut out = String::new();
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => {
+                let _ = writeln!(out, "{} {text}\n", "#".repeat(*level as usize));
+            }
+            Block::Paragraph(text) => {
+                let _ = writeln!(out, "{}\n", text.trim());
+            }
+            Block::Facts(facts) => {
+                for (label, value) in facts {
+                    let _ = writeln!(out, "- **{label}:** {value}");
+                }
+                out.push('\n');
+            }
+            Block::List(items) => {
+                for item in items {
+                    let _ = writeln!(out, "- {item}");
+                }
+                out.push('\n');
+            }
+            Block::Code { lang, text } => {
+                let fence = fence_for(text);
+                let _ = writeln!(out, "{fence}{lang}\n{text}\n{fence}\n");
+            }
+        }
+    }
+    out
+}
+
+/// A backtick fence longer than any backtick run inside `text`.
+fn fence_for(text: &str) -> String {
+    let longest = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    "`".repeat(longest.max(2) + 1)
+}
+
+fn to_html(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>body{{font-family:sans-serif;max-width:60rem;margin:2rem auto;padding:0 1rem}}\
+         pre{{background:#f4f4f4;padding:.75rem;overflow-x:auto;white-space:pre-wrap}}</style>\n\
+         </head>\n<body>\n",
+        escape_html(title)
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => {
+                let _ = writeln!(out, "<h{level}>{}</h{level}>", escape_html(text));
+            }
+            Block::Paragraph(text) => {
+                let _ = writeln!(
+                    out,
+                    "<p>{}</p>",
+                    escape_html(text.trim()).replace('\n', "<br>\n")
+                );
+            }
+            Block::Facts(facts) => {
+                out.push_str("<ul>\n");
+                for (label, value) in facts {
+                    let _ = writeln!(
+                        out,
+                        "<li><strong>{label}:</strong> {}</li>",
+                        escape_html(value)
+                    );
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::List(items) => {
+                out.push_str("<ul>\n");
+                for item in items {
+                    let _ = writeln!(out, "<li>{}</li>", escape_html(item));
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::Code { lang, text } => {
+                let _ = writeln!(
+                    out,
+                    "<pre><code class=\"language-{lang}\">{}</code></pre>",
+                    escape_html(text)
+                );
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
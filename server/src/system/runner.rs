@@ -1,16 +1,18 @@
 use crate::{
-    docker::{DOCKER_IMAGE_ORCHESTRATOR, DOCKER_IMAGE_WORKER},
-    globals::PROJECT_DIR,
-    shared::{
-        codex_exec::CodexExecBuilder,
-        docker::{DockerRunBuilder, ensure_default_mcp_url},
+    docker::{
+        DOCKER_IMAGE_ORCHESTRATOR, DOCKER_IMAGE_WIZARD, DOCKER_IMAGE_WORKER, host_schema_path,
     },
+    globals::PROJECT_DIR,
+    shared::{codex_exec::CodexExecBuilder, docker::ensure_default_mcp_url},
     system::{
         codex_config::{self, AgentKind as CodexAgentKind},
-        docker_overrides,
+        container_limits, container_runtime, docker_overrides, mcp_tokens, worker_profiles,
     },
 };
-use std::{env, path::PathBuf};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Persona {
@@ -23,6 +25,8 @@ pub struct RunnerConfig {
     pub api_port: Option<u16>,
     pub model_override: Option<String>,
     pub reasoning_override: Option<String>,
+    /// Bearer token the run presents to `/mcp`; see [`mcp_tokens`].
+    pub mcp_token: Option<String>,
 }
 
 impl Default for RunnerConfig {
@@ -31,13 +35,18 @@ impl Default for RunnerConfig {
             api_port: Some(current_api_port()),
             model_override: None,
             reasoning_override: None,
+            mcp_token: None,
         }
     }
 }
 
+/// `docker_args` is empty when the host runtime runs codex directly; `env` and
+/// `working_dir` are only set in that case.
 pub struct CommandPlan {
     pub docker_args: Vec<String>,
     pub codex_args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub working_dir: Option<PathBuf>,
 }
 
 fn current_api_port() -> u16 {
@@ -61,18 +70,25 @@ pub fn plan_codex_run(
         Persona::Orchestrator => CodexAgentKind::Orchestrator,
         Persona::Worker(_) => CodexAgentKind::Worker,
     };
-    let agent_label = match persona {
-        Persona::Orchestrator => "orchestrator".to_string(),
-        Persona::Worker(id) => format!("ws{id}"),
+    let profile = match persona {
+        Persona::Orchestrator => None,
+        Persona::Worker(id) => worker_profiles::profile_for(id),
     };
     let launch_settings = codex_config::settings_for(agent_kind);
     let model = config
         .model_override
         .clone()
+        .or_else(|| profile.as_ref().and_then(|p| p.model.clone()))
         .unwrap_or_else(|| launch_settings.model.clone());
     let reasoning = config
         .reasoning_override
         .clone()
+        .or_else(|| {
+            profile
+                .as_ref()
+                .and_then(|p| p.reasoning)
+                .map(|r| r.to_string())
+        })
         .unwrap_or_else(|| launch_settings.reasoning.clone());
 
     let api_port = config.api_port.unwrap_or_else(current_api_port);
@@ -80,17 +96,19 @@ pub fn plan_codex_run(
 
     codex = codex
         .json(true)
-        .output_schema("/opt/robot-farm/schema.json")
         .config_override("mcp_servers.robot_farm.enabled=true")
         .config_override("mcp_servers.robot_farm.tool_timeout_sec=900")
         .config_override(format!("mcp_servers.robot_farm.url=\"{mcp_url}\""))
-        .config_override(format!(
-            "mcp_servers.robot_farm.http_headers.AGENT=\"{agent_label}\""
-        ))
         .config_override(format!("model=\"{}\"", launch_settings.model))
         .config_override(format!("model=\"{}\"", model))
         .config_override(format!("model_reasoning_effort=\"{}\"", reasoning));
 
+    let mut env = Vec::new();
+    if let Some(token) = config.mcp_token.as_deref() {
+        codex = codex.config_override(mcp_tokens::bearer_env_override());
+        env.push(mcp_tokens::token_env(token));
+    }
+
     let project_root = PathBuf::from(PROJECT_DIR.as_str());
     let (workspace_host, workspace_container) = match persona {
         Persona::Orchestrator => (project_root.join("staging"), PathBuf::from("/workspace")),
@@ -100,16 +118,32 @@ pub fn plan_codex_run(
         ),
     };
 
+    let image = image_for(persona);
+
+    let Some(docker) = container_runtime::current().run_builder(&image) else {
+        return CommandPlan {
+            docker_args: Vec::new(),
+            codex_args: codex
+                .output_schema(host_schema_path(&image))
+                .change_dir(workspace_host.display().to_string())
+                .build(),
+            env: [("CODEX_NO_COLOR".to_string(), "1".to_string())]
+                .into_iter()
+                .chain(env)
+                .collect(),
+            working_dir: Some(workspace_host),
+        };
+    };
+
     let docker_home = PathBuf::from("/home/codex");
     let docker_codex_home = docker_home.join(".codex");
     let host_codex_home = resolve_codex_home();
 
-    let image = match persona {
-        Persona::Orchestrator => DOCKER_IMAGE_ORCHESTRATOR.as_str(),
-        Persona::Worker(_) => DOCKER_IMAGE_WORKER.as_str(),
+    let worker_id = match persona {
+        Persona::Orchestrator => None,
+        Persona::Worker(id) => Some(id),
     };
-
-    let docker = DockerRunBuilder::new(image)
+    let docker = docker
         .remove_container(true)
         .interactive(true)
         .attach("STDOUT")
@@ -121,14 +155,137 @@ pub fn plan_codex_run(
         .env("CODEX_NO_COLOR", "1")
         .env("HOME", docker_home.display().to_string())
         .env("CODEX_HOME", docker_codex_home.display().to_string())
-        .env("PWD", workspace_container.display().to_string());
+        .env("PWD", workspace_container.display().to_string())
+        .inherit_env(mcp_tokens::TOKEN_ENV)
+        .limits(container_limits::limits_for(agent_kind, worker_id));
 
     let mut docker_args = docker.build();
-    docker_overrides::apply_overrides(agent_kind, &mut docker_args, image);
+    docker_overrides::apply_overrides(agent_kind, &mut docker_args, &image);
 
     CommandPlan {
         docker_args,
-        codex_args: codex.change_dir("/workspace").build(),
+        codex_args: codex
+            .output_schema("/opt/robot-farm/schema.json")
+            .change_dir("/workspace")
+            .build(),
+        env,
+        working_dir: None,
+    }
+}
+
+/// MCP tools the task wizard may call.
+const WIZARD_TOOLS: [&str; 10] = [
+    "tasks_list",
+    "tasks_get",
+    "tasks_create",
+    "tasks_update",
+    "tasks_set_status",
+    "task_groups_list",
+    "task_groups_get",
+    "task_groups_create",
+    "task_dependencies_get",
+    "task_dependencies_set",
+];
+
+/// `codex exec` for a task wizard turn in `workspace`, inside the wizard image unless
+/// the host runtime is selected.
+pub fn plan_codex_wizard(
+    session_id: Option<&str>,
+    workspace: &Path,
+    config: RunnerConfig,
+) -> CommandPlan {
+    let builder = match session_id {
+        Some(id) => CodexExecBuilder::resume().session_id(id.to_string()),
+        None => CodexExecBuilder::new(),
+    };
+
+    let launch_settings = codex_config::settings_for(CodexAgentKind::Wizard);
+    let tools_arg = format!(
+        "mcp_servers.robot_farm.enabled_tools=[{}]",
+        WIZARD_TOOLS
+            .iter()
+            .map(|tool| format!("\"{tool}\""))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    let api_port = config.api_port.unwrap_or_else(current_api_port);
+    let default_mcp_url = ensure_default_mcp_url(api_port);
+
+    let mut codex = builder
+        .json(true)
+        .config_override("mcp_servers.robot_farm.enabled=true")
+        .config_override("mcp_servers.robot_farm.tool_timeout_sec=900")
+        .config_override(tools_arg)
+        .config_override(format!("mcp_servers.robot_farm.url=\"{default_mcp_url}\""))
+        .config_override(format!("model=\"{}\"", launch_settings.model))
+        .config_override(format!(
+            "model_reasoning_effort=\"{}\"",
+            launch_settings.reasoning
+        ))
+        .skip_git_repo_check(true);
+    let mut env = Vec::new();
+    if let Some(token) = config.mcp_token.as_deref() {
+        codex = codex.config_override(mcp_tokens::bearer_env_override());
+        env.push(mcp_tokens::token_env(token));
+    }
+
+    match wizard_container_args(workspace) {
+        Some(docker_args) => CommandPlan {
+            docker_args,
+            codex_args: codex.change_dir("/workspace").build(),
+            env,
+            working_dir: None,
+        },
+        None => CommandPlan {
+            docker_args: Vec::new(),
+            codex_args: codex.change_dir(workspace.display().to_string()).build(),
+            env,
+            working_dir: None,
+        },
+    }
+}
+
+/// Container argv for a wizard run up to and including the image, or `None` under
+/// the host runtime.
+pub fn wizard_container_args(workspace: &Path) -> Option<Vec<String>> {
+    let docker = container_runtime::current().run_builder(DOCKER_IMAGE_WIZARD)?;
+    let mut docker_args = docker
+        .remove_container(true)
+        .interactive(true)
+        .attach("STDOUT")
+        .attach("STDERR")
+        .user("1000:1000")
+        .workdir("/workspace")
+        .volume(workspace, "/workspace", Some("rw".into()))
+        .volume(
+            format!(
+                "{}/.codex",
+                env::var("HOME").unwrap_or_else(|_| "/home/codex".to_string())
+            ),
+            "/home/codex/.codex",
+            None,
+        )
+        .inherit_env(mcp_tokens::TOKEN_ENV)
+        .limits(container_limits::limits_for(CodexAgentKind::Wizard, None))
+        .build();
+
+    docker_overrides::apply_overrides(
+        CodexAgentKind::Wizard,
+        &mut docker_args,
+        DOCKER_IMAGE_WIZARD,
+    );
+
+    Some(docker_args)
+}
+
+/// Image `persona` runs in, honouring the worker's profile.
+pub fn image_for(persona: Persona) -> String {
+    match persona {
+        Persona::Orchestrator => DOCKER_IMAGE_ORCHESTRATOR.to_string(),
+        Persona::Worker(id) => match worker_profiles::profile_for(id) {
+            Some(profile) => worker_profiles::image_for(&profile),
+            None => DOCKER_IMAGE_WORKER.to_string(),
+        },
     }
 }
 
@@ -138,3 +295,29 @@ fn resolve_codex_home() -> PathBuf {
         .unwrap_or_else(|_| PathBuf::from("."))
         .join(".codex")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mcp_token_travels_in_the_environment() {
+        let config = RunnerConfig {
+            api_port: Some(8080),
+            mcp_token: Some("tok-123".to_string()),
+            ..RunnerConfig::default()
+        };
+        let plan = plan_codex_wizard(None, Path::new("/tmp/wizard"), config);
+
+        let argv = plan.docker_args.iter().chain(&plan.codex_args);
+        assert!(argv.clone().all(|arg| !arg.contains("tok-123")));
+        assert!(
+            argv.clone()
+                .any(|arg| *arg == mcp_tokens::bearer_env_override())
+        );
+        assert!(
+            plan.env
+                .contains(&(mcp_tokens::TOKEN_ENV.to_string(), "tok-123".to_string()))
+        );
+    }
+}
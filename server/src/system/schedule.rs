@@ -0,0 +1,435 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime, TimeZone, Weekday};
+use openapi::models::{ActiveStrategy, ScheduleAction, ScheduleRule, ScheduleState, ScheduledRun};
+use parking_lot::RwLock;
+
+/// How far ahead `GET /schedule` looks for upcoming transitions.
+const UPCOMING_DAYS: i64 = 7;
+const UPCOMING_LIMIT: usize = 10;
+/// Longest gap between checks that is replayed (e.g. after the host slept or
+/// an override ran out); after a longer gap the schedule re-syncs to the
+/// windows open now instead.
+const MAX_CATCH_UP_DAYS: i64 = 2;
+
+/// Rule times are read in `Tz`: the host's zone in production, a fixed offset
+/// in tests so they do not depend on where they run.
+struct ScheduleStore<Tz: TimeZone = Local> {
+    rules: Vec<ScheduleRule>,
+    override_until: Option<i64>,
+    last_checked: Option<DateTime<Tz>>,
+    /// Strategy captured when a windowed SET_STRATEGY rule started, keyed by rule index.
+    windows: HashMap<usize, ActiveStrategy>,
+}
+
+impl<Tz: TimeZone> Default for ScheduleStore<Tz> {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            override_until: None,
+            last_checked: None,
+            windows: HashMap::new(),
+        }
+    }
+}
+
+static STORE: LazyLock<RwLock<ScheduleStore>> =
+    LazyLock::new(|| RwLock::new(ScheduleStore::default()));
+
+/// A rule boundary that was crossed: its start, or the end of its window.
+#[derive(Debug, Clone)]
+pub struct Transition<Tz: TimeZone = Local> {
+    pub index: usize,
+    pub rule: ScheduleRule,
+    pub ends_window: bool,
+    pub at: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> Transition<Tz> {
+    pub fn label(&self) -> String {
+        rule_label(self.index, &self.rule)
+    }
+}
+
+pub fn replace(rules: Vec<ScheduleRule>) {
+    let mut store = STORE.write();
+    store.rules = rules;
+    store.windows.clear();
+}
+
+pub fn reset() {
+    replace(Vec::new());
+}
+
+pub fn set_override(until: i64) {
+    STORE.write().override_until = Some(until);
+}
+
+pub fn clear_override() -> Option<i64> {
+    STORE.write().override_until.take()
+}
+
+/// Clear the override once it has run out, returning when it ended.
+pub fn take_expired_override(now: i64) -> Option<i64> {
+    let mut store = STORE.write();
+    match store.override_until {
+        Some(until) if until <= now => store.override_until.take(),
+        _ => None,
+    }
+}
+
+/// Keep the strategy a window replaced. Re-entering a window that is already
+/// open keeps the strategy captured the first time.
+pub fn remember_window(index: usize, previous: ActiveStrategy) {
+    STORE.write().windows.entry(index).or_insert(previous);
+}
+
+pub fn take_window(index: usize) -> Option<ActiveStrategy> {
+    STORE.write().windows.remove(&index)
+}
+
+/// Transitions crossed since the previous call. Nothing is due while an
+/// override is active; the checks it suppressed are replayed once it ends.
+pub fn due_transitions(now: DateTime<Local>) -> Vec<Transition> {
+    STORE.write().due_transitions(now)
+}
+
+impl<Tz: TimeZone> ScheduleStore<Tz> {
+    fn due_transitions(&mut self, now: DateTime<Tz>) -> Vec<Transition<Tz>> {
+        if self
+            .override_until
+            .is_some_and(|until| now.timestamp() < until)
+        {
+            return Vec::new();
+        }
+        let previous = self.last_checked.replace(now.clone());
+
+        let mut due: Vec<Transition<Tz>> = match previous {
+            Some(last) if now.clone() - last.clone() <= Duration::days(MAX_CATCH_UP_DAYS) => self
+                .rules
+                .iter()
+                .enumerate()
+                .flat_map(|(index, rule)| transitions_between(index, rule, &last, &now))
+                .collect(),
+            _ => self.resync(&now),
+        };
+        due.sort_by(|a, b| a.at.cmp(&b.at));
+        due
+    }
+
+    /// Enter every window open at `now` and close the captured ones that are
+    /// not, instead of replaying an unknown or too long stretch of the past.
+    fn resync(&self, now: &DateTime<Tz>) -> Vec<Transition<Tz>> {
+        self.rules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| {
+                let (ends_window, at) = match open_window_start(rule, now) {
+                    Some(start) => (false, start),
+                    None if self.windows.contains_key(&index) => (true, now.clone()),
+                    None => return None,
+                };
+                Some(Transition {
+                    index,
+                    rule: rule.clone(),
+                    ends_window,
+                    at,
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn snapshot(now: DateTime<Local>) -> ScheduleState {
+    let store = STORE.read();
+    let horizon = now + Duration::days(UPCOMING_DAYS);
+    let mut upcoming: Vec<Transition> = store
+        .rules
+        .iter()
+        .enumerate()
+        .flat_map(|(index, rule)| transitions_between(index, rule, &now, &horizon))
+        .collect();
+    upcoming.sort_by_key(|transition| transition.at);
+
+    ScheduleState {
+        rules: store.rules.clone(),
+        override_until: store.override_until,
+        upcoming: upcoming
+            .into_iter()
+            .take(UPCOMING_LIMIT)
+            .map(|transition| ScheduledRun {
+                rule: transition.label(),
+                action: transition.rule.action,
+                strategy: transition.rule.strategy.clone(),
+                at: transition.at.timestamp(),
+                ends_window: transition.ends_window,
+            })
+            .collect(),
+    }
+}
+
+pub fn validate_rules(
+    rules: &[ScheduleRule],
+    is_strategy: impl Fn(&str) -> bool,
+) -> Result<(), String> {
+    for (index, rule) in rules.iter().enumerate() {
+        let label = rule_label(index, rule);
+        if parse_time(&rule.at).is_none() {
+            return Err(format!("schedule '{label}': invalid time '{}'", rule.at));
+        }
+        if let Some(until) = rule.until.as_deref().filter(|t| parse_time(t).is_none()) {
+            return Err(format!("schedule '{label}': invalid time '{until}'"));
+        }
+        for day in rule.days.iter().flatten() {
+            if day.parse::<Weekday>().is_err() {
+                return Err(format!("schedule '{label}': invalid day '{day}'"));
+            }
+        }
+        if rule.action == ScheduleAction::SetStrategy {
+            match rule.strategy.as_deref() {
+                Some(name) if is_strategy(name) => {}
+                Some(name) => {
+                    return Err(format!("schedule '{label}': unknown strategy '{name}'"));
+                }
+                None => {
+                    return Err(format!("schedule '{label}': SET_STRATEGY needs a strategy"));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn rule_label(index: usize, rule: &ScheduleRule) -> String {
+    rule.name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("#{}", index + 1))
+}
+
+fn parse_time(raw: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(raw.trim(), "%H:%M").ok()
+}
+
+fn runs_on(rule: &ScheduleRule, date: NaiveDate) -> bool {
+    match rule.days.as_deref() {
+        None | Some([]) => true,
+        Some(days) => days
+            .iter()
+            .filter_map(|day| day.parse::<Weekday>().ok())
+            .any(|day| day == date.weekday()),
+    }
+}
+
+fn local_at<Tz: TimeZone>(tz: &Tz, date: NaiveDate, time: NaiveTime) -> Option<DateTime<Tz>> {
+    tz.from_local_datetime(&date.and_time(time)).earliest()
+}
+
+/// Start and (optional) end of the occurrence of `rule` that starts on `date`
+/// in `tz`.
+fn occurrence<Tz: TimeZone>(
+    rule: &ScheduleRule,
+    tz: &Tz,
+    date: NaiveDate,
+) -> Option<(DateTime<Tz>, Option<DateTime<Tz>>)> {
+    if !runs_on(rule, date) {
+        return None;
+    }
+    let at = parse_time(&rule.at)?;
+    let start = local_at(tz, date, at)?;
+    let end = rule
+        .until
+        .as_deref()
+        .and_then(parse_time)
+        .and_then(|until| {
+            let end_date = if until <= at { date.succ_opt()? } else { date };
+            local_at(tz, end_date, until)
+        });
+    Some((start, end))
+}
+
+fn open_window_start<Tz: TimeZone>(
+    rule: &ScheduleRule,
+    now: &DateTime<Tz>,
+) -> Option<DateTime<Tz>> {
+    let today = now.date_naive();
+    let tz = now.timezone();
+    [today.pred_opt()?, today].into_iter().find_map(|date| {
+        let (start, end) = occurrence(rule, &tz, date)?;
+        (&start <= now && now < &end?).then_some(start)
+    })
+}
+
+fn transitions_between<Tz: TimeZone>(
+    index: usize,
+    rule: &ScheduleRule,
+    from: &DateTime<Tz>,
+    to: &DateTime<Tz>,
+) -> Vec<Transition<Tz>> {
+    let mut transitions = Vec::new();
+    let tz = from.timezone();
+    // Windows can start the day before and end after midnight.
+    let mut date = from.date_naive() - Duration::days(1);
+    while date <= to.date_naive() {
+        if let Some((start, end)) = occurrence(rule, &tz, date) {
+            if from < &start && &start <= to {
+                transitions.push(Transition {
+                    index,
+                    rule: rule.clone(),
+                    ends_window: false,
+                    at: start,
+                });
+            }
+            if let Some(end) = end.filter(|end| from < end && end <= to) {
+                transitions.push(Transition {
+                    index,
+                    rule: rule.clone(),
+                    ends_window: true,
+                    at: end,
+                });
+            }
+        }
+        let Some(next) = date.succ_opt() else {
+            break;
+        };
+        date = next;
+    }
+    transitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::FixedOffset;
+
+    /// A fixed zone, so results do not depend on the host's zone or its DST dates.
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(2 * 3600)
+            .expect("valid offset")
+            .with_ymd_and_hms(2025, 3, day, hour, minute, 0)
+            .single()
+            .expect("unambiguous time")
+    }
+
+    fn rule(at: &str, until: Option<&str>, action: ScheduleAction) -> ScheduleRule {
+        ScheduleRule {
+            until: until.map(str::to_string),
+            strategy: (action == ScheduleAction::SetStrategy).then(|| "MODERATE".to_string()),
+            ..ScheduleRule::new(at.to_string(), action)
+        }
+    }
+
+    fn store(
+        rules: Vec<ScheduleRule>,
+        last_checked: DateTime<FixedOffset>,
+    ) -> ScheduleStore<FixedOffset> {
+        ScheduleStore {
+            rules,
+            last_checked: Some(last_checked),
+            ..ScheduleStore::default()
+        }
+    }
+
+    fn summary(due: &[Transition<FixedOffset>]) -> Vec<(usize, bool, DateTime<FixedOffset>)> {
+        due.iter()
+            .map(|transition| (transition.index, transition.ends_window, transition.at))
+            .collect()
+    }
+
+    #[test]
+    fn replays_transitions_crossed_since_last_check() {
+        let mut store = store(
+            vec![
+                rule("09:00", None, ScheduleAction::Pause),
+                rule("12:00", None, ScheduleAction::Resume),
+            ],
+            at(10, 8, 0),
+        );
+        let due = store.due_transitions(at(11, 10, 0));
+        assert_eq!(
+            summary(&due),
+            [
+                (0, false, at(10, 9, 0)),
+                (1, false, at(10, 12, 0)),
+                (0, false, at(11, 9, 0)),
+            ]
+        );
+        assert!(store.due_transitions(at(11, 11, 0)).is_empty());
+    }
+
+    #[test]
+    fn gaps_longer_than_catch_up_resync_to_open_windows() {
+        let mut store = store(
+            vec![
+                rule("09:00", None, ScheduleAction::Pause),
+                rule("08:00", Some("17:00"), ScheduleAction::SetStrategy),
+            ],
+            at(3, 12, 0),
+        );
+        let due = store.due_transitions(at(10, 12, 0));
+        assert_eq!(summary(&due), [(1, false, at(10, 8, 0))]);
+    }
+
+    #[test]
+    fn override_holds_transitions_until_it_ends() {
+        let mut store = store(
+            vec![rule("09:00", None, ScheduleAction::Pause)],
+            at(10, 8, 0),
+        );
+        store.override_until = Some(at(10, 10, 0).timestamp());
+        assert!(store.due_transitions(at(10, 9, 30)).is_empty());
+
+        store.override_until = None;
+        let due = store.due_transitions(at(10, 10, 0));
+        assert_eq!(summary(&due), [(0, false, at(10, 9, 0))]);
+    }
+
+    #[test]
+    fn long_override_enters_open_window_and_closes_finished_ones() {
+        let mut store = store(
+            vec![
+                rule("08:00", Some("17:00"), ScheduleAction::SetStrategy),
+                rule("01:00", Some("03:00"), ScheduleAction::SetStrategy),
+            ],
+            at(5, 2, 0),
+        );
+        store
+            .windows
+            .insert(1, ActiveStrategy::new("PLANNING".into()));
+        store.override_until = Some(at(10, 12, 0).timestamp());
+        assert!(store.due_transitions(at(9, 12, 0)).is_empty());
+
+        store.override_until = None;
+        let due = store.due_transitions(at(10, 12, 0));
+        assert_eq!(
+            summary(&due),
+            [(0, false, at(10, 8, 0)), (1, true, at(10, 12, 0))]
+        );
+    }
+
+    #[test]
+    fn windows_span_midnight() {
+        let night = rule("22:00", Some("02:00"), ScheduleAction::Pause);
+        let mut store = ScheduleStore::<FixedOffset> {
+            rules: vec![night],
+            ..ScheduleStore::default()
+        };
+        let due = store.due_transitions(at(11, 1, 0));
+        assert_eq!(summary(&due), [(0, false, at(10, 22, 0))]);
+
+        let due = store.due_transitions(at(11, 3, 0));
+        assert_eq!(summary(&due), [(0, true, at(11, 2, 0))]);
+    }
+
+    #[test]
+    fn upcoming_covers_the_whole_week() {
+        let daily = rule("09:00", None, ScheduleAction::Resume);
+        let upcoming = transitions_between(0, &daily, &at(10, 10, 0), &at(17, 10, 0));
+        assert_eq!(upcoming.len(), 7);
+        assert_eq!(upcoming[0].at, at(11, 9, 0));
+    }
+}
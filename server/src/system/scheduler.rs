@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+
+use openapi::models::Task;
+
+use crate::models::strategy::Strategy;
+
+use super::features;
+
+/// What one scheduler pass can do with the ready work it found.
+#[derive(Debug, PartialEq)]
+pub enum SchedulerCandidates {
+    /// Ready tasks, highest priority first.
+    Ready(Vec<Task>),
+    /// Nothing is ready but workers are still busy; check again later.
+    Waiting,
+    /// The orchestrator has to decide; carries the reason.
+    Handoff(&'static str),
+}
+
+/// Number of workers the built-in scheduler keeps busy for a strategy, or
+/// `None` when the strategy is left to the orchestrator.
+pub fn worker_budget(strategy: &Strategy) -> Option<usize> {
+    if !strategy.scheduler || !strategy.allow_assignments {
+        return None;
+    }
+    Some(strategy.busy_workers.unwrap_or(usize::MAX))
+}
+
+/// True when the deterministic scheduler owns task assignment for `strategy`.
+pub fn is_active(strategy: &Strategy) -> bool {
+    features::deterministic_scheduler() && worker_budget(strategy).is_some()
+}
+
+/// Idle workers the scheduler may assign to, in `idle` order: paused workers
+/// and workers in `busy` (owning a task, queued for or holding a terminal) are
+/// left alone.
+pub fn assignable_workers(idle: &[i64], busy: &HashSet<i64>, paused: &HashSet<i64>) -> Vec<i64> {
+    idle.iter()
+        .copied()
+        .filter(|worker_id| !busy.contains(worker_id) && !paused.contains(worker_id))
+        .collect()
+}
+
+/// How many of `idle` workers may get a task while `busy` are already working,
+/// keeping at most `budget` busy.
+pub fn open_slots(budget: usize, busy: usize, idle: usize) -> usize {
+    budget.saturating_sub(busy).min(idle)
+}
+
+/// Groups to draw tasks from, given the open task count of each group. Focus
+/// groups come first; the strategy's own groups (chores and the like) are only
+/// used once every focus group is finished. With `sequential` only the first
+/// open group is used. `Err` carries the reason to hand off to the orchestrator.
+pub fn candidate_groups(
+    focus: &[i64],
+    fallback: &[i64],
+    sequential: bool,
+    open: &HashMap<i64, i64>,
+) -> Result<Vec<i64>, &'static str> {
+    if focus.is_empty() && fallback.is_empty() {
+        return Err("no focus groups are set");
+    }
+    let open_groups = |group_ids: &[i64]| {
+        let unfinished = group_ids
+            .iter()
+            .copied()
+            .filter(|group_id| open.get(group_id).is_some_and(|count| *count > 0));
+        if sequential {
+            unfinished.take(1).collect::<Vec<_>>()
+        } else {
+            unfinished.collect()
+        }
+    };
+    let mut groups = open_groups(focus);
+    if groups.is_empty() {
+        groups = open_groups(fallback);
+    }
+    if groups.is_empty() {
+        return Err("all eligible task groups are complete");
+    }
+    Ok(groups)
+}
+
+/// Orders schedulable `tasks` by priority, then age. With none ready, waits for
+/// the `busy` workers to finish, or hands off when nothing is in flight: the
+/// remaining work is blocked or waiting on dependencies outside the focus.
+pub fn rank_candidates(mut tasks: Vec<Task>, busy: usize) -> SchedulerCandidates {
+    if tasks.is_empty() {
+        if busy == 0 {
+            return SchedulerCandidates::Handoff("no ready task has all of its dependencies done");
+        }
+        return SchedulerCandidates::Waiting;
+    }
+    tasks.sort_by(|a, b| {
+        b.priority
+            .unwrap_or_default()
+            .cmp(&a.priority.unwrap_or_default())
+            .then(a.id.cmp(&b.id))
+    });
+    SchedulerCandidates::Ready(tasks)
+}
+
+pub fn format_assignment(task: &Task) -> String {
+    let mut sections = vec![format!("New assignment: {} ({})", task.slug, task.title)];
+    let description = task.description.trim();
+    if !description.is_empty() {
+        sections.push(format!("Details:\n{description}"));
+    }
+    sections.push(
+        "Respond with STATUS_UPDATE for long-running work and COMPLETE_TASK when finished."
+            .to_string(),
+    );
+    sections.join("\n\n")
+}
+
+pub fn format_handoff(reason: &str, idle_workers: &[i64]) -> String {
+    let idle = idle_workers
+        .iter()
+        .map(|id| format!("ws{id}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("Scheduler handoff: {reason}. Idle workers: [{idle}].")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapi::models::TaskStatus;
+
+    fn task(id: i64, priority: Option<i64>) -> Task {
+        Task {
+            priority,
+            ..Task::new(
+                id,
+                1,
+                format!("task-{id}"),
+                format!("Task {id}"),
+                TaskStatus::Ready,
+                "orchestrator".to_string(),
+                String::new(),
+            )
+        }
+    }
+
+    fn ids(candidates: SchedulerCandidates) -> Vec<i64> {
+        match candidates {
+            SchedulerCandidates::Ready(tasks) => tasks.iter().map(|task| task.id).collect(),
+            other => panic!("expected ready tasks, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn assignable_workers_skip_paused_and_busy_workers() {
+        let busy = HashSet::from([2]);
+        let paused = HashSet::from([3]);
+
+        assert_eq!(
+            assignable_workers(&[4, 3, 2, 1], &busy, &paused),
+            vec![4, 1]
+        );
+        assert!(assignable_workers(&[3], &busy, &paused).is_empty());
+    }
+
+    #[test]
+    fn open_slots_keep_busy_workers_within_budget() {
+        assert_eq!(open_slots(2, 0, 4), 2);
+        assert_eq!(open_slots(2, 1, 4), 1);
+        assert_eq!(open_slots(2, 3, 4), 0);
+        assert_eq!(open_slots(usize::MAX, 1, 3), 3);
+        assert_eq!(open_slots(5, 0, 0), 0);
+    }
+
+    #[test]
+    fn candidate_groups_fall_back_to_strategy_groups_once_focus_is_done() {
+        let open = HashMap::from([(1, 0), (2, 3), (3, 2), (10, 1), (11, 4)]);
+
+        assert_eq!(
+            candidate_groups(&[1, 2, 3], &[10], false, &open),
+            Ok(vec![2, 3])
+        );
+        assert_eq!(
+            candidate_groups(&[1, 2, 3], &[10], true, &open),
+            Ok(vec![2])
+        );
+        assert_eq!(
+            candidate_groups(&[1], &[10, 11], false, &open),
+            Ok(vec![10, 11])
+        );
+        assert_eq!(candidate_groups(&[], &[10, 11], true, &open), Ok(vec![10]));
+        assert_eq!(
+            candidate_groups(&[1], &[99], false, &open),
+            Err("all eligible task groups are complete")
+        );
+        assert_eq!(
+            candidate_groups(&[], &[], false, &open),
+            Err("no focus groups are set")
+        );
+    }
+
+    #[test]
+    fn rank_candidates_orders_by_priority_then_id() {
+        let tasks = vec![
+            task(3, None),
+            task(1, Some(-1)),
+            task(4, Some(5)),
+            task(2, None),
+        ];
+
+        assert_eq!(ids(rank_candidates(tasks, 0)), vec![4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn rank_candidates_waits_for_busy_workers_before_handing_off() {
+        assert_eq!(rank_candidates(Vec::new(), 2), SchedulerCandidates::Waiting);
+        assert_eq!(
+            rank_candidates(Vec::new(), 0),
+            SchedulerCandidates::Handoff("no ready task has all of its dependencies done")
+        );
+    }
+}
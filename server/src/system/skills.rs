@@ -0,0 +1,203 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::LazyLock,
+};
+
+use openapi::models::{Task, TaskGroup, config::SkillMismatch};
+use parking_lot::RwLock;
+
+use crate::db::{DbResult, task_group};
+
+use super::worker_profiles;
+
+static POLICY: LazyLock<RwLock<SkillMismatch>> =
+    LazyLock::new(|| RwLock::new(SkillMismatch::default()));
+
+pub fn replace(policy: SkillMismatch) {
+    *POLICY.write() = policy;
+}
+
+pub fn reset() {
+    replace(SkillMismatch::default());
+}
+
+pub fn mismatch_policy() -> SkillMismatch {
+    *POLICY.read()
+}
+
+/// Skills `wsN` provides through its worker profile, lowercase and sorted.
+pub fn provided_by(worker_id: i64) -> Vec<String> {
+    let skills: BTreeSet<String> = worker_profiles::profile_for(worker_id)
+        .and_then(|profile| profile.skills)
+        .unwrap_or_default()
+        .iter()
+        .map(|skill| skill.trim().to_ascii_lowercase())
+        .filter(|skill| !skill.is_empty())
+        .collect();
+    skills.into_iter().collect()
+}
+
+/// Whether any worker profile declares skills. Skill routing stays out of prompts until then.
+pub fn any_declared() -> bool {
+    worker_profiles::snapshot()
+        .iter()
+        .any(|profile| profile.skills.as_ref().is_some_and(|s| !s.is_empty()))
+}
+
+/// Entries of `required` that `provided` lacks.
+pub fn missing(required: &[String], provided: &[String]) -> Vec<String> {
+    required
+        .iter()
+        .filter(|skill| !provided.contains(skill))
+        .cloned()
+        .collect()
+}
+
+/// Required skills per task group slug.
+pub fn group_requirements(groups: &[TaskGroup]) -> HashMap<String, Vec<String>> {
+    groups
+        .iter()
+        .map(|group| {
+            (
+                group.slug.clone(),
+                group.required_skills.clone().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+/// Skills `task` requires, including those of its group.
+pub async fn required_for(task: &Task) -> DbResult<Vec<String>> {
+    let mut skills: BTreeSet<String> = task
+        .required_skills
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    if let Some(group) = task_group::get_task_group(task.group_id).await? {
+        skills.extend(group.required_skills.unwrap_or_default());
+    }
+    Ok(skills.into_iter().collect())
+}
+
+/// Groups from `groups` whose skills `worker_id` provides.
+pub fn eligible_groups(
+    worker_id: i64,
+    groups: &[String],
+    requirements: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let provided = provided_by(worker_id);
+    groups
+        .iter()
+        .filter(|group| {
+            requirements
+                .get(group.as_str())
+                .is_none_or(|required| missing(required, &provided).is_empty())
+        })
+        .cloned()
+        .collect()
+}
+
+/// Position in `candidates` of the first worker providing every skill in `required`.
+pub fn first_capable(required: &[String], candidates: &[i64]) -> Option<usize> {
+    candidates
+        .iter()
+        .position(|worker_id| missing(required, &provided_by(*worker_id)).is_empty())
+}
+
+/// Whether an assignment missing `missing` skills goes ahead under `policy`.
+pub fn assignment_allowed(missing: &[String], policy: SkillMismatch) -> bool {
+    missing.is_empty() || !matches!(policy, SkillMismatch::Reject)
+}
+
+/// `ws1 [flutter, sql], ws2 [none]`
+pub fn describe(worker_ids: &[i64]) -> String {
+    worker_ids
+        .iter()
+        .map(|id| {
+            let skills = provided_by(*id);
+            let list = if skills.is_empty() {
+                "none".to_string()
+            } else {
+                skills.join(", ")
+            };
+            format!("ws{id} [{list}]")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapi::models::WorkerProfile;
+
+    fn skills(list: &[&str]) -> Vec<String> {
+        list.iter().map(|skill| skill.to_string()).collect()
+    }
+
+    /// ws1 provides flutter and sql, ws2 only sql; ws3 has no profile. Every test
+    /// installs the same profiles, so running them in parallel is harmless.
+    fn install_profiles() {
+        worker_profiles::replace(vec![
+            WorkerProfile {
+                skills: Some(skills(&["Flutter", "sql"])),
+                ..WorkerProfile::new("mobile".into(), vec!["ws1".into()])
+            },
+            WorkerProfile {
+                skills: Some(skills(&["sql"])),
+                ..WorkerProfile::new("data".into(), vec!["ws2".into()])
+            },
+        ]);
+    }
+
+    #[test]
+    fn eligible_groups_keeps_groups_the_worker_can_serve() {
+        install_profiles();
+        let requirements = HashMap::from([
+            ("mobile".to_string(), skills(&["flutter"])),
+            ("reports".to_string(), skills(&["sql"])),
+            ("docs".to_string(), Vec::new()),
+        ]);
+        let groups = skills(&["mobile", "reports", "docs", "unknown"]);
+
+        assert_eq!(
+            eligible_groups(1, &groups, &requirements),
+            skills(&["mobile", "reports", "docs", "unknown"])
+        );
+        assert_eq!(
+            eligible_groups(2, &groups, &requirements),
+            skills(&["reports", "docs", "unknown"])
+        );
+        assert_eq!(
+            eligible_groups(3, &groups, &requirements),
+            skills(&["docs", "unknown"])
+        );
+    }
+
+    #[test]
+    fn assignment_policy_rejects_only_missing_skills_under_reject() {
+        install_profiles();
+        let required = skills(&["flutter"]);
+        let lacking = missing(&required, &provided_by(2));
+        assert_eq!(lacking, skills(&["flutter"]));
+
+        assert!(!assignment_allowed(&lacking, SkillMismatch::Reject));
+        assert!(assignment_allowed(&lacking, SkillMismatch::Warn));
+        assert!(assignment_allowed(
+            &missing(&required, &provided_by(1)),
+            SkillMismatch::Reject
+        ));
+    }
+
+    #[test]
+    fn scheduler_picks_the_first_idle_worker_with_every_skill() {
+        install_profiles();
+        let idle = [3, 2, 1];
+
+        assert_eq!(first_capable(&skills(&["flutter", "sql"]), &idle), Some(2));
+        assert_eq!(first_capable(&skills(&["sql"]), &idle), Some(1));
+        assert_eq!(first_capable(&[], &idle), Some(0));
+        assert_eq!(first_capable(&skills(&["rust"]), &idle), None);
+    }
+}
@@ -1,6 +1,156 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
 use once_cell::sync::OnceCell;
-use openapi::models::{ActiveStrategy, Strategy};
+use openapi::models::{ActiveStrategy, StrategyDefinition, TaskGroup};
 use parking_lot::RwLock;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::models::strategy::{self as strategy_model, Strategy};
+use crate::realtime::{self, RealtimeEvent};
+
+/// Strategy the system starts in and falls back to when the active one is
+/// removed from config.
+pub const DEFAULT_STRATEGY: &str = "PLANNING";
+
+static REGISTRY: LazyLock<RwLock<Vec<Strategy>>> =
+    LazyLock::new(|| RwLock::new(strategy_model::presets()));
+
+/// Replace config-defined strategies. Built-in presets stay available unless a
+/// definition with the same name overrides them.
+pub fn replace(definitions: Vec<StrategyDefinition>) {
+    let mut strategies = strategy_model::presets();
+    for definition in definitions {
+        let strategy = Strategy::from(definition);
+        match strategies.iter_mut().find(|s| s.name == strategy.name) {
+            Some(existing) => *existing = strategy,
+            None => strategies.push(strategy),
+        }
+    }
+    *REGISTRY.write() = strategies;
+    revalidate_active();
+}
+
+pub fn reset() {
+    *REGISTRY.write() = strategy_model::presets();
+    revalidate_active();
+}
+
+pub fn list() -> Vec<Strategy> {
+    REGISTRY.read().clone()
+}
+
+pub fn get(name: &str) -> Option<Strategy> {
+    let name = name.trim();
+    REGISTRY
+        .read()
+        .iter()
+        .find(|strategy| strategy.name.eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+/// Look up the strategy for `name`, falling back to PLANNING so an unknown id
+/// never hands out work. Unknown ids are reported once, when config loads
+/// (see [`revalidate_active`]), not on every lookup.
+pub fn resolve(name: &str) -> Strategy {
+    get(name)
+        .unwrap_or_else(|| get(DEFAULT_STRATEGY).expect("PLANNING preset is always registered"))
+}
+
+/// Group slugs `strategy` sources assignments from: its focus group ids translated
+/// through `groups`, followed by the definition's own groups.
+pub fn eligible_group_slugs(
+    strategy: &ActiveStrategy,
+    definition: &Strategy,
+    groups: &[TaskGroup],
+) -> Vec<String> {
+    let mut slugs: Vec<String> = strategy
+        .focus
+        .iter()
+        .flatten()
+        .filter_map(|id| groups.iter().find(|group| group.id == *id))
+        .map(|group| group.slug.clone())
+        .collect();
+    for slug in &definition.groups {
+        if !slugs.contains(slug) {
+            slugs.push(slug.clone());
+        }
+    }
+    slugs
+}
+
+/// True when `name` is a preset or one of `definitions`; used to check config
+/// before it is applied.
+pub fn is_defined(name: &str, definitions: &[StrategyDefinition]) -> bool {
+    let name = name.trim();
+    strategy_model::presets()
+        .iter()
+        .any(|strategy| strategy.name.eq_ignore_ascii_case(name))
+        || definitions
+            .iter()
+            .any(|definition| definition.name.trim().eq_ignore_ascii_case(name))
+}
+
+pub fn validate_definitions(definitions: &[StrategyDefinition]) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for definition in definitions {
+        let name = definition.name.trim();
+        let valid_name = name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+        if !valid_name {
+            return Err(format!(
+                "strategy name '{name}' must be upper-case letters, digits and underscores"
+            ));
+        }
+        if !seen.insert(name) {
+            return Err(format!("strategy '{name}' is defined more than once"));
+        }
+        if definition.hint.trim().is_empty() {
+            return Err(format!("strategy '{name}' needs a hint"));
+        }
+        if definition.busy_workers.is_some_and(|count| count < 1) {
+            return Err(format!(
+                "strategy '{name}': busy_workers must be at least 1"
+            ));
+        }
+        for tool in definition.tools.iter().flatten() {
+            if !crate::mcp::is_known_tool(tool) {
+                return Err(format!("strategy '{name}': unknown tool '{tool}'"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Drop back to the default strategy if the active one was removed from config.
+fn revalidate_active() {
+    let Some(state) = STRATEGY_STATE.get() else {
+        return;
+    };
+    let id = state.inner.read().id.clone();
+    if get(&id).is_some() {
+        return;
+    }
+    warn!(strategy = %id, "active strategy no longer defined; switching to {DEFAULT_STRATEGY}");
+    let fallback = ActiveStrategy {
+        id: DEFAULT_STRATEGY.to_string(),
+        focus: Some(vec![]),
+    };
+    *state.inner.write() = fallback.clone();
+    realtime::publish(RealtimeEvent::StrategyState {
+        id: fallback.id,
+        focus: vec![],
+    });
+}
+
+#[derive(Debug, Error)]
+pub enum StrategyError {
+    #[error("unknown strategy: {0}")]
+    Unknown(String),
+}
 
 pub struct StrategyState {
     inner: RwLock<ActiveStrategy>,
@@ -12,7 +162,7 @@ impl StrategyState {
     pub fn init_global() -> &'static StrategyState {
         STRATEGY_STATE.get_or_init(|| StrategyState {
             inner: RwLock::new(ActiveStrategy {
-                id: Strategy::Planning,
+                id: DEFAULT_STRATEGY.to_string(),
                 focus: Some(vec![]),
             }),
         })
@@ -28,24 +178,83 @@ impl StrategyState {
         self.inner.read().clone()
     }
 
-    pub fn update(&self, mut strategy: ActiveStrategy) -> ActiveStrategy {
-        match strategy.id {
-            Strategy::Planning | Strategy::WindDown => {
-                strategy.focus = Some(vec![]);
-            }
-            Strategy::Aggressive | Strategy::HotfixSwarm | Strategy::BugSmash => {
-                if strategy.focus.is_none() {
-                    strategy.focus = Some(vec![]);
-                }
-            }
-            Strategy::Moderate | Strategy::Economical => {
-                if strategy.focus.is_none() {
-                    strategy.focus = Some(vec![]);
-                }
-            }
+    /// Definition backing the active strategy.
+    pub fn active(&self) -> Strategy {
+        resolve(&self.inner.read().id)
+    }
+
+    pub fn update(&self, mut strategy: ActiveStrategy) -> Result<ActiveStrategy, StrategyError> {
+        let definition =
+            get(&strategy.id).ok_or_else(|| StrategyError::Unknown(strategy.id.clone()))?;
+        strategy.id = definition.name;
+        if !definition.allow_assignments || strategy.focus.is_none() {
+            strategy.focus = Some(vec![]);
         }
 
         *self.inner.write() = strategy.clone();
-        strategy
+        Ok(strategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(name: &str) -> StrategyDefinition {
+        StrategyDefinition::new(name.to_string(), "Assign tasks from {focus}.".to_string())
+    }
+
+    fn rejected(definitions: &[StrategyDefinition]) -> String {
+        validate_definitions(definitions).expect_err("definitions should be rejected")
+    }
+
+    #[test]
+    fn built_in_presets_validate_and_round_trip() {
+        let presets = strategy_model::presets();
+        let definitions: Vec<_> = presets.iter().map(Strategy::to_definition).collect();
+
+        assert_eq!(validate_definitions(&definitions), Ok(()));
+        for (preset, definition) in presets.iter().zip(definitions) {
+            assert_eq!(&Strategy::from(definition), preset);
+        }
+        assert!(presets.iter().any(|preset| preset.name == DEFAULT_STRATEGY));
+    }
+
+    #[test]
+    fn validate_definitions_rejects_bad_definitions() {
+        let duplicate = rejected(&[definition("FOCUSED"), definition(" FOCUSED ")]);
+        assert!(duplicate.contains("defined more than once"), "{duplicate}");
+
+        let lower_case = rejected(&[definition("focused")]);
+        assert!(lower_case.contains("upper-case"), "{lower_case}");
+
+        let no_hint = rejected(&[StrategyDefinition::new("FOCUSED".into(), " ".into())]);
+        assert!(no_hint.contains("needs a hint"), "{no_hint}");
+
+        let zero_budget = rejected(&[StrategyDefinition {
+            busy_workers: Some(0),
+            allow_assignments: Some(true),
+            scheduler: Some(true),
+            ..definition("FOCUSED")
+        }]);
+        assert!(zero_budget.contains("busy_workers"), "{zero_budget}");
+
+        let unknown_tool = rejected(&[StrategyDefinition {
+            tools: Some(vec!["tasks_list".into(), "rm_rf".into()]),
+            ..definition("FOCUSED")
+        }]);
+        assert!(
+            unknown_tool.contains("unknown tool 'rm_rf'"),
+            "{unknown_tool}"
+        );
+    }
+
+    #[test]
+    fn config_definitions_count_as_defined_alongside_presets() {
+        let definitions = [definition("NIGHT_SHIFT")];
+
+        assert!(is_defined("night_shift", &definitions));
+        assert!(is_defined(" moderate ", &[]));
+        assert!(!is_defined("NIGHT_SHIFT", &[]));
     }
 }
@@ -0,0 +1,328 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Read, Write},
+    path::PathBuf,
+    sync::LazyLock,
+};
+
+use parking_lot::Mutex;
+use portable_pty::{ChildKiller, CommandBuilder, MasterPty, PtySize, native_pty_system};
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+use uuid::Uuid;
+
+use super::{
+    codex_config::AgentKind,
+    container_limits, container_runtime,
+    exec::{self, CONTAINER_WORKSPACE},
+    runner::{self, Persona},
+};
+use crate::globals::PROJECT_DIR;
+
+/// Open terminals and queue runs per worker, behind one lock so a terminal and a
+/// run are never admitted on the same worker at the same time.
+#[derive(Default)]
+struct Occupancy {
+    terminals: HashMap<i64, usize>,
+    runs: HashSet<i64>,
+}
+
+static OCCUPANCY: LazyLock<Mutex<Occupancy>> = LazyLock::new(|| Mutex::new(Occupancy::default()));
+
+#[derive(Debug, Error)]
+pub enum TerminalError {
+    #[error("terminals need the docker or podman runtime")]
+    Unavailable,
+    #[error("worktree ws{0} does not exist")]
+    MissingWorktree(i64),
+    #[error("ws{0} has an active run; pass force=true to open anyway")]
+    RunActive(i64),
+    #[error("pty error: {0}")]
+    Pty(String),
+}
+
+/// Interactive shell in an ephemeral container of a worker's image.
+pub struct Terminal {
+    master: Box<dyn MasterPty + Send>,
+    input: mpsc::Sender<Vec<u8>>,
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    program: String,
+    container: String,
+    _claim: SessionClaim,
+}
+
+/// Marks `wsN` as having an open terminal until dropped. Taken by [`claim`] before
+/// the WebSocket upgrade and handed to [`open`].
+pub struct SessionClaim {
+    worker_id: i64,
+    forced: bool,
+}
+
+impl SessionClaim {
+    fn register(occupancy: &mut Occupancy, worker_id: i64, forced: bool) -> Self {
+        *occupancy.terminals.entry(worker_id).or_default() += 1;
+        Self { worker_id, forced }
+    }
+
+    /// Whether a run was active on the worker when the terminal was admitted.
+    pub fn forced(&self) -> bool {
+        self.forced
+    }
+}
+
+impl Drop for SessionClaim {
+    fn drop(&mut self) {
+        let mut occupancy = OCCUPANCY.lock();
+        if let Some(count) = occupancy.terminals.get_mut(&self.worker_id) {
+            *count -= 1;
+            if *count == 0 {
+                occupancy.terminals.remove(&self.worker_id);
+            }
+        }
+    }
+}
+
+/// Admit a terminal on `wsN`. Refused while a queue run is active there unless
+/// `force`; the check and the claim happen under the lock [`begin_run`] takes.
+pub fn claim(worker_id: i64, force: bool) -> Result<SessionClaim, TerminalError> {
+    let mut occupancy = OCCUPANCY.lock();
+    let forced = occupancy.runs.contains(&worker_id);
+    if forced && !force {
+        return Err(TerminalError::RunActive(worker_id));
+    }
+    check(worker_id)?;
+    Ok(SessionClaim::register(&mut occupancy, worker_id, forced))
+}
+
+/// Whether a terminal is open on `wsN`.
+pub fn session_open(worker_id: i64) -> bool {
+    OCCUPANCY.lock().terminals.contains_key(&worker_id)
+}
+
+/// Record that the queue is starting a run on `wsN`. Returns false while a terminal
+/// is open there: the queue holds dispatch until every session on it has closed,
+/// so a run never starts underneath a user's shell.
+pub fn begin_run(worker_id: i64) -> bool {
+    let mut occupancy = OCCUPANCY.lock();
+    if occupancy.terminals.contains_key(&worker_id) {
+        return false;
+    }
+    occupancy.runs.insert(worker_id);
+    true
+}
+
+/// The run started by [`begin_run`] finished or never launched.
+pub fn end_run(worker_id: i64) {
+    OCCUPANCY.lock().runs.remove(&worker_id);
+}
+
+/// Streams of a [`Terminal`]: raw PTY output, then the shell's exit code.
+pub struct TerminalOutput {
+    pub output: mpsc::Receiver<Vec<u8>>,
+    pub exit: oneshot::Receiver<u32>,
+}
+
+/// Whether a terminal could be opened for `wsN` right now.
+fn check(worker_id: i64) -> Result<PathBuf, TerminalError> {
+    let worktree = PathBuf::from(PROJECT_DIR.as_str()).join(format!("ws{worker_id}"));
+    if !worktree.is_dir() {
+        return Err(TerminalError::MissingWorktree(worker_id));
+    }
+    if !container_runtime::current().uses_images() {
+        return Err(TerminalError::Unavailable);
+    }
+    Ok(worktree)
+}
+
+/// Start `bash -l` in the claimed worker's image with only its worktree mounted at
+/// `/workspace`, plus its git directory read-only as exec does.
+pub fn open(
+    claim: SessionClaim,
+    cols: u16,
+    rows: u16,
+) -> Result<(Terminal, TerminalOutput), TerminalError> {
+    let worker_id = claim.worker_id;
+    let worktree = check(worker_id)?;
+    let persona = Persona::Worker(worker_id);
+    let container = format!("robot-farm-term-ws{worker_id}-{}", Uuid::new_v4().simple());
+    let builder = container_runtime::current()
+        .run_builder(&runner::image_for(persona))
+        .ok_or(TerminalError::Unavailable)?
+        .name(&container)
+        .remove_container(true)
+        .interactive(true)
+        .tty(true)
+        .user("1000:1000")
+        .workdir(CONTAINER_WORKSPACE)
+        .volume(&worktree, CONTAINER_WORKSPACE, Some("rw".into()));
+    let argv = exec::git_dir_mounts(&worktree)
+        .into_iter()
+        .fold(builder, |builder, (host, container)| {
+            builder.volume(host, container, Some("ro".into()))
+        })
+        .env("HOME", "/home/codex")
+        .env("TERM", "xterm-256color")
+        .limits(container_limits::limits_for(
+            AgentKind::Worker,
+            Some(worker_id),
+        ))
+        .command(["bash", "-l"])
+        .build();
+
+    let pair = native_pty_system()
+        .openpty(size(cols, rows))
+        .map_err(|err| TerminalError::Pty(err.to_string()))?;
+    let mut command = CommandBuilder::new(&argv[0]);
+    command.args(&argv[1..]);
+    command.cwd(PROJECT_DIR.as_str());
+    let mut child = pair
+        .slave
+        .spawn_command(command)
+        .map_err(|err| TerminalError::Pty(err.to_string()))?;
+    drop(pair.slave);
+
+    let mut reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|err| TerminalError::Pty(err.to_string()))?;
+    let mut writer = pair
+        .master
+        .take_writer()
+        .map_err(|err| TerminalError::Pty(err.to_string()))?;
+    let killer = child.clone_killer();
+
+    let (output_tx, output) = mpsc::channel(64);
+    tokio::task::spawn_blocking(move || {
+        let mut buf = [0u8; 4096];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(read) => {
+                    if output_tx.blocking_send(buf[..read].to_vec()).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+
+    let (input, mut input_rx) = mpsc::channel::<Vec<u8>>(64);
+    tokio::task::spawn_blocking(move || {
+        while let Some(bytes) = input_rx.blocking_recv() {
+            if writer
+                .write_all(&bytes)
+                .and_then(|_| writer.flush())
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    let (exit_tx, exit) = oneshot::channel();
+    tokio::task::spawn_blocking(move || {
+        let code = child.wait().map(|status| status.exit_code()).unwrap_or(1);
+        let _ = exit_tx.send(code);
+    });
+
+    Ok((
+        Terminal {
+            master: pair.master,
+            input,
+            killer,
+            program: argv[0].clone(),
+            container,
+            _claim: claim,
+        },
+        TerminalOutput { output, exit },
+    ))
+}
+
+impl Terminal {
+    /// Returns false once the shell has stopped reading input.
+    pub async fn write(&self, bytes: Vec<u8>) -> bool {
+        self.input.send(bytes).await.is_ok()
+    }
+
+    pub fn resize(&self, cols: u16, rows: u16) -> Result<(), TerminalError> {
+        self.master
+            .resize(size(cols, rows))
+            .map_err(|err| TerminalError::Pty(err.to_string()))
+    }
+
+    /// Kill the shell and remove its container.
+    pub async fn close(mut self) {
+        let _ = self.killer.kill();
+        // The container is usually gone already; this covers a client killed mid-session.
+        exec::remove_container(&self.program, &self.container).await;
+    }
+}
+
+fn size(cols: u16, rows: u16) -> PtySize {
+    PtySize {
+        rows: rows.max(1),
+        cols: cols.max(1),
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register(worker_id: i64) -> SessionClaim {
+        SessionClaim::register(&mut OCCUPANCY.lock(), worker_id, false)
+    }
+
+    #[test]
+    fn claims_hold_the_worker_until_the_last_one_drops() {
+        let worker_id = 9_001;
+        assert!(!session_open(worker_id));
+
+        let first = register(worker_id);
+        let second = register(worker_id);
+        assert!(session_open(worker_id));
+        assert!(
+            !begin_run(worker_id),
+            "no run starts under an open terminal"
+        );
+
+        drop(first);
+        assert!(session_open(worker_id));
+        drop(second);
+        assert!(!session_open(worker_id));
+        assert!(begin_run(worker_id));
+        end_run(worker_id);
+    }
+
+    #[test]
+    fn active_runs_refuse_terminals_unless_forced() {
+        let worker_id = 9_003;
+        assert!(begin_run(worker_id));
+        assert!(matches!(
+            claim(worker_id, false),
+            Err(TerminalError::RunActive(9_003))
+        ));
+        // Forcing gets past the run to the worktree check.
+        assert!(matches!(
+            claim(worker_id, true),
+            Err(TerminalError::MissingWorktree(9_003))
+        ));
+
+        end_run(worker_id);
+        assert!(matches!(
+            claim(worker_id, false),
+            Err(TerminalError::MissingWorktree(9_003))
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_worktrees() {
+        assert!(matches!(
+            claim(9_002, false),
+            Err(TerminalError::MissingWorktree(9_002))
+        ));
+        assert!(!session_open(9_002));
+    }
+}
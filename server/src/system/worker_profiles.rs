@@ -0,0 +1,156 @@
+use std::{collections::HashSet, path::PathBuf, sync::LazyLock};
+
+use openapi::models::WorkerProfile;
+use parking_lot::RwLock;
+
+use crate::{
+    docker::DOCKER_IMAGE_WORKER, routes::config::CONFIG_DIR, shared::docker::validate_limits,
+    system::container_limits::worker_id_from_key,
+};
+
+static PROFILES: LazyLock<RwLock<Vec<WorkerProfile>>> = LazyLock::new(|| RwLock::new(Vec::new()));
+
+pub fn reset() {
+    replace(Vec::new());
+}
+
+pub fn replace(profiles: Vec<WorkerProfile>) {
+    *PROFILES.write() = profiles;
+}
+
+pub fn snapshot() -> Vec<WorkerProfile> {
+    PROFILES.read().clone()
+}
+
+/// Profile assigned to `wsN`, if any.
+pub fn profile_for(worker_id: i64) -> Option<WorkerProfile> {
+    PROFILES
+        .read()
+        .iter()
+        .find(|profile| {
+            profile
+                .workers
+                .iter()
+                .any(|key| worker_id_from_key(key) == Some(worker_id))
+        })
+        .cloned()
+}
+
+/// Image workers on `profile` run in.
+pub fn image_for(profile: &WorkerProfile) -> String {
+    profile
+        .image
+        .clone()
+        .unwrap_or_else(|| format!("{}-{}", DOCKER_IMAGE_WORKER.as_str(), profile.name))
+}
+
+/// Dockerfile fragment folded into the profile's image.
+pub fn dockerfile_for(profile: &WorkerProfile) -> PathBuf {
+    PathBuf::from(CONFIG_DIR.as_str()).join(profile.dockerfile.as_deref().unwrap_or("Dockerfile"))
+}
+
+pub fn validate(profiles: &[WorkerProfile]) -> Result<(), String> {
+    let mut names = HashSet::new();
+    let mut assigned = HashSet::new();
+    for profile in profiles {
+        let name = profile.name.as_str();
+        let valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_'));
+        if !valid_name {
+            return Err(format!(
+                "profile name '{name}' must use lowercase letters, digits, '-' or '_'"
+            ));
+        }
+        if !names.insert(name) {
+            return Err(format!("profile '{name}' is defined more than once"));
+        }
+        if let Some(image) = profile
+            .image
+            .as_deref()
+            .filter(|image| image.is_empty() || image.chars().any(char::is_whitespace))
+        {
+            return Err(format!("{name}: image '{image}' is not a valid tag"));
+        }
+        if profile
+            .model
+            .as_deref()
+            .is_some_and(|model| model.trim().is_empty())
+        {
+            return Err(format!("{name}: model must not be empty"));
+        }
+        if profile.dockerfile.is_some() && !dockerfile_for(profile).is_file() {
+            return Err(format!(
+                "{name}: Dockerfile fragment {} not found",
+                dockerfile_for(profile).display()
+            ));
+        }
+        if let Some(limits) = profile.limits.as_deref() {
+            validate_limits(limits).map_err(|msg| format!("{name}: {msg}"))?;
+        }
+        for key in &profile.workers {
+            let Some(id) = worker_id_from_key(key) else {
+                return Err(format!(
+                    "{name}: '{key}' is not a worktree name such as ws1"
+                ));
+            };
+            if !assigned.insert(id) {
+                return Err(format!("{key} is assigned to more than one profile"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str, workers: &[&str]) -> WorkerProfile {
+        WorkerProfile::new(
+            name.to_string(),
+            workers.iter().map(|key| key.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn accepts_distinct_profiles() {
+        let profiles = [profile("gpu", &["ws1", "ws2"]), profile("docs", &["ws3"])];
+        assert_eq!(validate(&profiles), Ok(()));
+    }
+
+    #[test]
+    fn rejects_duplicate_names() {
+        let profiles = [profile("gpu", &["ws1"]), profile("gpu", &["ws2"])];
+        let err = validate(&profiles).unwrap_err();
+        assert!(err.contains("defined more than once"), "{err}");
+    }
+
+    #[test]
+    fn rejects_a_worker_in_two_profiles() {
+        let profiles = [profile("gpu", &["ws1"]), profile("docs", &["ws2", "ws1"])];
+        let err = validate(&profiles).unwrap_err();
+        assert!(
+            err.contains("ws1 is assigned to more than one profile"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn rejects_a_missing_dockerfile_fragment() {
+        let profiles = [WorkerProfile {
+            dockerfile: Some("Dockerfile.missing-profile-fragment".to_string()),
+            ..profile("gpu", &["ws1"])
+        }];
+        let err = validate(&profiles).unwrap_err();
+        assert!(err.contains("Dockerfile fragment"), "{err}");
+        assert!(err.contains("not found"), "{err}");
+    }
+
+    #[test]
+    fn rejects_keys_that_are_not_worktrees() {
+        let err = validate(&[profile("gpu", &["worker-1"])]).unwrap_err();
+        assert!(err.contains("not a worktree name"), "{err}");
+    }
+}
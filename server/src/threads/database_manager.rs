@@ -1,5 +1,7 @@
 use crate::db::feed::{self, NewFeedEntry};
-use crate::db::message_queue::{self, MessageFilters, MessageQueueError, RelativePosition};
+use crate::db::message_queue::{
+    self, MessageFilters, MessageOptions, MessageQueueError, RelativePosition,
+};
 use openapi::models::{Feed, Message};
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, info};
@@ -87,16 +89,73 @@ impl DatabaseManagerHandle {
         from_actor: String,
         to_actor: String,
         body: String,
+    ) -> Result<Message, DatabaseManagerError> {
+        self.enqueue_message_with(from_actor, to_actor, body, MessageOptions::default())
+            .await
+    }
+
+    pub async fn enqueue_message_with(
+        &self,
+        from_actor: String,
+        to_actor: String,
+        body: String,
+        options: MessageOptions,
     ) -> Result<Message, DatabaseManagerError> {
         self.request(|respond_to| DatabaseManagerCommand::CreateMessage {
             from_actor,
             to_actor,
             body,
+            options,
             respond_to,
         })
         .await
     }
 
+    pub async fn hold_message(
+        &self,
+        message_id: i64,
+        reason: String,
+    ) -> Result<Option<Message>, DatabaseManagerError> {
+        self.request(|respond_to| DatabaseManagerCommand::HoldMessage {
+            message_id,
+            reason,
+            respond_to,
+        })
+        .await
+    }
+
+    pub async fn approve_message(
+        &self,
+        message_id: i64,
+        body: Option<String>,
+    ) -> Result<Message, DatabaseManagerError> {
+        self.request(|respond_to| DatabaseManagerCommand::ApproveMessage {
+            message_id,
+            body,
+            respond_to,
+        })
+        .await
+    }
+
+    pub async fn take_held_message(
+        &self,
+        message_id: i64,
+    ) -> Result<Message, DatabaseManagerError> {
+        self.request(|respond_to| DatabaseManagerCommand::TakeHeldMessage {
+            message_id,
+            respond_to,
+        })
+        .await
+    }
+
+    pub async fn purge_expired_messages(
+        &self,
+        now: i64,
+    ) -> Result<Vec<Message>, DatabaseManagerError> {
+        self.request(|respond_to| DatabaseManagerCommand::PurgeExpiredMessages { now, respond_to })
+            .await
+    }
+
     async fn request<T>(
         &self,
         build: impl FnOnce(oneshot::Sender<Result<T, DatabaseManagerError>>) -> DatabaseManagerCommand,
@@ -146,6 +205,25 @@ enum DatabaseManagerCommand {
         from_actor: String,
         to_actor: String,
         body: String,
+        options: MessageOptions,
+        respond_to: oneshot::Sender<Result<Message, DatabaseManagerError>>,
+    },
+    PurgeExpiredMessages {
+        now: i64,
+        respond_to: oneshot::Sender<Result<Vec<Message>, DatabaseManagerError>>,
+    },
+    HoldMessage {
+        message_id: i64,
+        reason: String,
+        respond_to: oneshot::Sender<Result<Option<Message>, DatabaseManagerError>>,
+    },
+    ApproveMessage {
+        message_id: i64,
+        body: Option<String>,
+        respond_to: oneshot::Sender<Result<Message, DatabaseManagerError>>,
+    },
+    TakeHeldMessage {
+        message_id: i64,
         respond_to: oneshot::Sender<Result<Message, DatabaseManagerError>>,
     },
 }
@@ -223,10 +301,50 @@ async fn run_manager(mut rx: mpsc::Receiver<DatabaseManagerCommand>) {
                 from_actor,
                 to_actor,
                 body,
+                options,
                 respond_to,
             } => {
                 info!(from = %from_actor, to = %to_actor, "database manager: enqueue_message");
-                let result = message_queue::enqueue_message(&from_actor, &to_actor, &body)
+                let result =
+                    message_queue::enqueue_message_with(&from_actor, &to_actor, &body, options)
+                        .await
+                        .map_err(DatabaseManagerError::from);
+                let _ = respond_to.send(result);
+            }
+            DatabaseManagerCommand::PurgeExpiredMessages { now, respond_to } => {
+                let result = message_queue::purge_expired(now)
+                    .await
+                    .map_err(DatabaseManagerError::from);
+                let _ = respond_to.send(result);
+            }
+            DatabaseManagerCommand::HoldMessage {
+                message_id,
+                reason,
+                respond_to,
+            } => {
+                info!(message_id, %reason, "database manager: hold_message");
+                let result = message_queue::hold_message(message_id, &reason)
+                    .await
+                    .map_err(DatabaseManagerError::from);
+                let _ = respond_to.send(result);
+            }
+            DatabaseManagerCommand::ApproveMessage {
+                message_id,
+                body,
+                respond_to,
+            } => {
+                info!(message_id, "database manager: approve_message");
+                let result = message_queue::approve_message(message_id, body.as_deref())
+                    .await
+                    .map_err(DatabaseManagerError::from);
+                let _ = respond_to.send(result);
+            }
+            DatabaseManagerCommand::TakeHeldMessage {
+                message_id,
+                respond_to,
+            } => {
+                info!(message_id, "database manager: take_held_message");
+                let result = message_queue::take_held_message(message_id)
                     .await
                     .map_err(DatabaseManagerError::from);
                 let _ = respond_to.send(result);
@@ -52,6 +52,8 @@ fn spawn_threads() -> ThreadHandles {
         middleware_handle.clone(),
         notification_rx,
     );
+    crate::system::feed_archive::spawn_sweeper();
+    crate::system::run_recordings::spawn_pruner();
 
     ThreadHandles {
         middleware: middleware_handle,
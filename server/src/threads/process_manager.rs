@@ -1,12 +1,17 @@
 use crate::ai::schemas::{OrchestratorTurn, WorkerTurn};
-use crate::models::codex_events::{CodexEvent, TurnItemDetail};
+use crate::models::agent_items::AgentItem;
 use crate::models::process::{
     KillReason, KillSignal, ProcessDirective, ProcessEvent, ProcessExit, ProcessHandle,
     ProcessKillDirective, ProcessKillHandle, ProcessKilled, ProcessLaunchDirective,
     ProcessLifecycleEvent, ProcessOutputChunk, ProcessOutputError, ProcessRequest,
     ProcessSpawnError, ProcessStream, RunId, RunMetadata,
 };
+use crate::system::{
+    agent_backend::{self, AgentBackend},
+    run_recordings::{self, RecordingExit, RunRecording},
+};
 use chrono::Utc;
+use openapi::models::FeedItemKind;
 use std::collections::HashMap;
 use std::process::Stdio;
 use std::sync::Arc;
@@ -51,11 +56,13 @@ pub enum ProcessNotification {
         turn: OrchestratorTurn,
     },
     AgentFeed {
+        run_id: RunId,
         actor: AgentRunActor,
         message: String,
         raw: String,
         thread_id: Option<String>,
         category: Option<String>,
+        item: AgentFeedItem,
     },
     AgentCompleted {
         run_id: RunId,
@@ -63,6 +70,23 @@ pub enum ProcessNotification {
     },
 }
 
+/// Typed facts about the agent item behind a feed entry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentFeedItem {
+    pub kind: Option<FeedItemKind>,
+    pub exit_code: Option<i32>,
+    pub changed_files: Vec<String>,
+}
+
+impl AgentFeedItem {
+    fn of_kind(kind: FeedItemKind) -> Self {
+        Self {
+            kind: Some(kind),
+            ..Self::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AgentRunActor {
     Worker(i64),
@@ -91,6 +115,7 @@ struct OrchestratorObserver {
 
 #[derive(Clone)]
 struct AgentFeedContext {
+    run_id: RunId,
     actor: AgentRunActor,
     notifications_tx: mpsc::Sender<ProcessNotification>,
 }
@@ -263,10 +288,15 @@ async fn run_child(
     let stdin_payload = request.stdin.clone();
     let mut command = build_command(&request);
     let actor = detect_agent_actor(&request.metadata);
-    let collector =
-        actor.map(|actor_kind| Arc::new(Mutex::new(StructuredOutputCollector::new(actor_kind))));
+    let collector = actor.map(|actor_kind| {
+        Arc::new(Mutex::new(StructuredOutputCollector::new(
+            actor_kind,
+            agent_backend::for_run(request.metadata.backend.as_deref()),
+        )))
+    });
 
     let feed_ctx = actor.map(|actor_kind| AgentFeedContext {
+        run_id,
         actor: actor_kind,
         notifications_tx: notifications_tx.clone(),
     });
@@ -293,6 +323,20 @@ async fn run_child(
                     let tx = events_tx.clone();
                     let collector = collector.clone();
                     let feed_ctx = feed_ctx.clone();
+                    let recording = match actor {
+                        Some(_) => {
+                            match RunRecording::create(&request.metadata, request.stdin.as_deref())
+                                .await
+                            {
+                                Ok(recording) => Some(recording),
+                                Err(err) => {
+                                    warn!(%run_id, ?err, "failed to open run recording");
+                                    None
+                                }
+                            }
+                        }
+                        None => None,
+                    };
                     tokio::spawn(forward_output(
                         stdout,
                         tx,
@@ -300,6 +344,7 @@ async fn run_child(
                         ProcessStream::Stdout,
                         collector,
                         feed_ctx,
+                        recording,
                     ));
                 }
             }
@@ -314,6 +359,7 @@ async fn run_child(
                         ProcessStream::Stderr,
                         None,
                         None,
+                        None,
                     ));
                 }
             }
@@ -426,6 +472,7 @@ async fn observe_child(
     notifications_tx: mpsc::Sender<ProcessNotification>,
 ) {
     let mut kill_reason: Option<KillReason> = None;
+    let mut recorded_exit: Option<RecordingExit> = None;
 
     loop {
         tokio::select! {
@@ -438,6 +485,13 @@ async fn observe_child(
             status = child.wait() => {
                 match status {
                     Ok(exit) => {
+                        let ended = if kill_reason.is_some() { "killed" } else { "exited" };
+                        recorded_exit = Some(RecordingExit {
+                            reason: ended.to_string(),
+                            code: exit.code(),
+                            detail: kill_reason.as_ref().map(|reason| format!("{reason:?}")),
+                            finished_at: Utc::now(),
+                        });
                         if let Some(reason) = kill_reason {
                             let finished_at = Utc::now();
                             let _ = events_tx
@@ -465,6 +519,12 @@ async fn observe_child(
                     }
                     Err(err) => {
                         error!(%run_id, error = ?err, "failed to await child exit");
+                        recorded_exit = Some(RecordingExit {
+                            reason: "failed".to_string(),
+                            code: None,
+                            detail: Some(err.to_string()),
+                            finished_at: Utc::now(),
+                        });
                         let _ = events_tx
                             .send(ProcessEvent::SpawnFailed(ProcessSpawnError {
                                 run_id,
@@ -482,6 +542,15 @@ async fn observe_child(
         }
     }
 
+    // Only agent runs have a recording to annotate.
+    let exit_recorded = match (recorded_exit, agent_ctx.as_ref()) {
+        (Some(exit), Some(_)) => run_recordings::record_exit(run_id, exit).await,
+        _ => Ok(()),
+    };
+    if let Err(err) = exit_recorded {
+        warn!(%run_id, ?err, "failed to record run exit");
+    }
+
     if let Some(observer) = agent_ctx {
         match observer {
             AgentObserver::Worker(observer) => {
@@ -540,17 +609,70 @@ async fn observe_child(
     }
 }
 
+/// Rebuild the feed entries and structured turn a recorded run produced, parsing it
+/// with the backend named in `metadata`.
+/// Thread ids are dropped so a replay never overwrites a live session, and no
+/// completion is emitted because no process is running.
+pub fn replay_recording(
+    metadata: &RunMetadata,
+    recording: &[u8],
+) -> Option<Vec<ProcessNotification>> {
+    let actor = detect_agent_actor(metadata)?;
+    let run_id = metadata.run_id;
+    let mut collector =
+        StructuredOutputCollector::new(actor, agent_backend::for_run(metadata.backend.as_deref()));
+    let mut notifications: Vec<ProcessNotification> = collector
+        .ingest(recording)
+        .into_iter()
+        .filter(|fragment| !fragment.text.trim().is_empty())
+        .map(|fragment| ProcessNotification::AgentFeed {
+            run_id,
+            actor,
+            message: fragment.text,
+            raw: fragment.raw,
+            thread_id: None,
+            category: fragment.category,
+            item: fragment.item,
+        })
+        .collect();
+
+    match actor {
+        AgentRunActor::Worker(worker_id) => {
+            if let Some(turn) = collector.take_worker_turn() {
+                notifications.push(ProcessNotification::WorkerTurn {
+                    run_id,
+                    worker_id,
+                    metadata: metadata.clone(),
+                    turn,
+                });
+            }
+        }
+        AgentRunActor::Orchestrator => {
+            if let Some(turn) = collector.take_orchestrator_turn() {
+                notifications.push(ProcessNotification::OrchestratorTurn {
+                    run_id,
+                    metadata: metadata.clone(),
+                    turn,
+                });
+            }
+        }
+    }
+    Some(notifications)
+}
+
 struct StructuredOutputCollector {
     actor: AgentRunActor,
+    backend: Arc<dyn AgentBackend>,
     buffer: Vec<u8>,
     worker_turn: Option<WorkerTurn>,
     orchestrator_turn: Option<OrchestratorTurn>,
 }
 
 impl StructuredOutputCollector {
-    fn new(actor: AgentRunActor) -> Self {
+    fn new(actor: AgentRunActor, backend: Arc<dyn AgentBackend>) -> Self {
         Self {
             actor,
+            backend,
             buffer: Vec::new(),
             worker_turn: None,
             orchestrator_turn: None,
@@ -571,9 +693,7 @@ impl StructuredOutputCollector {
             if text.is_empty() {
                 continue;
             }
-            if let Some(fragment) = self.process_line(&text) {
-                fragments.push(fragment);
-            }
+            fragments.extend(self.process_line(&text));
         }
         fragments
     }
@@ -606,85 +726,68 @@ impl StructuredOutputCollector {
         self.orchestrator_turn.take()
     }
 
-    fn process_line(&mut self, line: &str) -> Option<AgentFeedFragment> {
-        let event: CodexEvent = match serde_json::from_str(line) {
-            Ok(event) => event,
-            Err(_) => return None,
-        };
-        match event {
-            CodexEvent::ItemCompleted { item } => self.fragment_for_detail(item.detail, line),
-            CodexEvent::ThreadStarted { thread_id } => Some(AgentFeedFragment {
-                text: format!("Thread started: {thread_id}"),
-                raw: line.to_string(),
-                thread_id: Some(thread_id),
-                category: Some("thread".to_string()),
-            }),
-            _ => None,
-        }
+    fn process_line(&mut self, line: &str) -> Vec<AgentFeedFragment> {
+        let items = self.backend.parse_line(line);
+        items
+            .into_iter()
+            .filter_map(|item| self.fragment_for_item(item, line))
+            .collect()
     }
 
-    fn fragment_for_detail(
-        &mut self,
-        detail: TurnItemDetail,
-        raw: &str,
-    ) -> Option<AgentFeedFragment> {
-        match detail {
-            TurnItemDetail::AgentMessage { text } => {
-                let mut summary: Option<String> = None;
-                let mut category: Option<String> = None;
-                match self.actor {
-                    AgentRunActor::Worker(_) => {
-                        if let Ok(turn) = serde_json::from_str::<WorkerTurn>(&text) {
-                            summary = Some(turn.summary.clone());
-                            category = Some("worker_turn".to_string());
-                            self.worker_turn = Some(turn);
-                        }
-                    }
-                    AgentRunActor::Orchestrator => {
-                        if let Ok(turn) = serde_json::from_str::<OrchestratorTurn>(&text) {
-                            summary = Some(turn.summary.clone());
-                            category = Some("orchestrator_turn".to_string());
-                            self.orchestrator_turn = Some(turn);
-                        }
-                    }
-                }
-                let display_text = summary
-                    .map(|value| value.trim().to_string())
-                    .filter(|value| !value.is_empty())
-                    .unwrap_or_else(|| "Turn completed.".to_string());
-                Some(AgentFeedFragment {
-                    text: display_text,
-                    raw: raw.to_string(),
-                    thread_id: None,
-                    category,
-                })
-            }
-            TurnItemDetail::Reasoning { text } => Some(AgentFeedFragment {
-                text: format!("Reasoning:\n{text}"),
+    fn fragment_for_item(&mut self, item: AgentItem, raw: &str) -> Option<AgentFeedFragment> {
+        if let Some(payload) = self.backend.structured_output(&item) {
+            let payload = payload.to_string();
+            return Some(self.structured_fragment(&payload, raw));
+        }
+        let fragment =
+            |text: String, category: Option<&str>, item: AgentFeedItem| AgentFeedFragment {
+                text,
                 raw: raw.to_string(),
                 thread_id: None,
-                category: Some("reasoning".to_string()),
+                category: category.map(str::to_string),
+                item,
+            };
+        match item {
+            AgentItem::SessionStarted { session_id } => Some(AgentFeedFragment {
+                text: format!("Thread started: {session_id}"),
+                raw: raw.to_string(),
+                thread_id: Some(session_id),
+                category: Some("thread".to_string()),
+                item: AgentFeedItem::of_kind(FeedItemKind::Session),
             }),
-            TurnItemDetail::CommandExecution(cmd) => {
-                let mut summary = format!("Command `{}` {:?}", cmd.command, cmd.status);
-                if !cmd.aggregated_output.trim().is_empty() {
-                    summary.push_str("\n");
-                    summary.push_str(cmd.aggregated_output.trim());
+            AgentItem::Message { text } => Some(fragment(
+                text,
+                None,
+                AgentFeedItem::of_kind(FeedItemKind::Message),
+            )),
+            AgentItem::Reasoning { text } => Some(fragment(
+                format!("Reasoning:\n{text}"),
+                Some("reasoning"),
+                AgentFeedItem::of_kind(FeedItemKind::Reasoning),
+            )),
+            AgentItem::Command {
+                command,
+                output,
+                exit_code,
+                status,
+            } => {
+                let mut summary = format!("Command `{command}` {status:?}");
+                if !output.trim().is_empty() {
+                    summary.push('\n');
+                    summary.push_str(output.trim());
                 }
-                Some(AgentFeedFragment {
-                    text: summary,
-                    raw: raw.to_string(),
-                    thread_id: None,
-                    category: None,
-                })
+                let item = AgentFeedItem {
+                    exit_code,
+                    ..AgentFeedItem::of_kind(FeedItemKind::Command)
+                };
+                Some(fragment(summary, None, item))
             }
-            TurnItemDetail::ItemError { message } => Some(AgentFeedFragment {
-                text: format!("Error: {message}"),
-                raw: raw.to_string(),
-                thread_id: None,
-                category: None,
-            }),
-            TurnItemDetail::TodoList { items } => {
+            AgentItem::Error { message } => Some(fragment(
+                format!("Error: {message}"),
+                None,
+                AgentFeedItem::of_kind(FeedItemKind::Error),
+            )),
+            AgentItem::TodoList { items } => {
                 let list = items
                     .into_iter()
                     .map(|item| {
@@ -696,42 +799,72 @@ impl StructuredOutputCollector {
                     })
                     .collect::<Vec<_>>()
                     .join("\n");
-                Some(AgentFeedFragment {
-                    text: format!("TODO List:\n{list}"),
-                    raw: raw.to_string(),
-                    thread_id: None,
-                    category: None,
-                })
+                Some(fragment(
+                    format!("TODO List:\n{list}"),
+                    None,
+                    AgentFeedItem::of_kind(FeedItemKind::TodoList),
+                ))
             }
-            TurnItemDetail::FileChange(file_change) => {
-                let files = file_change
-                    .changes
-                    .into_iter()
+            AgentItem::FileChanges { changes, .. } => {
+                let files = changes
+                    .iter()
                     .map(|entry| format!("{:?}: {}", entry.kind, entry.path))
                     .collect::<Vec<_>>()
                     .join("\n");
-                Some(AgentFeedFragment {
-                    text: format!("File changes:\n{files}"),
-                    raw: raw.to_string(),
-                    thread_id: None,
-                    category: None,
-                })
+                let item = AgentFeedItem {
+                    changed_files: changes.into_iter().map(|entry| entry.path).collect(),
+                    ..AgentFeedItem::of_kind(FeedItemKind::FileChange)
+                };
+                Some(fragment(format!("File changes:\n{files}"), None, item))
+            }
+            AgentItem::ToolCall {
+                server,
+                tool,
+                status,
+            } => Some(fragment(
+                format!("MCP tool call {server}::{tool}, status {status:?}"),
+                None,
+                AgentFeedItem::of_kind(FeedItemKind::McpToolCall),
+            )),
+            AgentItem::WebSearch { query } => Some(fragment(
+                format!("Web search: {query}"),
+                None,
+                AgentFeedItem::of_kind(FeedItemKind::WebSearch),
+            )),
+            AgentItem::Usage { .. } | AgentItem::TurnFailed { .. } => None,
+        }
+    }
+
+    /// Capture the structured turn payload and summarize it for the feed.
+    fn structured_fragment(&mut self, payload: &str, raw: &str) -> AgentFeedFragment {
+        let mut summary: Option<String> = None;
+        let mut category: Option<String> = None;
+        match self.actor {
+            AgentRunActor::Worker(_) => {
+                if let Ok(turn) = serde_json::from_str::<WorkerTurn>(payload) {
+                    summary = Some(turn.summary.clone());
+                    category = Some("worker_turn".to_string());
+                    self.worker_turn = Some(turn);
+                }
+            }
+            AgentRunActor::Orchestrator => {
+                if let Ok(turn) = serde_json::from_str::<OrchestratorTurn>(payload) {
+                    summary = Some(turn.summary.clone());
+                    category = Some("orchestrator_turn".to_string());
+                    self.orchestrator_turn = Some(turn);
+                }
             }
-            TurnItemDetail::McpToolCall(call) => Some(AgentFeedFragment {
-                text: format!(
-                    "MCP tool call {}::{}, status {:?}",
-                    call.server, call.tool, call.status
-                ),
-                raw: raw.to_string(),
-                thread_id: None,
-                category: None,
-            }),
-            TurnItemDetail::WebSearch { query } => Some(AgentFeedFragment {
-                text: format!("Web search: {query}"),
-                raw: raw.to_string(),
-                thread_id: None,
-                category: None,
-            }),
+        }
+        let display_text = summary
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "Turn completed.".to_string());
+        AgentFeedFragment {
+            text: display_text,
+            raw: raw.to_string(),
+            thread_id: None,
+            category,
+            item: AgentFeedItem::of_kind(FeedItemKind::Turn),
         }
     }
 }
@@ -741,6 +874,7 @@ struct AgentFeedFragment {
     raw: String,
     thread_id: Option<String>,
     category: Option<String>,
+    item: AgentFeedItem,
 }
 
 async fn forward_output<R>(
@@ -750,6 +884,7 @@ async fn forward_output<R>(
     stream: ProcessStream,
     collector: Option<Arc<Mutex<StructuredOutputCollector>>>,
     feed_ctx: Option<AgentFeedContext>,
+    mut recording: Option<RunRecording>,
 ) where
     R: AsyncRead + Unpin + Send + 'static,
 {
@@ -768,6 +903,15 @@ async fn forward_output<R>(
                     captured_at: Utc::now(),
                 };
 
+                let record_error = match recording.as_mut() {
+                    Some(file) => file.append(&chunk.bytes).await.err(),
+                    None => None,
+                };
+                if let Some(err) = record_error {
+                    warn!(%run_id, ?err, "failed to write run recording; recording stopped");
+                    recording = None;
+                }
+
                 if events_tx
                     .send(ProcessEvent::Output(chunk.clone()))
                     .await
@@ -790,11 +934,13 @@ async fn forward_output<R>(
                                 if ctx
                                     .notifications_tx
                                     .send(ProcessNotification::AgentFeed {
+                                        run_id: ctx.run_id,
                                         actor: ctx.actor,
                                         message: fragment.text,
                                         raw: fragment.raw,
                                         thread_id: fragment.thread_id,
                                         category: fragment.category.clone(),
+                                        item: fragment.item,
                                     })
                                     .await
                                     .is_err()
@@ -3,41 +3,54 @@ use crate::ai::schemas::{
 };
 use crate::db;
 use crate::db::assignments;
-use crate::db::feed::NewFeedEntry;
-use crate::db::message_queue::{MessageFilters, RelativePosition};
+use crate::db::feed::{FeedEntryMeta, NewFeedEntry};
+use crate::db::message_queue::{MessageFilters, MessageOptions, RelativePosition};
 use crate::db::task as task_db;
 use crate::db::task_group;
+use crate::db::task_history::TaskChangeOrigin;
 use crate::globals::PROJECT_DIR;
+use crate::mcp::Agent;
 use crate::mcp::project_commands::ProjectCommandRegistry;
 use crate::models::process::{
     KillReason, ProcessEvent, ProcessHandle, ProcessIntent, ProcessSpawnIntent, ProcessStream,
     RunId, RunMetadata, RunPriority,
 };
-use crate::models::strategy::OrchestratorHint;
+use crate::models::strategy::{OrchestratorHint, Strategy};
 use crate::post_turn_checks::PostTurnCheckRegistry;
 use crate::realtime::{self, RealtimeEvent};
 use crate::shared::git::MergeConflict;
 use crate::shared::{git, shell};
 use crate::system::{
     events::{SystemActor, SystemEvent},
-    features,
+    features, feed_archive,
+    launcher::{self, LaunchRequest},
+    mcp_tokens, qa_inbox,
     queue::{QueueCoordinator, QueueError},
-    runner::{self, Persona, RunnerConfig},
-    staging_hooks,
-    strategy::StrategyState,
+    review,
+    runner::{Persona, RunnerConfig},
+    schedule,
+    scheduler::{self, SchedulerCandidates},
+    skills, staging_hooks,
+    strategy::{self, StrategyState},
+    terminal,
 };
 use crate::threads::database_manager::{DatabaseManagerError, DatabaseManagerHandle};
 use crate::threads::middleware::MiddlewareHandle;
-use crate::threads::process_manager::{AgentRunActor, ProcessNotification};
-use chrono::Utc;
+use crate::threads::process_manager::{self, AgentFeedItem, AgentRunActor, ProcessNotification};
+use chrono::{Local, Utc};
 use openapi::models::{
-    CommandConfig, Feed, FeedLevel, Message, Strategy as ApiStrategy, TaskUpdateInput,
+    ActiveStrategy, CommandConfig, Feed, FeedLevel, Message, MessageReviewStatus, RunReplay,
+    ScheduleAction, Task, TaskUpdateInput,
 };
 use serde_json;
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
     path::{Path, PathBuf},
-    time::Duration,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
 };
 use thiserror::Error;
 use tokio::{
@@ -76,6 +89,7 @@ pub enum QueueManagerCommand {
         from: SystemActor,
         to: SystemActor,
         body: String,
+        options: MessageOptions,
         respond_to: oneshot::Sender<Result<Message, QueueManagerError>>,
     },
     Pause {
@@ -84,6 +98,27 @@ pub enum QueueManagerCommand {
     Resume {
         respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
     },
+    Drain {
+        respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
+    },
+    ApproveMessage {
+        message_id: i64,
+        body: Option<String>,
+        respond_to: oneshot::Sender<Result<Message, QueueManagerError>>,
+    },
+    RejectMessage {
+        message_id: i64,
+        reply: String,
+        respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
+    },
+    PauseWorker {
+        worker_id: i64,
+        respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
+    },
+    ResumeWorker {
+        worker_id: i64,
+        respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
+    },
     EnqueueProcessIntent {
         intent: ProcessIntent,
         respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
@@ -95,6 +130,11 @@ pub enum QueueManagerCommand {
     KillOrchestrator {
         respond_to: oneshot::Sender<Result<(), QueueManagerError>>,
     },
+    ReplayRun {
+        run_id: RunId,
+        apply: bool,
+        respond_to: oneshot::Sender<Result<RunReplay, QueueManagerError>>,
+    },
 }
 
 /// Public handle that other components use to talk to the queue manager.
@@ -159,11 +199,13 @@ impl QueueManagerHandle {
         from: SystemActor,
         to: SystemActor,
         body: String,
+        options: MessageOptions,
     ) -> Result<Message, QueueManagerError> {
         self.request(|respond_to| QueueManagerCommand::EnqueueMessage {
             from,
             to,
             body,
+            options,
             respond_to,
         })
         .await
@@ -179,6 +221,57 @@ impl QueueManagerHandle {
             .await
     }
 
+    /// Finish in-flight runs and post-turn jobs without dispatching anything
+    /// new, then pause.
+    pub async fn drain(&self) -> Result<(), QueueManagerError> {
+        self.request(|respond_to| QueueManagerCommand::Drain { respond_to })
+            .await
+    }
+
+    /// Release a message held for Quality Assurance review.
+    pub async fn approve_message(
+        &self,
+        message_id: i64,
+        body: Option<String>,
+    ) -> Result<Message, QueueManagerError> {
+        self.request(|respond_to| QueueManagerCommand::ApproveMessage {
+            message_id,
+            body,
+            respond_to,
+        })
+        .await
+    }
+
+    /// Drop a held message and send `reply` to its sender.
+    pub async fn reject_message(
+        &self,
+        message_id: i64,
+        reply: String,
+    ) -> Result<(), QueueManagerError> {
+        self.request(|respond_to| QueueManagerCommand::RejectMessage {
+            message_id,
+            reply,
+            respond_to,
+        })
+        .await
+    }
+
+    pub async fn pause_worker(&self, worker_id: i64) -> Result<(), QueueManagerError> {
+        self.request(|respond_to| QueueManagerCommand::PauseWorker {
+            worker_id,
+            respond_to,
+        })
+        .await
+    }
+
+    pub async fn resume_worker(&self, worker_id: i64) -> Result<(), QueueManagerError> {
+        self.request(|respond_to| QueueManagerCommand::ResumeWorker {
+            worker_id,
+            respond_to,
+        })
+        .await
+    }
+
     pub async fn enqueue_process_intent(
         &self,
         intent: ProcessIntent,
@@ -200,6 +293,22 @@ impl QueueManagerHandle {
             .await
     }
 
+    /// Re-run turn processing for a recorded agent run.
+    /// Parse a run recording. Its feed entries and turn only go through live turn
+    /// processing when `apply` is set; otherwise this is a dry run.
+    pub async fn replay_run(
+        &self,
+        run_id: RunId,
+        apply: bool,
+    ) -> Result<RunReplay, QueueManagerError> {
+        self.request(|respond_to| QueueManagerCommand::ReplayRun {
+            run_id,
+            apply,
+            respond_to,
+        })
+        .await
+    }
+
     async fn request<T>(
         &self,
         build: impl FnOnce(oneshot::Sender<Result<T, QueueManagerError>>) -> QueueManagerCommand,
@@ -327,19 +436,26 @@ impl QueueManagerRuntime {
                 from,
                 to,
                 body,
+                options,
                 respond_to,
             } => {
                 info!(from = %from.label(), to = %to.label(), "queue command: enqueue_manual_message");
-                let result = self.enqueue_message(from, to, &body).await;
+                let result = self
+                    .db
+                    .enqueue_message_with(from.label(), to.label(), body, options)
+                    .await
+                    .map_err(QueueManagerError::from);
                 let _ = respond_to.send(result);
             }
             QueueManagerCommand::Pause { respond_to } => {
                 self.state.paused = true;
+                self.state.draining = false;
                 info!("queue manager paused");
                 let _ = respond_to.send(Ok(()));
             }
             QueueManagerCommand::Resume { respond_to } => {
                 self.state.paused = false;
+                self.state.draining = false;
                 info!("queue manager resumed");
                 if let Err(err) = self.flush_pending().await {
                     let _ = respond_to.send(Err(err));
@@ -347,6 +463,60 @@ impl QueueManagerRuntime {
                     let _ = respond_to.send(Ok(()));
                 }
             }
+            QueueManagerCommand::Drain { respond_to } => {
+                if !self.state.paused {
+                    self.state.draining = true;
+                    info!("queue manager draining");
+                }
+                let _ = respond_to.send(Ok(()));
+            }
+            QueueManagerCommand::ApproveMessage {
+                message_id,
+                body,
+                respond_to,
+            } => {
+                let edited = body.is_some();
+                info!(message_id, edited, "queue command: approve_message");
+                let result = self
+                    .db
+                    .approve_message(message_id, body)
+                    .await
+                    .map_err(QueueManagerError::from);
+                if let Ok(message) = &result {
+                    let verdict = if edited {
+                        "edited and approved"
+                    } else {
+                        "approved"
+                    };
+                    QueueCoordinator::global().message_reviewed(message, verdict, None);
+                }
+                let _ = respond_to.send(result);
+            }
+            QueueManagerCommand::RejectMessage {
+                message_id,
+                reply,
+                respond_to,
+            } => {
+                info!(message_id, "queue command: reject_message");
+                let result = self.reject_held_message(message_id, &reply).await;
+                let _ = respond_to.send(result);
+            }
+            QueueManagerCommand::PauseWorker {
+                worker_id,
+                respond_to,
+            } => {
+                self.state.paused_workers.insert(worker_id);
+                info!(worker_id, "worker dispatch paused");
+                let _ = respond_to.send(Ok(()));
+            }
+            QueueManagerCommand::ResumeWorker {
+                worker_id,
+                respond_to,
+            } => {
+                self.state.paused_workers.remove(&worker_id);
+                info!(worker_id, "worker dispatch resumed");
+                let _ = respond_to.send(Ok(()));
+            }
             QueueManagerCommand::EnqueueProcessIntent { intent, respond_to } => {
                 info!("queue command: enqueue_process_intent");
                 let result = self.enqueue_intent(intent).await;
@@ -365,6 +535,15 @@ impl QueueManagerRuntime {
                 let result = self.kill_orchestrator_process().await;
                 let _ = respond_to.send(result);
             }
+            QueueManagerCommand::ReplayRun {
+                run_id,
+                apply,
+                respond_to,
+            } => {
+                info!(%run_id, apply, "queue command: replay_run");
+                let result = self.replay_run(run_id, apply).await;
+                let _ = respond_to.send(result);
+            }
         }
         Ok(())
     }
@@ -380,7 +559,7 @@ impl QueueManagerRuntime {
                 warn!(?err, worker_id, "ghost commit failed for worker turn");
                 let note = format!("Ghost commit failed: {err}");
                 let _ = self
-                    .record_worker_feed(worker_id, &note, "", Some("system"))
+                    .record_worker_feed(worker_id, &note, "", Some("system"), None)
                     .await;
             }
         }
@@ -443,11 +622,13 @@ impl QueueManagerRuntime {
                 self.process_orchestrator_turn(metadata, turn).await?;
             }
             ProcessNotification::AgentFeed {
+                run_id,
                 actor,
                 message,
                 raw,
                 thread_id,
                 category,
+                item,
             } => match actor {
                 AgentRunActor::Worker(worker_id) => {
                     if let Some(thread_id) = thread_id.clone() {
@@ -462,8 +643,14 @@ impl QueueManagerRuntime {
                         }
                     }
                     self.capture_reasoning(worker_id, &message, category.as_deref());
-                    self.record_worker_feed(worker_id, &message, &raw, category.as_deref())
-                        .await?;
+                    self.record_worker_feed(
+                        worker_id,
+                        &message,
+                        &raw,
+                        category.as_deref(),
+                        Some((run_id, item)),
+                    )
+                    .await?;
                 }
                 AgentRunActor::Orchestrator => {
                     if let Some(thread_id) = thread_id {
@@ -476,27 +663,86 @@ impl QueueManagerRuntime {
                             }
                         }
                     }
-                    self.record_orchestrator_feed(&message, &raw, category.as_deref())
-                        .await?;
+                    self.record_orchestrator_feed(
+                        &message,
+                        &raw,
+                        category.as_deref(),
+                        run_id,
+                        item,
+                    )
+                    .await?;
                 }
             },
-            ProcessNotification::AgentCompleted { actor, run_id } => match actor {
-                AgentRunActor::Worker(worker_id) => {
-                    self.state.active_workers.remove(&worker_id);
-                    self.state.worker_runs.remove(&worker_id);
-                    self.state.worker_reasoning.remove(&worker_id);
-                    QueueCoordinator::global().clear_assignment(worker_id);
-                    debug!(%run_id, worker_id, "worker run completed");
-                }
-                AgentRunActor::Orchestrator => {
-                    self.state.orchestrator_run = None;
-                    debug!(%run_id, "orchestrator run completed");
+            ProcessNotification::AgentCompleted { actor, run_id } => {
+                mcp_tokens::revoke_run(run_id);
+                self.state.run_context.remove(&run_id);
+                match actor {
+                    AgentRunActor::Worker(worker_id) => {
+                        self.state.active_workers.remove(&worker_id);
+                        self.state.worker_runs.remove(&worker_id);
+                        self.state.worker_reasoning.remove(&worker_id);
+                        terminal::end_run(worker_id);
+                        QueueCoordinator::global().clear_assignment(worker_id);
+                        debug!(%run_id, worker_id, "worker run completed");
+                    }
+                    AgentRunActor::Orchestrator => {
+                        self.state.orchestrator_run = None;
+                        debug!(%run_id, "orchestrator run completed");
+                    }
                 }
-            },
+            }
         }
         Ok(())
     }
 
+    async fn replay_run(
+        &mut self,
+        run_id: RunId,
+        apply: bool,
+    ) -> Result<RunReplay, QueueManagerError> {
+        let (meta, recording) =
+            run_recordings::load(run_id)
+                .await
+                .map_err(|err| match err.kind() {
+                    std::io::ErrorKind::NotFound => QueueManagerError::RecordingNotFound(run_id),
+                    _ => QueueManagerError::RecordingUnreadable(err.to_string()),
+                })?;
+        let metadata = meta.to_run_metadata();
+        let notifications =
+            process_manager::replay_recording(&metadata, &recording).ok_or_else(|| {
+                QueueManagerError::Replay(format!("run {run_id} is not an agent run"))
+            })?;
+
+        let mut replay = RunReplay::new(run_id.to_string(), String::new(), 0, apply);
+        for notification in notifications {
+            match &notification {
+                ProcessNotification::AgentFeed { actor, .. } => {
+                    replay.agent = agent_label(*actor);
+                    replay.feed_entries += 1;
+                }
+                ProcessNotification::WorkerTurn {
+                    worker_id, turn, ..
+                } => {
+                    replay.agent = agent_label(AgentRunActor::Worker(*worker_id));
+                    replay.turn_intent = intent_label(&turn.intent);
+                }
+                ProcessNotification::OrchestratorTurn { turn, .. } => {
+                    replay.agent = agent_label(AgentRunActor::Orchestrator);
+                    replay.turn_intent = intent_label(&turn.intent);
+                }
+                ProcessNotification::AgentCompleted { .. } => {}
+            }
+            if apply {
+                self.handle_notification(notification).await?;
+            }
+        }
+        if replay.agent.is_empty() {
+            replay.agent = meta.persona;
+        }
+        info!(%run_id, agent = %replay.agent, feed_entries = replay.feed_entries, apply, "replayed run recording");
+        Ok(replay)
+    }
+
     async fn process_worker_turn(
         &mut self,
         worker_id: i64,
@@ -546,7 +792,7 @@ impl QueueManagerRuntime {
             }
             WorkerIntent::StatusUpdate => {
                 if let Some(mut message) = Self::format_status_update(worker_id, &turn) {
-                    if let Some(hints) = Self::render_support_hints(worker_id) {
+                    if let Some(hints) = Self::render_support_hints(worker_id).await {
                         message.push_str("\n\n");
                         message.push_str(&hints);
                     }
@@ -645,13 +891,18 @@ impl QueueManagerRuntime {
             .map_err(|err| QueueManagerError::Assignment(err.to_string()))?
             .ok_or_else(|| QueueManagerError::Assignment(format!("task {slug} not found")))?;
 
+        if !self.check_assignment_skills(worker_id, &task).await? {
+            return Ok(());
+        }
+
         let assignment_message = Self::format_assignment_message(turn, assignment);
 
         if task.owner.trim().eq_ignore_ascii_case("orchestrator") {
             let mut update = TaskUpdateInput::new();
             let worker_label = format!("ws{worker_id}");
             update.owner = Some(worker_label.clone());
-            let updated = task_db::update_task(task.id, update)
+            let origin = TaskChangeOrigin::system("Orchestrator");
+            let updated = task_db::update_task(task.id, update, &origin)
                 .await
                 .map_err(|err| QueueManagerError::Assignment(err.to_string()))?
                 .ok_or_else(|| {
@@ -694,6 +945,38 @@ impl QueueManagerRuntime {
         Ok(())
     }
 
+    /// Enforce `skill_mismatch` for an orchestrator assignment. Returns false when the
+    /// assignment was rejected and the orchestrator told to pick another worker.
+    async fn check_assignment_skills(
+        &self,
+        worker_id: i64,
+        task: &Task,
+    ) -> Result<bool, QueueManagerError> {
+        let required = skills::required_for(task)
+            .await
+            .map_err(|err| QueueManagerError::Assignment(err.to_string()))?;
+        let missing = skills::missing(&required, &skills::provided_by(worker_id));
+        if missing.is_empty() {
+            return Ok(true);
+        }
+        let rejected = !skills::assignment_allowed(&missing, skills::mismatch_policy());
+        QueueCoordinator::global().skill_mismatch(worker_id, &task.slug, &missing, rejected);
+        if !rejected {
+            warn!(worker_id, task = %task.slug, ?missing, "assigned task to worker lacking skills");
+            return Ok(true);
+        }
+        warn!(worker_id, task = %task.slug, ?missing, "rejected assignment to worker lacking skills");
+        let body = format!(
+            "Assignment of {slug} to ws{worker_id} was rejected: ws{worker_id} lacks required skill(s) {missing}. Idle workers: {idle}. Assign {slug} to a worker that provides every required skill.",
+            slug = task.slug,
+            missing = missing.join(", "),
+            idle = skills::describe(&QueueCoordinator::global().idle_workers()),
+        );
+        self.enqueue_message(SystemActor::System, SystemActor::Orchestrator, &body)
+            .await?;
+        Ok(false)
+    }
+
     async fn handle_orchestrator_status(
         &self,
         turn: &OrchestratorTurn,
@@ -702,25 +985,22 @@ impl QueueManagerRuntime {
             warn!("orchestrator status update missing target");
             return Ok(());
         };
-        let Some(worker_id) = Self::parse_worker_target(target) else {
-            warn!(target, "orchestrator status update target invalid");
-            return Ok(());
+        let recipient = match SystemActor::from_label(target) {
+            Some(SystemActor::QualityAssurance) => SystemActor::QualityAssurance,
+            _ => match Self::parse_worker_target(target) {
+                Some(worker_id) => SystemActor::Worker(worker_id),
+                None => {
+                    warn!(target, "orchestrator status update target invalid");
+                    return Ok(());
+                }
+            },
         };
 
         let message = Self::format_orchestrator_status(turn);
-        self.enqueue_message(
-            SystemActor::Orchestrator,
-            SystemActor::Worker(worker_id),
-            &message,
-        )
-        .await?;
-        self.record_message_feed(
-            &SystemActor::Orchestrator,
-            &SystemActor::Worker(worker_id),
-            &message,
-            "routing",
-        )
-        .await?;
+        self.enqueue_message(SystemActor::Orchestrator, recipient, &message)
+            .await?;
+        self.record_message_feed(&SystemActor::Orchestrator, &recipient, &message, "routing")
+            .await?;
         Ok(())
     }
 
@@ -736,6 +1016,7 @@ impl QueueManagerRuntime {
             self.middleware.clone(),
             self.db.clone(),
             drift_trace,
+            self.state.post_turn_jobs.clone(),
         );
         Ok(())
     }
@@ -757,15 +1038,24 @@ impl QueueManagerRuntime {
         message: &str,
         raw: &str,
         category: Option<&str>,
+        run: Option<(RunId, AgentFeedItem)>,
     ) -> Result<(), QueueManagerError> {
         let feed_category = category.unwrap_or("worker");
+        let run_id = run.as_ref().map(|(run_id, _)| *run_id);
+        let (raw, raw_blob) = feed_archive::offload_raw(run_id, raw).await;
+        let meta = match run {
+            Some((run_id, item)) => self.feed_meta(run_id, item),
+            None => FeedEntryMeta::default(),
+        };
         let entry = NewFeedEntry {
             source: format!("ws{worker_id}"),
             target: format!("ws{worker_id}"),
             level: FeedLevel::Info,
             text: message.to_string(),
-            raw: raw.to_string(),
+            raw,
             category: feed_category.to_string(),
+            raw_blob,
+            meta,
         };
         let feed_entry = self
             .db
@@ -790,6 +1080,8 @@ impl QueueManagerRuntime {
             text: body.to_string(),
             raw: String::new(),
             category: category.to_string(),
+            raw_blob: None,
+            meta: FeedEntryMeta::default(),
         };
         let feed_entry = self
             .db
@@ -805,15 +1097,21 @@ impl QueueManagerRuntime {
         message: &str,
         raw: &str,
         category: Option<&str>,
+        run_id: RunId,
+        item: AgentFeedItem,
     ) -> Result<(), QueueManagerError> {
         let feed_category = category.unwrap_or("orchestrator");
+        let (raw, raw_blob) = feed_archive::offload_raw(Some(run_id), raw).await;
+        let meta = self.feed_meta(run_id, item);
         let entry = NewFeedEntry {
             source: "Orchestrator".to_string(),
             target: "Orchestrator".to_string(),
             level: FeedLevel::Info,
             text: message.to_string(),
-            raw: raw.to_string(),
+            raw,
             category: feed_category.to_string(),
+            raw_blob,
+            meta,
         };
         let feed_entry = self
             .db
@@ -824,6 +1122,41 @@ impl QueueManagerRuntime {
         Ok(())
     }
 
+    /// Metadata for an agent-output entry. Task and turn come from the context
+    /// captured at dispatch, so replayed runs only carry the run id and item facts.
+    fn feed_meta(&self, run_id: RunId, item: AgentFeedItem) -> FeedEntryMeta {
+        let context = self.state.run_context.get(&run_id);
+        FeedEntryMeta {
+            run_id: Some(run_id.to_string()),
+            task_slug: context.and_then(|context| context.task_slug.clone()),
+            turn_index: context.map(|context| context.turn_index),
+            item_kind: item.kind,
+            exit_code: item.exit_code,
+            changed_files: item.changed_files,
+        }
+    }
+
+    /// Remember which task `run_id` works on and claim its turn index for `source`.
+    async fn capture_run_context(
+        &mut self,
+        run_id: RunId,
+        source: &str,
+        task_slug: Option<String>,
+    ) {
+        match db::feed::next_turn_index(source, task_slug.as_deref()).await {
+            Ok(turn_index) => {
+                self.state.run_context.insert(
+                    run_id,
+                    RunContext {
+                        task_slug,
+                        turn_index,
+                    },
+                );
+            }
+            Err(err) => warn!(?err, %run_id, "failed to claim a turn index for feed metadata"),
+        }
+    }
+
     async fn enqueue_message(
         &self,
         from: SystemActor,
@@ -838,34 +1171,49 @@ impl QueueManagerRuntime {
     }
 
     async fn drive_queue(&mut self) -> Result<(), QueueManagerError> {
-        if self.state.paused {
+        if self.state.paused || self.state.draining {
+            // Filing in the QA inbox starts no run, so it goes on while paused.
+            self.deliver_pending_qa().await;
+            if self.state.draining {
+                self.check_drained();
+            }
             return Ok(());
         }
 
-        let queue = self
+        let now = Utc::now().timestamp();
+        self.expire_messages(now).await;
+
+        let queue: Vec<Message> = self
             .db
             .list_messages(MessageFilters::default())
             .await
-            .map_err(QueueManagerError::from)?;
+            .map_err(QueueManagerError::from)?
+            .into_iter()
+            .filter(|entry| entry.deliver_after.is_none_or(|at| at <= now))
+            .collect();
+        let queue = self.apply_review_holds(queue).await;
+
+        if let Err(err) = self.run_scheduler(&queue).await {
+            warn!(?err, "deterministic scheduler pass failed");
+            // Retry on the next tick instead of waiting for a change.
+            self.state.scheduler_inputs = None;
+        }
 
-        for entry in queue {
+        for batch in Self::delivery_batches(queue, features::coalesce_messages()) {
+            let entry = Self::coalesce_batch(&batch);
             match SystemActor::from_label(&entry.to) {
                 Some(SystemActor::Worker(worker_id)) => {
-                    if self.state.active_workers.contains(&worker_id) {
+                    if self.state.active_workers.contains(&worker_id)
+                        || self.state.paused_workers.contains(&worker_id)
+                        || !terminal::begin_run(worker_id)
+                    {
                         continue;
                     }
                     match self.dispatch_worker_message(worker_id, &entry).await {
-                        Ok(true) => {
-                            if let Err(err) = self.db.delete_message_by_id(entry.id).await {
-                                warn!(
-                                    ?err,
-                                    message_id = entry.id,
-                                    "failed to delete message after dispatch"
-                                );
-                            }
-                        }
-                        Ok(false) => continue,
+                        Ok(true) => self.delete_delivered(&batch).await,
+                        Ok(false) => terminal::end_run(worker_id),
                         Err(err) => {
+                            terminal::end_run(worker_id);
                             warn!(
                                 ?err,
                                 worker_id,
@@ -875,20 +1223,13 @@ impl QueueManagerRuntime {
                         }
                     }
                 }
+                Some(SystemActor::QualityAssurance) => self.deliver_to_qa(&batch).await,
                 Some(SystemActor::Orchestrator) => {
                     if self.state.orchestrator_run.is_some() {
                         continue;
                     }
                     match self.dispatch_orchestrator_message(&entry).await {
-                        Ok(true) => {
-                            if let Err(err) = self.db.delete_message_by_id(entry.id).await {
-                                warn!(
-                                    ?err,
-                                    message_id = entry.id,
-                                    "failed to delete orchestrator message after dispatch"
-                                );
-                            }
-                        }
+                        Ok(true) => self.delete_delivered(&batch).await,
                         Ok(false) => continue,
                         Err(err) => {
                             warn!(
@@ -906,6 +1247,469 @@ impl QueueManagerRuntime {
         Ok(())
     }
 
+    /// Hold messages matching the review rules and return the ones that may be
+    /// delivered. Held messages wait for Quality Assurance.
+    async fn apply_review_holds(&self, queue: Vec<Message>) -> Vec<Message> {
+        let mode = review::snapshot();
+        let enabled = review::is_enabled(&mode);
+        let active = StrategyState::global().snapshot();
+        let mut deliverable = Vec::with_capacity(queue.len());
+        for entry in queue {
+            match entry.review_status {
+                Some(MessageReviewStatus::Held) => continue,
+                Some(MessageReviewStatus::Approved) => {
+                    deliverable.push(entry);
+                    continue;
+                }
+                None if !enabled => {
+                    deliverable.push(entry);
+                    continue;
+                }
+                None => {}
+            }
+            let from = SystemActor::from_label(&entry.from);
+            let to = SystemActor::from_label(&entry.to);
+            let group = if review::watches_groups(&mode) {
+                Self::message_group_slug(from, to).await
+            } else {
+                None
+            };
+            let Some(reason) = review::hold_reason(&mode, from, to, &active.id, group.as_deref())
+            else {
+                deliverable.push(entry);
+                continue;
+            };
+            match self.db.hold_message(entry.id, reason.clone()).await {
+                Ok(Some(held)) => {
+                    info!(message_id = held.id, %reason, "queue message held for review");
+                    QueueCoordinator::global().message_held(&held, &reason);
+                }
+                Ok(None) => {}
+                Err(err) => warn!(?err, message_id = entry.id, "failed to hold queue message"),
+            }
+        }
+        deliverable
+    }
+
+    /// Task group of the assignment held by the worker on either end of a message.
+    async fn message_group_slug(
+        from: Option<SystemActor>,
+        to: Option<SystemActor>,
+    ) -> Option<String> {
+        let worker_id = match (to, from) {
+            (Some(SystemActor::Worker(id)), _) | (_, Some(SystemActor::Worker(id))) => id,
+            _ => return None,
+        };
+        let assigned = QueueCoordinator::global().assigned_task(worker_id)?;
+        let task = task_db::get_task(assigned.task_id).await.ok().flatten()?;
+        let group = task_group::get_task_group(task.group_id)
+            .await
+            .ok()
+            .flatten()?;
+        Some(group.slug)
+    }
+
+    async fn reject_held_message(
+        &self,
+        message_id: i64,
+        reply: &str,
+    ) -> Result<(), QueueManagerError> {
+        let message = self
+            .db
+            .take_held_message(message_id)
+            .await
+            .map_err(QueueManagerError::from)?;
+        QueueCoordinator::global().message_reviewed(&message, "rejected", Some(reply));
+        let sender = SystemActor::from_label(&message.from);
+        if let Some(sender @ (SystemActor::Orchestrator | SystemActor::Worker(_))) = sender {
+            let body = format!(
+                "Quality Assurance rejected your message to {} (#{}): {}\n\nOriginal message:\n{}",
+                message.to,
+                message.id,
+                reply.trim(),
+                message.message.trim()
+            );
+            self.enqueue_message(SystemActor::QualityAssurance, sender, &body)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn expire_messages(&self, now: i64) {
+        match self.db.purge_expired_messages(now).await {
+            Ok(expired) => {
+                let coordinator = QueueCoordinator::global();
+                for message in expired {
+                    info!(message_id = message.id, to = %message.to, "queue message expired");
+                    coordinator.message_expired(&message);
+                }
+            }
+            Err(err) => warn!(?err, "failed to purge expired queue messages"),
+        }
+    }
+
+    async fn deliver_pending_qa(&self) {
+        let filters = MessageFilters {
+            to: Some(SystemActor::QualityAssurance.label()),
+            ..MessageFilters::default()
+        };
+        let now = Utc::now().timestamp();
+        match self.db.list_messages(filters).await {
+            Ok(messages) => {
+                let due: Vec<Message> = messages
+                    .into_iter()
+                    .filter(|entry| entry.deliver_after.is_none_or(|at| at <= now))
+                    .collect();
+                if !due.is_empty() {
+                    self.deliver_to_qa(&due).await;
+                }
+            }
+            Err(err) => warn!(?err, "failed to list QA messages"),
+        }
+    }
+
+    /// Quality Assurance is a person, not an agent run: file its messages in
+    /// the inbox instead of dispatching them.
+    async fn deliver_to_qa(&self, batch: &[Message]) {
+        let mut filed = Vec::with_capacity(batch.len());
+        for message in batch {
+            match qa_inbox::receive(message).await {
+                Ok(item) => {
+                    info!(
+                        message_id = message.id,
+                        item_id = item.id,
+                        "filed message in QA inbox"
+                    );
+                    filed.push(message.clone());
+                }
+                Err(err) => warn!(?err, message_id = message.id, "failed to file QA message"),
+            }
+        }
+        if !filed.is_empty() {
+            self.delete_delivered(&filed).await;
+            qa_inbox::broadcast().await;
+        }
+    }
+
+    async fn delete_delivered(&self, batch: &[Message]) {
+        for message in batch {
+            if let Err(err) = self.db.delete_message_by_id(message.id).await {
+                warn!(
+                    ?err,
+                    message_id = message.id,
+                    "failed to delete message after dispatch"
+                );
+            }
+        }
+    }
+
+    /// Group due messages by recipient in queue order. With coalescing off,
+    /// each recipient gets only its first message per pass, as before.
+    fn delivery_batches(queue: Vec<Message>, coalesce: bool) -> Vec<Vec<Message>> {
+        let mut batches: Vec<Vec<Message>> = Vec::new();
+        for entry in queue {
+            match batches
+                .iter_mut()
+                .find(|batch| batch[0].to.eq_ignore_ascii_case(&entry.to))
+            {
+                Some(batch) if coalesce => batch.push(entry),
+                Some(_) => {}
+                None => batches.push(vec![entry]),
+            }
+        }
+        batches
+    }
+
+    /// Fold a batch into a single message so the recipient handles it in one run.
+    fn coalesce_batch(batch: &[Message]) -> Message {
+        let mut merged = batch[0].clone();
+        if batch.len() == 1 {
+            return merged;
+        }
+        let mut senders: Vec<&str> = Vec::new();
+        for message in batch {
+            let from = message.from.trim();
+            if !senders.contains(&from) {
+                senders.push(from);
+            }
+        }
+        merged.from = senders.join(", ");
+        let sections = batch
+            .iter()
+            .map(|message| {
+                format!(
+                    "[#{} from {}]\n{}",
+                    message.id,
+                    message.from.trim(),
+                    message.message.trim()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        merged.message = format!(
+            "{} queued messages delivered together; address each one.\n\n{sections}",
+            batch.len()
+        );
+        merged
+    }
+
+    /// Pause once no agent run or post-turn job is left from before the drain.
+    fn check_drained(&mut self) {
+        let in_flight = self.state.active_workers.len()
+            + usize::from(self.state.orchestrator_run.is_some())
+            + self.state.post_turn_jobs.load(Ordering::SeqCst);
+        if in_flight > 0 {
+            return;
+        }
+        self.state.draining = false;
+        self.state.paused = true;
+        info!("queue drained; pausing");
+        QueueCoordinator::global().finish_drain();
+    }
+
+    /// Assign ready tasks straight to idle workers when the deterministic
+    /// scheduler is enabled for the active strategy. Anything it cannot
+    /// decide on its own is handed to the orchestrator.
+    async fn run_scheduler(&mut self, queue: &[Message]) -> Result<(), QueueManagerError> {
+        if !features::deterministic_scheduler() {
+            return Ok(());
+        }
+        let active = StrategyState::global().snapshot();
+        let definition = strategy::resolve(&active.id);
+        let Some(budget) = scheduler::worker_budget(&definition) else {
+            return Ok(());
+        };
+        // BLOCKED reports, status updates and user messages go through the
+        // orchestrator first; wait for them to settle before assigning.
+        let orchestrator_pending = queue.iter().any(|entry| {
+            matches!(
+                SystemActor::from_label(&entry.to),
+                Some(SystemActor::Orchestrator)
+            )
+        });
+        if self.state.orchestrator_run.is_some() || orchestrator_pending {
+            return Ok(());
+        }
+
+        // The pass below reads workers and tasks from the database; skip it
+        // while nothing it depends on has moved since the last one.
+        let coordinator = QueueCoordinator::global();
+        let inputs = SchedulerInputs {
+            task_revision: task_db::revision(),
+            strategy: active.clone(),
+            queued: queue.iter().map(|entry| entry.id).collect(),
+            active_workers: self.state.active_workers.iter().copied().collect(),
+            paused_workers: self.state.paused_workers.iter().copied().collect(),
+            idle_workers: coordinator.idle_workers(),
+        };
+        let unchanged = self
+            .state
+            .scheduler_inputs
+            .as_ref()
+            .is_some_and(|(last, at)| *last == inputs && at.elapsed() < SCHEDULER_RESYNC);
+        if unchanged {
+            return Ok(());
+        }
+        self.state.scheduler_inputs = Some((inputs, Instant::now()));
+
+        let workers = crate::db::worker::list_workers().await;
+        for worker in &workers {
+            coordinator.register_worker(worker.id);
+        }
+        let owned = assignments::list_active_assignments()
+            .await
+            .map_err(DatabaseManagerError::from)
+            .map_err(QueueManagerError::from)?;
+        let mut busy: HashSet<i64> = owned
+            .iter()
+            .filter_map(|assignment| Self::parse_worker_target(&assignment.worker))
+            .collect();
+        busy.extend(self.state.active_workers.iter().copied());
+        busy.extend(
+            queue
+                .iter()
+                .filter_map(|entry| match SystemActor::from_label(&entry.to) {
+                    Some(SystemActor::Worker(worker_id)) => Some(worker_id),
+                    _ => None,
+                }),
+        );
+        let idle = coordinator.idle_workers();
+        busy.extend(
+            idle.iter()
+                .copied()
+                .filter(|worker_id| terminal::session_open(*worker_id)),
+        );
+        let idle = scheduler::assignable_workers(&idle, &busy, &self.state.paused_workers);
+        let available = workers
+            .iter()
+            .filter(|worker| !self.state.paused_workers.contains(&worker.id))
+            .count();
+        let busy_count = available.saturating_sub(idle.len());
+        let slots = scheduler::open_slots(budget, busy_count, idle.len());
+        if slots == 0 {
+            return Ok(());
+        }
+
+        match self
+            .scheduler_candidates(&active, &definition, busy_count)
+            .await?
+        {
+            SchedulerCandidates::Ready(tasks) => {
+                self.state.scheduler_handoff = None;
+                let mut idle = idle;
+                let mut assigned = 0;
+                for task in tasks {
+                    if assigned == slots || idle.is_empty() {
+                        break;
+                    }
+                    let required = skills::required_for(&task)
+                        .await
+                        .map_err(DatabaseManagerError::from)
+                        .map_err(QueueManagerError::from)?;
+                    let Some(position) = skills::first_capable(&required, &idle) else {
+                        debug!(task = %task.slug, ?required, "no idle worker provides task skills");
+                        continue;
+                    };
+                    let worker_id = idle.remove(position);
+                    self.assign_scheduled_task(worker_id, task).await?;
+                    assigned += 1;
+                }
+            }
+            SchedulerCandidates::Waiting => {}
+            SchedulerCandidates::Handoff(reason) => {
+                self.scheduler_handoff(reason, &idle).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn scheduler_candidates(
+        &self,
+        active: &ActiveStrategy,
+        definition: &Strategy,
+        busy_count: usize,
+    ) -> Result<SchedulerCandidates, QueueManagerError> {
+        let focus = active.focus.clone().unwrap_or_default();
+        let mut fallback = Vec::new();
+        for slug in &definition.groups {
+            if let Some(group) = task_group::get_task_group_by_slug(slug)
+                .await
+                .map_err(DatabaseManagerError::from)
+                .map_err(QueueManagerError::from)?
+            {
+                fallback.push(group.id);
+            }
+        }
+        let mut open = HashMap::new();
+        for &group_id in focus.iter().chain(&fallback) {
+            if open.contains_key(&group_id) {
+                continue;
+            }
+            let count = task_db::count_open_in_group(group_id)
+                .await
+                .map_err(DatabaseManagerError::from)
+                .map_err(QueueManagerError::from)?;
+            open.insert(group_id, count);
+        }
+        let groups = match scheduler::candidate_groups(
+            &focus,
+            &fallback,
+            definition.sequential_groups,
+            &open,
+        ) {
+            Ok(groups) => groups,
+            Err(reason) => return Ok(SchedulerCandidates::Handoff(reason)),
+        };
+
+        let mut tasks = Vec::new();
+        for group_id in groups {
+            let ready = task_db::list_schedulable_in_group(group_id)
+                .await
+                .map_err(DatabaseManagerError::from)
+                .map_err(QueueManagerError::from)?;
+            tasks.extend(ready);
+        }
+        Ok(scheduler::rank_candidates(tasks, busy_count))
+    }
+
+    async fn assign_scheduled_task(
+        &mut self,
+        worker_id: i64,
+        task: Task,
+    ) -> Result<(), QueueManagerError> {
+        let mut update = TaskUpdateInput::new();
+        update.owner = Some(format!("ws{worker_id}"));
+        let origin = TaskChangeOrigin::system("System");
+        let updated = task_db::update_task(task.id, update, &origin)
+            .await
+            .map_err(|err| QueueManagerError::Assignment(err.to_string()))?
+            .ok_or_else(|| QueueManagerError::Assignment(format!("task {} missing", task.id)))?;
+
+        if let Err(QueueError::WorkerBusy) = QueueCoordinator::global().assign_task(
+            worker_id,
+            updated.id,
+            Some(updated.slug.clone()),
+        ) {
+            warn!(
+                worker_id,
+                task_id = updated.id,
+                "worker already has an assignment"
+            );
+        }
+
+        let message = scheduler::format_assignment(&updated);
+        let queued = self
+            .enqueue_message(
+                SystemActor::System,
+                SystemActor::Worker(worker_id),
+                &message,
+            )
+            .await?;
+        if let Some(reason) = review::scheduler_hold_reason(&review::snapshot()) {
+            match self.db.hold_message(queued.id, reason.clone()).await {
+                Ok(Some(held)) => QueueCoordinator::global().message_held(&held, &reason),
+                Ok(None) => {}
+                Err(err) => warn!(
+                    ?err,
+                    message_id = queued.id,
+                    "failed to hold scheduler assignment"
+                ),
+            }
+        }
+        self.record_message_feed(
+            &SystemActor::System,
+            &SystemActor::Worker(worker_id),
+            &message,
+            "routing",
+        )
+        .await?;
+        info!(worker_id, task = %updated.slug, "scheduler assigned task");
+        Ok(())
+    }
+
+    async fn scheduler_handoff(
+        &mut self,
+        reason: &'static str,
+        idle_workers: &[i64],
+    ) -> Result<(), QueueManagerError> {
+        // Hand off once per situation rather than on every tick.
+        if self.state.scheduler_handoff == Some(reason) {
+            return Ok(());
+        }
+        self.state.scheduler_handoff = Some(reason);
+        let message = scheduler::format_handoff(reason, idle_workers);
+        self.enqueue_message(SystemActor::System, SystemActor::Orchestrator, &message)
+            .await?;
+        self.record_message_feed(
+            &SystemActor::System,
+            &SystemActor::Orchestrator,
+            &message,
+            "routing",
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn dispatch_worker_message(
         &mut self,
         worker_id: i64,
@@ -953,20 +1757,24 @@ impl QueueManagerRuntime {
             }
         }
 
-        let plan = runner::plan_codex_run(
-            Persona::Worker(worker_id),
-            session_id.as_deref(),
-            runner_cfg,
-        );
-        let mut docker_args = plan.docker_args;
-        if docker_args.is_empty() {
-            warn!(worker_id, "docker run command missing for worker persona");
-            return Ok(false);
-        }
-        let program = docker_args.remove(0);
-        docker_args.extend(plan.codex_args);
-
         let run_id = Uuid::new_v4();
+        runner_cfg.mcp_token = Some(mcp_tokens::mint(Agent::WorkerWithId(worker_id), run_id));
+        let plan = match launcher::current().plan(LaunchRequest {
+            persona: Persona::Worker(worker_id),
+            session_id,
+            config: runner_cfg,
+        }) {
+            Ok(plan) => plan,
+            Err(err) => {
+                mcp_tokens::revoke_run(run_id);
+                warn!(
+                    ?err,
+                    worker_id, "failed to plan worker launch; skipping turn"
+                );
+                return Ok(false);
+            }
+        };
+
         let metadata = RunMetadata {
             run_id,
             persona: format!("worker:ws{worker_id}"),
@@ -974,29 +1782,37 @@ impl QueueManagerRuntime {
             tags: vec![format!("worker:{worker_id}")],
             priority: RunPriority::Normal,
             issued_at: Utc::now(),
+            backend: Some(plan.backend.to_string()),
         };
 
         let intent = ProcessSpawnIntent {
             metadata,
-            program,
-            args: docker_args,
-            env: Vec::new(),
-            working_dir: PathBuf::from(PROJECT_DIR.as_str()),
+            program: plan.program,
+            args: plan.args,
+            env: plan.env,
+            working_dir: plan
+                .working_dir
+                .unwrap_or_else(|| PathBuf::from(PROJECT_DIR.as_str())),
             stream_stdout: true,
             stream_stderr: true,
             stdin: Some(Self::format_worker_prompt(message).into_bytes()),
         };
 
-        let handle_rx = self
-            .middleware
-            .enqueue_spawn(intent)
-            .await
-            .map_err(|err| QueueManagerError::MiddlewareSend(err.to_string()))?;
-        let handle = handle_rx
-            .await
-            .map_err(|_| QueueManagerError::ResponseDropped)?;
+        let handle_rx = self.middleware.enqueue_spawn(intent).await.map_err(|err| {
+            mcp_tokens::revoke_run(run_id);
+            QueueManagerError::MiddlewareSend(err.to_string())
+        })?;
+        let handle = handle_rx.await.map_err(|_| {
+            mcp_tokens::revoke_run(run_id);
+            QueueManagerError::ResponseDropped
+        })?;
 
         self.state.worker_runs.insert(worker_id, run_id);
+        let task_slug = QueueCoordinator::global()
+            .assigned_task(worker_id)
+            .and_then(|assigned| assigned.slug);
+        self.capture_run_context(run_id, &format!("ws{worker_id}"), task_slug)
+            .await;
         self.state.worker_reasoning.insert(worker_id, Vec::new());
         Self::spawn_process_event_drain(worker_id, handle.events);
         self.state.active_workers.insert(worker_id);
@@ -1026,20 +1842,23 @@ impl QueueManagerRuntime {
             }
         };
 
-        let plan = runner::plan_codex_run(
-            Persona::Orchestrator,
-            session_id.as_deref(),
-            RunnerConfig::default(),
-        );
-        let mut docker_args = plan.docker_args;
-        if docker_args.is_empty() {
-            warn!("docker run command missing for orchestrator persona");
-            return Ok(false);
-        }
-        let program = docker_args.remove(0);
-        docker_args.extend(plan.codex_args);
-
         let run_id = Uuid::new_v4();
+        let plan = match launcher::current().plan(LaunchRequest {
+            persona: Persona::Orchestrator,
+            session_id,
+            config: RunnerConfig {
+                mcp_token: Some(mcp_tokens::mint(Agent::Orchestrator, run_id)),
+                ..RunnerConfig::default()
+            },
+        }) {
+            Ok(plan) => plan,
+            Err(err) => {
+                mcp_tokens::revoke_run(run_id);
+                warn!(?err, "failed to plan orchestrator launch; skipping turn");
+                return Ok(false);
+            }
+        };
+
         let metadata = RunMetadata {
             run_id,
             persona: "orchestrator".to_string(),
@@ -1047,29 +1866,33 @@ impl QueueManagerRuntime {
             tags: vec!["orchestrator".to_string()],
             priority: RunPriority::Normal,
             issued_at: Utc::now(),
+            backend: Some(plan.backend.to_string()),
         };
 
         let intent = ProcessSpawnIntent {
             metadata,
-            program,
-            args: docker_args,
-            env: Vec::new(),
-            working_dir: PathBuf::from(PROJECT_DIR.as_str()),
+            program: plan.program,
+            args: plan.args,
+            env: plan.env,
+            working_dir: plan
+                .working_dir
+                .unwrap_or_else(|| PathBuf::from(PROJECT_DIR.as_str())),
             stream_stdout: true,
             stream_stderr: true,
             stdin: Some(self.format_orchestrator_prompt(message).await.into_bytes()),
         };
 
-        let handle_rx = self
-            .middleware
-            .enqueue_spawn(intent)
-            .await
-            .map_err(|err| QueueManagerError::MiddlewareSend(err.to_string()))?;
-        let handle = handle_rx
-            .await
-            .map_err(|_| QueueManagerError::ResponseDropped)?;
+        let handle_rx = self.middleware.enqueue_spawn(intent).await.map_err(|err| {
+            mcp_tokens::revoke_run(run_id);
+            QueueManagerError::MiddlewareSend(err.to_string())
+        })?;
+        let handle = handle_rx.await.map_err(|_| {
+            mcp_tokens::revoke_run(run_id);
+            QueueManagerError::ResponseDropped
+        })?;
 
         self.state.orchestrator_run = Some(run_id);
+        self.capture_run_context(run_id, "Orchestrator", None).await;
         Self::spawn_agent_event_drain(AgentRunActor::Orchestrator, handle.events);
         info!(
             message_id = message.id,
@@ -1132,6 +1955,7 @@ impl QueueManagerRuntime {
                         break;
                     }
                     ProcessEvent::SpawnFailed(err) => {
+                        mcp_tokens::revoke_run(err.run_id);
                         match agent {
                             AgentRunActor::Worker(worker_id) => {
                                 error!(
@@ -1267,7 +2091,7 @@ impl QueueManagerRuntime {
 
     async fn strategy_footer(&self) -> String {
         let strategy = StrategyState::global().snapshot();
-        let strategy_label = strategy.id.to_string();
+        let strategy_label = strategy.id.clone();
         let focus = strategy
             .focus
             .clone()
@@ -1303,10 +2127,24 @@ impl QueueManagerRuntime {
             format!("[{items}]")
         };
 
-        format!(
-            "Active Strategy: {} | Focus Groups: {} | Active Assignments: {} | Idle Workers: {}/{}",
-            strategy_label, focus_str, assignments_str, idle_workers, total_workers
-        )
+        let hint = strategy::resolve(&strategy.id).render_hint(&focus_str);
+
+        let mut footer = format!(
+            "Active Strategy: {} | Focus Groups: {} | Active Assignments: {} | Idle Workers: {}/{}\nStrategy Hint: {}",
+            strategy_label, focus_str, assignments_str, idle_workers, total_workers, hint
+        );
+        if skills::any_declared() {
+            let idle: Vec<i64> = QueueCoordinator::global()
+                .idle_workers()
+                .into_iter()
+                .filter(|id| !active_workers.contains(&format!("ws{id}")))
+                .collect();
+            footer.push_str(&format!(
+                "\nIdle Worker Skills: {}\nOnly assign a task to a worker that provides every skill in the task's and its group's `required_skills`.",
+                if idle.is_empty() { "none".to_string() } else { skills::describe(&idle) }
+            ));
+        }
+        footer
     }
 
     fn format_status_update(worker_id: i64, turn: &WorkerTurn) -> Option<String> {
@@ -1332,23 +2170,26 @@ impl QueueManagerRuntime {
         Some(body)
     }
 
-    fn render_support_hints(worker_id: i64) -> Option<String> {
+    async fn render_support_hints(worker_id: i64) -> Option<String> {
         let strategy = StrategyState::global().snapshot();
+        let definition = strategy::resolve(&strategy.id);
         let mut hints = vec![OrchestratorHint::SendSupport {
             to_worker: worker_id,
         }];
-        if should_seed_assignments(&strategy.id) {
-            let focus = strategy
-                .focus
-                .clone()
-                .unwrap_or_default()
-                .into_iter()
-                .map(|group| group.to_string())
-                .collect();
-            hints.push(OrchestratorHint::AssignTask {
-                to_worker: worker_id,
-                from_groups: focus,
+        if definition.allow_assignments && !scheduler::is_active(&definition) {
+            let groups = task_group::list_task_groups().await.unwrap_or_else(|err| {
+                warn!(?err, "failed to load task groups for support hints");
+                Vec::new()
             });
+            let focus = strategy::eligible_group_slugs(&strategy, &definition, &groups);
+            let group_skills = skills::group_requirements(&groups);
+            let from_groups = skills::eligible_groups(worker_id, &focus, &group_skills);
+            if focus.is_empty() || !from_groups.is_empty() {
+                hints.push(OrchestratorHint::AssignTask {
+                    to_worker: worker_id,
+                    from_groups,
+                });
+            }
         }
         if hints.is_empty() {
             return None;
@@ -1411,6 +2252,96 @@ impl QueueManagerRuntime {
         Ok(())
     }
 
+    /// Apply time-based schedule rules whose start or window end has passed.
+    async fn apply_schedule(&mut self) -> Result<(), QueueManagerError> {
+        let now = Local::now();
+        let coordinator = QueueCoordinator::global();
+        if let Some(until) = schedule::take_expired_override(now.timestamp()) {
+            coordinator.strategy_scheduled(
+                "Schedule override expired; scheduled rules are active again".to_string(),
+                serde_json::json!({ "override_until": until }),
+            );
+        }
+
+        for transition in schedule::due_transitions(now) {
+            let label = transition.label();
+            let rule = &transition.rule;
+            let pause = match (rule.action, transition.ends_window) {
+                (ScheduleAction::Pause, false) | (ScheduleAction::Resume, true) => Some(true),
+                (ScheduleAction::Resume, false) | (ScheduleAction::Pause, true) => Some(false),
+                (ScheduleAction::SetStrategy, _) => None,
+            };
+            let summary = match pause {
+                Some(true) => {
+                    self.state.paused = true;
+                    self.state.draining = false;
+                    coordinator.pause();
+                    format!("Schedule '{label}' paused the queue")
+                }
+                Some(false) => {
+                    self.state.paused = false;
+                    self.state.draining = false;
+                    coordinator.resume();
+                    self.flush_pending().await?;
+                    format!("Schedule '{label}' resumed the queue")
+                }
+                None => match self.apply_scheduled_strategy(&transition) {
+                    Some(id) => format!("Schedule '{label}' switched strategy to {id}"),
+                    None => continue,
+                },
+            };
+            info!(rule = %label, ends_window = transition.ends_window, "applied schedule rule");
+            coordinator.strategy_scheduled(
+                summary,
+                serde_json::json!({
+                    "rule": label,
+                    "action": rule.action,
+                    "ends_window": transition.ends_window,
+                    "at": transition.at.timestamp(),
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// Switch to a rule's strategy, or restore the previous one when its window
+    /// ends. Returns the id that became active.
+    fn apply_scheduled_strategy(&self, transition: &schedule::Transition) -> Option<String> {
+        let state = StrategyState::global();
+        let current = state.snapshot();
+        let rule_strategy = transition.rule.strategy.as_deref()?;
+        let next = if transition.ends_window {
+            let previous = schedule::take_window(transition.index)?;
+            // Leave manual changes made during the window alone.
+            if !current.id.eq_ignore_ascii_case(rule_strategy) {
+                return None;
+            }
+            previous
+        } else {
+            if transition.rule.until.is_some() {
+                schedule::remember_window(transition.index, current);
+            }
+            ActiveStrategy {
+                id: rule_strategy.to_string(),
+                focus: transition.rule.focus.clone(),
+            }
+        };
+
+        match state.update(next) {
+            Ok(strategy) => {
+                realtime::publish(RealtimeEvent::StrategyState {
+                    id: strategy.id.clone(),
+                    focus: strategy.focus.clone().unwrap_or_default(),
+                });
+                Some(strategy.id)
+            }
+            Err(err) => {
+                warn!(%err, rule = %transition.label(), "scheduled strategy change failed");
+                None
+            }
+        }
+    }
+
     async fn flush_system_events(&self) {
         let coordinator = QueueCoordinator::global();
         let events = coordinator.drain_events();
@@ -1435,9 +2366,38 @@ struct QueueRuntimeState {
     active_workers: HashSet<i64>,
     worker_runs: HashMap<i64, RunId>,
     orchestrator_run: Option<RunId>,
+    run_context: HashMap<RunId, RunContext>,
     worker_reasoning: HashMap<i64, Vec<String>>,
+    scheduler_handoff: Option<&'static str>,
+    scheduler_inputs: Option<(SchedulerInputs, Instant)>,
+    draining: bool,
+    paused_workers: HashSet<i64>,
+    post_turn_jobs: Arc<AtomicUsize>,
+}
+
+/// What a live run was dispatched for, attached to its feed entries.
+struct RunContext {
+    task_slug: Option<String>,
+    turn_index: i64,
 }
 
+/// Everything a scheduler pass depends on that is cheap to read; the pass is
+/// skipped while these stay equal, apart from a resync every
+/// [`SCHEDULER_RESYNC`] for state kept outside the database.
+#[derive(PartialEq)]
+struct SchedulerInputs {
+    task_revision: u64,
+    strategy: ActiveStrategy,
+    queued: Vec<i64>,
+    active_workers: BTreeSet<i64>,
+    paused_workers: BTreeSet<i64>,
+    idle_workers: Vec<i64>,
+}
+
+/// How long an unchanged scheduler pass may be skipped; picks up worker skill
+/// changes and worktrees added outside the API.
+const SCHEDULER_RESYNC: Duration = Duration::from_secs(30);
+
 impl Default for QueueRuntimeState {
     fn default() -> Self {
         Self {
@@ -1446,15 +2406,17 @@ impl Default for QueueRuntimeState {
             active_workers: HashSet::new(),
             worker_runs: HashMap::new(),
             orchestrator_run: None,
+            run_context: HashMap::new(),
             worker_reasoning: HashMap::new(),
+            scheduler_handoff: None,
+            scheduler_inputs: None,
+            draining: false,
+            paused_workers: HashSet::new(),
+            post_turn_jobs: Arc::new(AtomicUsize::new(0)),
         }
     }
 }
 
-fn should_seed_assignments(strategy: &ApiStrategy) -> bool {
-    !matches!(strategy, ApiStrategy::Planning | ApiStrategy::WindDown)
-}
-
 async fn run_queue_manager(mut runtime: QueueManagerRuntime) {
     info!("queue manager loop started");
     let mut tick = interval(Duration::from_millis(500));
@@ -1472,6 +2434,9 @@ async fn run_queue_manager(mut runtime: QueueManagerRuntime) {
                 }
             }
             _ = tick.tick() => {
+                if let Err(err) = runtime.apply_schedule().await {
+                    warn!(?err, "queue manager schedule tick failed");
+                }
                 if let Err(err) = runtime.drive_queue().await {
                     warn!(?err, "queue manager drive tick failed");
                 }
@@ -1493,6 +2458,8 @@ fn event_to_feed_entry(event: SystemEvent) -> NewFeedEntry {
         text: event.summary.clone(),
         raw: serde_json::to_string(&event.details).unwrap_or_else(|_| "{}".into()),
         category: event.category.as_str().to_string(),
+        raw_blob: None,
+        meta: FeedEntryMeta::default(),
     }
 }
 
@@ -1512,6 +2479,23 @@ struct ProcessRunResult {
     stderr: String,
 }
 
+/// Holds one slot of an in-flight counter and releases it on drop, so a job
+/// that panics still lets a drain finish.
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlightGuard {
+    fn enter(counter: Arc<AtomicUsize>) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 struct PostTurnJob {
     worker_id: i64,
     completion: WorkerCompletion,
@@ -1527,6 +2511,7 @@ impl PostTurnJob {
         middleware: MiddlewareHandle,
         db: DatabaseManagerHandle,
         drift_trace: Option<String>,
+        in_flight: Arc<AtomicUsize>,
     ) {
         let job = Self {
             worker_id,
@@ -1535,7 +2520,10 @@ impl PostTurnJob {
             db,
             drift_trace,
         };
+        // Counted so a queue drain waits for checks and merges to finish.
+        let guard = InFlightGuard::enter(in_flight);
         spawn(async move {
+            let _guard = guard;
             job.run().await;
         });
     }
@@ -1602,7 +2590,8 @@ impl PostTurnJob {
         if self.completion.task_slug.trim().is_empty() {
             return Ok(());
         }
-        db::task::mark_done_and_owner(&self.completion.task_slug, "Quality Assurance")
+        let origin = TaskChangeOrigin::system("System");
+        db::task::mark_done_and_owner(&self.completion.task_slug, "Quality Assurance", &origin)
             .await
             .map_err(|err| QueueManagerError::Assignment(err.to_string()))?;
         Ok(())
@@ -1665,6 +2654,7 @@ impl PostTurnJob {
             ],
             priority: RunPriority::High,
             issued_at: Utc::now(),
+            backend: None,
         };
 
         let intent = ProcessSpawnIntent {
@@ -1751,6 +2741,8 @@ impl PostTurnJob {
             text: message.to_string(),
             raw: message.to_string(),
             category: "validation".to_string(),
+            raw_blob: None,
+            meta: FeedEntryMeta::default(),
         };
         let feed_entry = self
             .db
@@ -1761,6 +2753,11 @@ impl PostTurnJob {
         Ok(())
     }
 
+    /// Report the completion to the orchestrator. Under a strategy the
+    /// deterministic scheduler owns, the orchestrator no longer picks follow-up
+    /// work, so the report is written to the feed instead of its queue; every
+    /// other strategy delivers it as a message (which lands in the feed when
+    /// delivered).
     async fn notify_orchestrator_completion(&self) -> Result<(), QueueManagerError> {
         let mut message = format!(
             "ws{} completed {}",
@@ -1784,6 +2781,11 @@ impl PostTurnJob {
             message.push_str(trace);
         }
 
+        if scheduler::is_active(&StrategyState::global().active()) {
+            // The scheduler picks the next task itself; keep the report in the feed only.
+            return self.record_completion_feed(&message).await;
+        }
+
         self.db
             .enqueue_message(
                 SystemActor::System.label(),
@@ -1795,6 +2797,26 @@ impl PostTurnJob {
         Ok(())
     }
 
+    async fn record_completion_feed(&self, message: &str) -> Result<(), QueueManagerError> {
+        let entry = NewFeedEntry {
+            source: format!("ws{}", self.worker_id),
+            target: "System".to_string(),
+            level: FeedLevel::Info,
+            text: message.to_string(),
+            raw: String::new(),
+            category: "routing".to_string(),
+            raw_blob: None,
+            meta: FeedEntryMeta::default(),
+        };
+        let feed_entry = self
+            .db
+            .insert_feed_entry(entry)
+            .await
+            .map_err(QueueManagerError::from)?;
+        realtime::publish(RealtimeEvent::FeedEntry(sanitize_feed_entry(&feed_entry)));
+        Ok(())
+    }
+
     async fn extra_tasks_hint(&self) -> Result<Option<String>, QueueManagerError> {
         let Some(task) = task_db::get_task_by_slug(&self.completion.task_slug)
             .await
@@ -1907,6 +2929,20 @@ fn ghost_commit_message(worker_id: i64, turn: &WorkerTurn) -> String {
     }
 }
 
+fn agent_label(actor: AgentRunActor) -> String {
+    match actor {
+        AgentRunActor::Worker(worker_id) => format!("ws{worker_id}"),
+        AgentRunActor::Orchestrator => "orchestrator".to_string(),
+    }
+}
+
+/// Wire name of a turn intent, e.g. `COMPLETE_TASK`.
+fn intent_label(intent: &impl serde::Serialize) -> Option<String> {
+    serde_json::to_value(intent)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+}
+
 fn compact_summary(text: &str) -> String {
     text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
@@ -2084,6 +3120,12 @@ pub enum QueueManagerError {
     OrchestratorNotRunning,
     #[error("assignment processing failed: {0}")]
     Assignment(String),
+    #[error("no recording for run {0}")]
+    RecordingNotFound(RunId),
+    #[error("failed to read run recording: {0}")]
+    RecordingUnreadable(String),
+    #[error("replay failed: {0}")]
+    Replay(String),
 }
 
 #[derive(Debug, Error)]
@@ -2177,3 +3219,68 @@ impl PostTurnError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(id: i64, from: &str, to: &str, body: &str) -> Message {
+        Message::new(id, from.to_string(), to.to_string(), body.to_string(), id)
+    }
+
+    fn ids(batches: &[Vec<Message>]) -> Vec<Vec<i64>> {
+        batches
+            .iter()
+            .map(|batch| batch.iter().map(|message| message.id).collect())
+            .collect()
+    }
+
+    #[test]
+    fn delivery_batches_group_by_recipient_in_queue_order() {
+        let queue = vec![
+            message(1, "Orchestrator", "ws1", "first"),
+            message(2, "Quality Assurance", "Orchestrator", "status?"),
+            message(3, "Orchestrator", "WS1", "second"),
+            message(4, "ws2", "Orchestrator", "done"),
+            message(5, "Orchestrator", "ws2", "next"),
+        ];
+
+        let batches = QueueManagerRuntime::delivery_batches(queue.clone(), true);
+        assert_eq!(ids(&batches), vec![vec![1, 3], vec![2, 4], vec![5]]);
+
+        // Without coalescing each recipient only gets its first message per pass.
+        let batches = QueueManagerRuntime::delivery_batches(queue, false);
+        assert_eq!(ids(&batches), vec![vec![1], vec![2], vec![5]]);
+    }
+
+    #[test]
+    fn coalesce_batch_lists_each_message_under_its_sender() {
+        let batch = vec![
+            message(4, "ws2", "Orchestrator", "  done with add-greeting \n"),
+            message(7, " Quality Assurance ", "Orchestrator", "status?"),
+            message(9, "ws2", "Orchestrator", "also fixed the typo"),
+        ];
+
+        let merged = QueueManagerRuntime::coalesce_batch(&batch);
+        assert_eq!(merged.id, 4);
+        assert_eq!(merged.to, "Orchestrator");
+        assert_eq!(merged.from, "ws2, Quality Assurance");
+        assert_eq!(
+            merged.message,
+            "3 queued messages delivered together; address each one.\n\n\
+             [#4 from ws2]\ndone with add-greeting\n\n\
+             [#7 from Quality Assurance]\nstatus?\n\n\
+             [#9 from ws2]\nalso fixed the typo"
+        );
+    }
+
+    #[test]
+    fn coalesce_batch_leaves_a_single_message_untouched() {
+        let single = message(3, "ws1", "Orchestrator", "  hello  ");
+
+        assert_eq!(
+            QueueManagerRuntime::coalesce_batch(std::slice::from_ref(&single)),
+            single
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+
+use openapi::models::{TaskCreateInput, TaskGroupCreateInput, TaskStatus};
+use server::ai::schemas::{
+    Assignment, OrchestratorIntent, OrchestratorTurn, WorkerCompletion, WorkerIntent, WorkerTurn,
+};
+use server::db::message_queue::MessageOptions;
+use server::db::task_history::TaskChangeOrigin;
+use server::system::events::SystemActor;
+use server::system::launcher;
+use server::system::mock_agent::{ScriptedLauncher, ScriptedTurn};
+use server::system::queue::QueueCoordinator;
+use server::{config_sync, db, globals, realtime, routes, system, threads};
+use tokio::time::{Instant, sleep};
+
+const TASK_SLUG: &str = "add-greeting";
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .expect("git should be installed");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+fn init_workspace(root: &Path) {
+    let staging = root.join("staging");
+    std::fs::create_dir_all(&staging).expect("create staging");
+    git(&staging, &["init", "-q", "-b", "staging"]);
+    git(&staging, &["config", "user.name", "Robot Farm Test"]);
+    git(&staging, &["config", "user.email", "test@robot-farm.local"]);
+    std::fs::write(staging.join("README.md"), "# fixture\n").expect("write readme");
+    git(&staging, &["add", "--all"]);
+    git(&staging, &["commit", "-q", "-m", "initial"]);
+}
+
+fn script(launcher: &ScriptedLauncher) {
+    launcher.push(
+        "orchestrator",
+        ScriptedTurn::orchestrator(&OrchestratorTurn {
+            target: Some("ws1".to_string()),
+            intent: OrchestratorIntent::AssignTask,
+            summary: "Assign add-greeting to ws1".to_string(),
+            details: None,
+            assignments: Some(Assignment {
+                task_slug: TASK_SLUG.to_string(),
+                task_title: "Add greeting".to_string(),
+                steps: vec!["Create greeting.txt".to_string()],
+                acceptance: Some("greeting.txt exists".to_string()),
+            }),
+            next_worker_assignment: None,
+        })
+        .reasoning("ws1 is idle and add-greeting is ready."),
+    );
+    launcher.push(
+        "ws1",
+        ScriptedTurn::worker(&WorkerTurn {
+            intent: WorkerIntent::CompleteTask,
+            summary: "Added greeting.txt".to_string(),
+            details: None,
+            completed: Some(WorkerCompletion {
+                task_slug: TASK_SLUG.to_string(),
+                notes: None,
+                commit_summary: "add greeting file".to_string(),
+            }),
+            blocked: None,
+        })
+        .reasoning("Writing the greeting file.")
+        .write_file("greeting.txt", "hello from ws1\n"),
+    );
+    launcher.push(
+        "orchestrator",
+        ScriptedTurn::orchestrator(&OrchestratorTurn {
+            target: Some("Quality Assurance".to_string()),
+            intent: OrchestratorIntent::StatusUpdate,
+            summary: "add-greeting merged".to_string(),
+            details: None,
+            assignments: None,
+            next_worker_assignment: None,
+        }),
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn scripted_agents_complete_a_task_end_to_end() {
+    let workspace = tempfile::tempdir().expect("temp workspace");
+    init_workspace(workspace.path());
+    assert!(
+        globals::set_project_dir(workspace.path()),
+        "PROJECT_DIR was read before the test configured it"
+    );
+
+    routes::config::ensure_config_exists().expect("write default config");
+    config_sync::reload_from_disk().expect("sync config");
+    db::ensure_db().await.expect("database");
+    system::init_system_state();
+    realtime::init();
+
+    let mock = Arc::new(ScriptedLauncher::new().expect("scripted launcher"));
+    script(&mock);
+    launcher::install(mock.clone());
+    threads::init_background_threads();
+
+    let worker = db::worker::create_worker().await.expect("create worker");
+    QueueCoordinator::global().register_worker(worker.id);
+
+    let group = db::task_group::create_task_group(TaskGroupCreateInput::new(
+        "greetings".to_string(),
+        "Greetings".to_string(),
+        "Fixture group".to_string(),
+    ))
+    .await
+    .expect("create group");
+    db::task::create_task(
+        TaskCreateInput {
+            group_id: group.id,
+            slug: TASK_SLUG.to_string(),
+            title: "Add greeting".to_string(),
+            commit_hash: None,
+            status: TaskStatus::Ready,
+            owner: "Orchestrator".to_string(),
+            description: "Create greeting.txt".to_string(),
+            model_override: None,
+            reasoning_override: None,
+            priority: None,
+            required_skills: None,
+        },
+        &TaskChangeOrigin::rest("fixture"),
+    )
+    .await
+    .expect("create task");
+
+    QueueCoordinator::global().resume();
+    threads::thread_handles()
+        .queue
+        .enqueue_manual_message(
+            SystemActor::QualityAssurance,
+            SystemActor::Orchestrator,
+            "Please get add-greeting done.".to_string(),
+            MessageOptions::default(),
+        )
+        .await
+        .expect("enqueue kickoff");
+
+    let merged = workspace.path().join("staging").join("greeting.txt");
+    let deadline = Instant::now() + Duration::from_secs(60);
+    loop {
+        let task = db::task::get_task_by_slug(TASK_SLUG)
+            .await
+            .expect("load task")
+            .expect("task exists");
+        if merged.exists() && task.status == TaskStatus::Done && mock.remaining("orchestrator") == 0
+        {
+            break;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "loop did not finish: merged={}, status={:?}, orchestrator turns left={}",
+            merged.exists(),
+            task.status,
+            mock.remaining("orchestrator")
+        );
+        sleep(Duration::from_millis(200)).await;
+    }
+
+    assert_eq!(
+        std::fs::read_to_string(&merged).expect("read merged file"),
+        "hello from ws1\n"
+    );
+    assert_eq!(mock.remaining("ws1"), 0);
+    let task = db::task::get_task_by_slug(TASK_SLUG)
+        .await
+        .expect("load task")
+        .expect("task exists");
+    let history = db::task_history::list_for_task(task.id)
+        .await
+        .expect("load history");
+    let created = history.first().expect("history has entries");
+    assert_eq!(created.field, "created");
+    assert_eq!(created.new_value.as_deref(), Some(TASK_SLUG));
+    assert_eq!(
+        (created.actor.as_str(), created.source.as_str()),
+        ("fixture", "rest")
+    );
+    let prompts = mock.prompts();
+    assert!(
+        prompts
+            .iter()
+            .any(|prompt| prompt.contains("Please get add-greeting done.")),
+        "orchestrator should receive the kickoff message"
+    );
+}
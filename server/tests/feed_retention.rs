@@ -0,0 +1,129 @@
+use chrono::Utc;
+use openapi::models::{Feed, FeedLevel};
+use server::db::{
+    self,
+    feed::{self, FeedEntryMeta, NewFeedEntry, RetentionCutoffs},
+};
+use server::globals;
+
+const DAY: i64 = 86_400;
+
+fn entry(category: &str) -> NewFeedEntry {
+    NewFeedEntry {
+        source: "ws1".to_string(),
+        target: "Orchestrator".to_string(),
+        level: FeedLevel::Info,
+        text: format!("{category} entry"),
+        raw: String::new(),
+        category: category.to_string(),
+        raw_blob: None,
+        meta: FeedEntryMeta::default(),
+    }
+}
+
+async fn age(id: i64, ts: i64) {
+    sqlx::query("UPDATE feed SET ts = ?1 WHERE id = ?2")
+        .bind(ts)
+        .bind(id)
+        .execute(db::pool())
+        .await
+        .expect("age entry");
+}
+
+async fn expired_ids(cutoffs: &RetentionCutoffs) -> Vec<i64> {
+    feed::list_expired(cutoffs, 100)
+        .await
+        .expect("list expired")
+        .into_iter()
+        .map(|(entry, _)| entry.id)
+        .collect()
+}
+
+// One test, because the pool is global and bound to the runtime that opened it.
+#[tokio::test]
+async fn restored_entries_expire_by_import_time_only() {
+    let workspace = tempfile::tempdir().expect("temp workspace");
+    assert!(
+        globals::set_project_dir(workspace.path()),
+        "PROJECT_DIR was read before the test configured it"
+    );
+    db::ensure_db().await.expect("database");
+    let now = Utc::now().timestamp();
+
+    let old = feed::insert_feed_entry(entry("agent"))
+        .await
+        .expect("insert");
+    age(old.id, now - 10 * DAY).await;
+    let old_status = feed::insert_feed_entry(entry("status"))
+        .await
+        .expect("insert");
+    age(old_status.id, now - 10 * DAY).await;
+    let fresh = feed::insert_feed_entry(entry("agent"))
+        .await
+        .expect("insert");
+
+    let restored_id = fresh.id + 1000;
+    let restored = Feed::new(
+        restored_id,
+        "ws2".to_string(),
+        "Orchestrator".to_string(),
+        now - 30 * DAY,
+        FeedLevel::Info,
+        "restored".to_string(),
+        String::new(),
+        "agent".to_string(),
+    );
+    let imported = feed::import_entries(vec![restored], "feed-1-1.jsonl.gz")
+        .await
+        .expect("import");
+    assert_eq!(imported, 1);
+
+    // The age cutoff catches old native rows but not a restored row that is older still.
+    let by_age = RetentionCutoffs {
+        before_ts: Some(now - 5 * DAY),
+        ..Default::default()
+    };
+    assert_eq!(expired_ids(&by_age).await, [old.id, old_status.id]);
+
+    // A category rule replaces the age cutoff for its category.
+    let with_rule = RetentionCutoffs {
+        before_ts: Some(now - 5 * DAY),
+        categories: vec![("status".to_string(), now - 20 * DAY)],
+        ..Default::default()
+    };
+    assert_eq!(expired_ids(&with_rule).await, [old.id]);
+
+    // Row caps count native rows only.
+    let by_rows = RetentionCutoffs {
+        keep_rows: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(expired_ids(&by_rows).await, [old.id, old_status.id]);
+
+    // Restored rows go once their import, not their original timestamp, is old enough.
+    let not_yet = RetentionCutoffs {
+        imported_before: Some(now - DAY),
+        ..Default::default()
+    };
+    assert_eq!(
+        feed::delete_expired_imports(&not_yet).await.expect("purge"),
+        0
+    );
+    let due = RetentionCutoffs {
+        imported_before: Some(now + 60),
+        ..Default::default()
+    };
+    assert_eq!(feed::delete_expired_imports(&due).await.expect("purge"), 1);
+    assert!(
+        feed::get_feed_entry(restored_id)
+            .await
+            .expect("load")
+            .is_none()
+    );
+    assert!(
+        feed::get_feed_entry(fresh.id)
+            .await
+            .expect("load")
+            .is_some()
+    );
+}
@@ -0,0 +1,159 @@
+use openapi::models::FeedLevel;
+use server::db::{
+    self,
+    feed::{self, FeedEntryMeta, FeedFilters, NewFeedEntry},
+};
+use server::globals;
+
+fn entry(text: &str) -> NewFeedEntry {
+    NewFeedEntry {
+        source: "ws1".to_string(),
+        target: "Orchestrator".to_string(),
+        level: FeedLevel::Info,
+        text: text.to_string(),
+        raw: String::new(),
+        category: "agent".to_string(),
+        raw_blob: None,
+        meta: FeedEntryMeta::default(),
+    }
+}
+
+async fn ids(filters: FeedFilters) -> Vec<i64> {
+    feed::list_feed(filters)
+        .await
+        .expect("list feed")
+        .into_iter()
+        .map(|entry| entry.id)
+        .collect()
+}
+
+async fn search(query: &str) -> Vec<i64> {
+    ids(FeedFilters {
+        query: Some(query.to_string()),
+        ..FeedFilters::default()
+    })
+    .await
+}
+
+// One test, because the pool is global and bound to the runtime that opened it.
+#[tokio::test]
+async fn cursors_limits_and_search_select_feed_pages() {
+    let workspace = tempfile::tempdir().expect("temp workspace");
+    assert!(
+        globals::set_project_dir(workspace.path()),
+        "PROJECT_DIR was read before the test configured it"
+    );
+    db::ensure_db().await.expect("database");
+
+    let mut inserted = Vec::new();
+    for text in [
+        "build started",
+        "disk full on runner",
+        "runner reports full disk",
+        "tests failed: OR NOT expected",
+        "deploy finished",
+        "disk usage fine",
+    ] {
+        let stored = feed::insert_feed_entry(entry(text))
+            .await
+            .expect("insert feed entry");
+        inserted.push(stored.id);
+    }
+    let [started, full, reports, failed, deployed, usage] = inserted[..] else {
+        panic!("six entries were inserted");
+    };
+
+    // Without a cursor pages are newest first; `limit` keeps the newest entries.
+    assert_eq!(
+        ids(FeedFilters::default()).await,
+        vec![usage, deployed, failed, reports, full, started]
+    );
+    assert_eq!(
+        ids(FeedFilters {
+            limit: Some(2),
+            ..FeedFilters::default()
+        })
+        .await,
+        vec![usage, deployed]
+    );
+    assert_eq!(
+        ids(FeedFilters {
+            limit: Some(100),
+            ..FeedFilters::default()
+        })
+        .await
+        .len(),
+        6
+    );
+    assert!(
+        ids(FeedFilters {
+            limit: Some(0),
+            ..FeedFilters::default()
+        })
+        .await
+        .is_empty()
+    );
+
+    // With only `after_id`, a limited page holds the entries right after the
+    // cursor, still newest first, so backfill does not skip any.
+    assert_eq!(
+        ids(FeedFilters {
+            after_id: Some(full),
+            limit: Some(2),
+            ..FeedFilters::default()
+        })
+        .await,
+        vec![failed, reports]
+    );
+    assert_eq!(
+        ids(FeedFilters {
+            after_id: Some(full),
+            ..FeedFilters::default()
+        })
+        .await,
+        vec![usage, deployed, failed, reports]
+    );
+    assert!(
+        ids(FeedFilters {
+            after_id: Some(usage),
+            limit: Some(2),
+            ..FeedFilters::default()
+        })
+        .await
+        .is_empty()
+    );
+    // Both cursors bound a window, which is paged from its newest end.
+    assert_eq!(
+        ids(FeedFilters {
+            after_id: Some(started),
+            before_id: Some(deployed),
+            limit: Some(2),
+            ..FeedFilters::default()
+        })
+        .await,
+        vec![failed, reports]
+    );
+
+    // Every search term must appear, in any order; quotes do not make a phrase.
+    assert_eq!(search("disk").await, vec![usage, reports, full]);
+    assert_eq!(search("\"disk full\"").await, vec![reports, full]);
+    assert_eq!(search("full disk runner").await, vec![reports, full]);
+    assert_eq!(search("\"disk").await, vec![usage, reports, full]);
+    // FTS5 operators and column syntax are matched as plain words.
+    assert_eq!(search("OR NOT").await, vec![failed]);
+    assert_eq!(search("failed:").await, vec![failed]);
+    assert!(search("disk NOT").await.is_empty());
+    // A blank query does not filter.
+    assert_eq!(search("   ").await.len(), 6);
+
+    assert_eq!(
+        ids(FeedFilters {
+            query: Some("disk".to_string()),
+            after_id: Some(full),
+            limit: Some(1),
+            ..FeedFilters::default()
+        })
+        .await,
+        vec![reports]
+    );
+}
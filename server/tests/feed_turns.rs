@@ -0,0 +1,30 @@
+use server::db::{self, feed};
+use server::globals;
+
+// One test, because the pool is global and bound to the runtime that opened it.
+#[tokio::test]
+async fn turn_indexes_count_dispatches_per_source_and_task() {
+    let workspace = tempfile::tempdir().expect("temp workspace");
+    assert!(
+        globals::set_project_dir(workspace.path()),
+        "PROJECT_DIR was read before the test configured it"
+    );
+    db::ensure_db().await.expect("database");
+
+    let claim = |source: &'static str, task: Option<&'static str>| async move {
+        feed::next_turn_index(source, task)
+            .await
+            .expect("claim turn")
+    };
+
+    assert_eq!(claim("ws1", Some("add-greeting")).await, 0);
+    assert_eq!(claim("ws1", Some("add-greeting")).await, 1);
+    assert_eq!(claim("ws1", Some("fix-bug")).await, 0);
+    assert_eq!(claim("ws2", Some("add-greeting")).await, 0);
+    assert_eq!(claim("ws1", None).await, 0);
+    assert_eq!(claim("ws1", None).await, 1);
+    assert_eq!(claim("ws1", Some("add-greeting")).await, 2);
+
+    feed::delete_feed().await.expect("clear feed");
+    assert_eq!(claim("ws1", Some("add-greeting")).await, 0);
+}
@@ -32,11 +32,13 @@ impl TestHarness {
             kill_buffer: 4,
         };
         let (lifecycle_tx, lifecycle_rx) = mpsc::channel(32);
+        let (notifications_tx, _notifications_rx) = mpsc::channel(32);
         let (middleware_handle, directives_rx) = spawn_middleware(middleware_config, lifecycle_rx);
         let runtime = ProcessManagerRuntime {
             directives_rx,
             config: manager_config,
             lifecycle_tx,
+            notifications_tx,
         };
         let manager_task = spawn_process_manager(runtime);
 
@@ -78,6 +80,7 @@ fn build_spawn_intent(command: &str) -> ProcessSpawnIntent {
         tags: vec![],
         priority: RunPriority::Normal,
         issued_at: Utc::now(),
+        backend: None,
     };
 
     ProcessSpawnIntent {
@@ -88,6 +91,7 @@ fn build_spawn_intent(command: &str) -> ProcessSpawnIntent {
         working_dir: std::env::current_dir().expect("current dir"),
         stream_stdout: true,
         stream_stderr: true,
+        stdin: None,
     }
 }
 
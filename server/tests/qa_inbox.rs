@@ -0,0 +1,130 @@
+use openapi::models::{TaskCreateInput, TaskStatus};
+use server::db::qa_inbox::{self, NewQaItem};
+use server::db::task_history::TaskChangeOrigin;
+use server::db::{self, task, task_group};
+use server::globals;
+
+fn item(agent: &str, task: Option<(i64, &str)>, message: &str, from_qa: bool) -> NewQaItem {
+    NewQaItem {
+        agent: agent.to_string(),
+        task_id: task.map(|(id, _)| id),
+        task_slug: task.map(|(_, slug)| slug.to_string()),
+        message: message.to_string(),
+        from_qa,
+        reply_to: None,
+    }
+}
+
+fn summary(threads: &[openapi::models::QaThread]) -> Vec<(String, Option<i64>, i64, Vec<i64>)> {
+    threads
+        .iter()
+        .map(|thread| {
+            (
+                thread.agent.clone(),
+                thread.task_id,
+                thread.unread,
+                thread.items.iter().map(|item| item.id).collect(),
+            )
+        })
+        .collect()
+}
+
+// One test, because the pool is global and bound to the runtime that opened it.
+#[tokio::test]
+async fn threads_group_by_agent_and_task() {
+    let workspace = tempfile::tempdir().expect("temp workspace");
+    assert!(
+        globals::set_project_dir(workspace.path()),
+        "PROJECT_DIR was read before the test configured it"
+    );
+    db::ensure_db().await.expect("database");
+
+    let chores = task_group::get_task_group_by_slug("chores")
+        .await
+        .expect("load group")
+        .expect("chores is built in");
+    let greeting = task::create_task(
+        TaskCreateInput::new(
+            chores.id,
+            "add-greeting".to_string(),
+            "Add greeting".to_string(),
+            TaskStatus::Ready,
+            "ws1".to_string(),
+            String::new(),
+        ),
+        &TaskChangeOrigin::rest("admin"),
+    )
+    .await
+    .expect("create task");
+    let task = Some((greeting.id, "add-greeting"));
+
+    // Items are back-dated so thread activity does not depend on the clock.
+    let insert = |new: NewQaItem, created_at: i64| async move {
+        let id = qa_inbox::insert_item(new).await.expect("insert qa item").id;
+        sqlx::query("UPDATE qa_inbox SET created_at = ?1 WHERE id = ?2")
+            .bind(created_at)
+            .bind(id)
+            .execute(db::pool())
+            .await
+            .expect("back-date qa item");
+        id
+    };
+    let first = insert(item("ws1", task, "Which greeting?", false), 100).await;
+    let loose = insert(item("ws1", None, "Staging is red", false), 110).await;
+    let reply = insert(item("ws1", task, "Use hello", true), 120).await;
+    let other = insert(item("ws2", None, "Need a database", false), 130).await;
+    let follow_up = insert(item("ws1", task, "Capitalised?", false), 140).await;
+
+    let threads = qa_inbox::list_threads(false).await.expect("list threads");
+    assert_eq!(
+        summary(&threads),
+        vec![
+            (
+                "ws1".to_string(),
+                Some(greeting.id),
+                2,
+                vec![first, reply, follow_up]
+            ),
+            ("ws2".to_string(), None, 1, vec![other]),
+            ("ws1".to_string(), None, 1, vec![loose]),
+        ]
+    );
+    assert_eq!(threads[0].task_slug.as_deref(), Some("add-greeting"));
+    assert_eq!(
+        threads
+            .iter()
+            .map(|thread| thread.last_activity)
+            .collect::<Vec<_>>(),
+        vec![140, 130, 110]
+    );
+
+    let loose_item = qa_inbox::get_item(loose)
+        .await
+        .expect("load item")
+        .expect("item exists");
+    assert_eq!(
+        qa_inbox::mark_thread_read(&loose_item)
+            .await
+            .expect("mark read"),
+        1
+    );
+
+    let unread = qa_inbox::list_threads(true)
+        .await
+        .expect("list unread threads");
+    assert_eq!(
+        summary(&unread),
+        vec![
+            (
+                "ws1".to_string(),
+                Some(greeting.id),
+                2,
+                vec![first, reply, follow_up]
+            ),
+            ("ws2".to_string(), None, 1, vec![other]),
+        ]
+    );
+    let all = qa_inbox::list_threads(false).await.expect("list threads");
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[2].unread, 0);
+}
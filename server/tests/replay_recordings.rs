@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use openapi::models::FeedItemKind;
+use server::ai::schemas::WorkerIntent;
+use server::models::process::{RunMetadata, RunPriority};
+use server::threads::process_manager::{AgentFeedItem, ProcessNotification, replay_recording};
+use uuid::Uuid;
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/recordings")
+        .join(name);
+    std::fs::read(&path).unwrap_or_else(|err| panic!("read {}: {err}", path.display()))
+}
+
+fn worker_metadata(worker_id: i64) -> RunMetadata {
+    RunMetadata {
+        run_id: Uuid::new_v4(),
+        persona: format!("worker:ws{worker_id}"),
+        workspace_root: PathBuf::from("."),
+        tags: vec![format!("worker:{worker_id}")],
+        priority: RunPriority::Normal,
+        issued_at: Utc::now(),
+        backend: Some("codex".to_string()),
+    }
+}
+
+#[test]
+fn worker_recording_replays_feed_and_completion() {
+    let notifications =
+        replay_recording(&worker_metadata(3), &fixture("worker_complete_task.jsonl"))
+            .expect("worker runs are replayable");
+
+    let feed: Vec<&str> = notifications
+        .iter()
+        .filter_map(|notification| match notification {
+            ProcessNotification::AgentFeed {
+                message, thread_id, ..
+            } => {
+                assert!(thread_id.is_none(), "replays must not carry thread ids");
+                Some(message.as_str())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        feed.len(),
+        5,
+        "thread, reasoning, command, file change, turn"
+    );
+    assert!(feed.iter().any(|text| text.starts_with("Reasoning:")));
+    assert!(feed.iter().any(|text| text.contains("src/health.rs")));
+
+    let turn = notifications
+        .iter()
+        .find_map(|notification| match notification {
+            ProcessNotification::WorkerTurn {
+                worker_id, turn, ..
+            } => Some((*worker_id, turn.clone())),
+            _ => None,
+        })
+        .expect("recording contains a worker turn");
+    assert_eq!(turn.0, 3);
+    assert!(matches!(turn.1.intent, WorkerIntent::CompleteTask));
+    assert_eq!(
+        turn.1.completed.expect("completion payload").task_slug,
+        "healthz-endpoint"
+    );
+    assert!(
+        !notifications
+            .iter()
+            .any(|notification| matches!(notification, ProcessNotification::AgentCompleted { .. })),
+        "replays never report a finished process"
+    );
+}
+
+#[test]
+fn replayed_feed_carries_run_id_and_item_metadata() {
+    let metadata = worker_metadata(3);
+    let notifications = replay_recording(&metadata, &fixture("worker_complete_task.jsonl"))
+        .expect("worker runs are replayable");
+
+    let items: Vec<AgentFeedItem> = notifications
+        .into_iter()
+        .filter_map(|notification| match notification {
+            ProcessNotification::AgentFeed { run_id, item, .. } => {
+                assert_eq!(run_id, metadata.run_id);
+                Some(item)
+            }
+            _ => None,
+        })
+        .collect();
+    let kinds: Vec<_> = items.iter().map(|item| item.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            Some(FeedItemKind::Session),
+            Some(FeedItemKind::Reasoning),
+            Some(FeedItemKind::Command),
+            Some(FeedItemKind::FileChange),
+            Some(FeedItemKind::Turn),
+        ]
+    );
+    assert_eq!(items[2].exit_code, Some(0));
+    assert_eq!(items[3].changed_files, ["src/routes.rs", "src/health.rs"]);
+}
+
+#[test]
+fn non_agent_runs_are_not_replayable() {
+    let mut metadata = worker_metadata(1);
+    metadata.persona = "post_turn_check:lint".to_string();
+    metadata.tags = vec!["post_turn_check".to_string()];
+    assert!(replay_recording(&metadata, b"{}\n").is_none());
+}
@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use server::system::run_recordings::{RecordingExit, RecordingMeta};
+use server::system::run_transcript::{TranscriptFormat, render};
+use uuid::Uuid;
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/recordings")
+        .join(name);
+    std::fs::read(&path).unwrap_or_else(|err| panic!("read {}: {err}", path.display()))
+}
+
+fn meta() -> RecordingMeta {
+    RecordingMeta {
+        run_id: Uuid::new_v4(),
+        persona: "worker:ws3".to_string(),
+        tags: vec!["worker:3".to_string()],
+        started_at: Utc::now(),
+        backend: Some("codex".to_string()),
+        prompt: Some("Implement <healthz> & report back".to_string()),
+        exit: Some(RecordingExit {
+            reason: "exited".to_string(),
+            code: Some(0),
+            detail: None,
+            finished_at: Utc::now(),
+        }),
+    }
+}
+
+#[test]
+fn markdown_transcript_covers_the_whole_run() {
+    let transcript = render(
+        &meta(),
+        &fixture("worker_complete_task.jsonl"),
+        TranscriptFormat::Markdown,
+    );
+
+    for expected in [
+        "## Prompt",
+        "Implement <healthz> & report back",
+        "### Reasoning",
+        "rg -n \"Router::new\" src",
+        "- **Exit code:** 0",
+        "- add src/health.rs",
+        "## Final turn",
+        "\"task_slug\": \"healthz-endpoint\"",
+        "- **Input tokens:** 18234",
+        "- **Reason:** exited",
+    ] {
+        assert!(
+            transcript.contains(expected),
+            "missing {expected:?} in:\n{transcript}"
+        );
+    }
+}
+
+#[test]
+fn html_transcript_escapes_agent_text() {
+    let transcript = render(
+        &meta(),
+        &fixture("worker_complete_task.jsonl"),
+        TranscriptFormat::Html,
+    );
+
+    assert!(transcript.starts_with("<!DOCTYPE html>"));
+    assert!(transcript.contains("Implement &lt;healthz&gt; &amp; report back"));
+    assert!(!transcript.contains("<healthz>"));
+}
+
+#[test]
+fn recordings_without_a_backend_are_read_as_codex() {
+    let legacy = RecordingMeta {
+        backend: None,
+        ..meta()
+    };
+    let transcript = render(
+        &legacy,
+        &fixture("worker_complete_task.jsonl"),
+        TranscriptFormat::Markdown,
+    );
+
+    assert!(transcript.contains("- **Backend:** codex"));
+    assert!(transcript.contains("## Final turn"));
+    assert!(!transcript.contains("were not agent events"));
+}
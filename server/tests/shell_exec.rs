@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use server::shared::shell::{self, ExecLimits, ExecOutput};
+use tokio::sync::mpsc;
+
+fn limits(timeout: Duration, max_output_bytes: usize) -> ExecLimits {
+    ExecLimits {
+        timeout,
+        max_output_bytes,
+    }
+}
+
+#[tokio::test]
+async fn kills_commands_that_outlive_their_timeout() {
+    let command = "echo started; sleep 30";
+    let result = shell::run_command(
+        &shell::shell_argv(command),
+        None,
+        command,
+        limits(Duration::from_millis(300), 1024),
+        None,
+    )
+    .await
+    .expect("command runs");
+
+    assert_eq!(result.timed_out, Some(true));
+    assert_eq!(result.stdout, "started\n");
+}
+
+#[tokio::test]
+async fn caps_and_streams_output() {
+    let command = "printf 'abcdefghij'; printf 'oops' >&2";
+    let (tx, mut rx) = mpsc::channel(16);
+    let result = shell::run_command(
+        &shell::shell_argv(command),
+        None,
+        command,
+        limits(Duration::from_secs(10), 4),
+        Some(tx),
+    )
+    .await
+    .expect("command runs");
+
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.timed_out, Some(false));
+    assert_eq!(result.truncated, Some(true));
+    assert_eq!(result.stdout, "abcd");
+    assert_eq!(result.stderr, "oops");
+
+    let mut streamed = String::new();
+    while let Ok(chunk) = rx.try_recv() {
+        if let ExecOutput::Stdout(text) = chunk {
+            streamed.push_str(&text);
+        }
+    }
+    assert_eq!(streamed, "abcd");
+}
+
+#[tokio::test]
+async fn timeout_kills_background_processes() {
+    let command = "sleep 30 & echo $!; wait";
+    let result = shell::run_command(
+        &shell::shell_argv(command),
+        None,
+        command,
+        limits(Duration::from_millis(500), 1024),
+        None,
+    )
+    .await
+    .expect("command runs");
+
+    assert_eq!(result.timed_out, Some(true));
+    let pid = result.stdout.trim();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let alive = std::process::Command::new("kill")
+        .args(["-0", pid])
+        .status()
+        .expect("kill runs")
+        .success();
+    assert!(!alive, "background sleep {pid} survived the timeout");
+}
+
+#[tokio::test]
+async fn dropping_the_receiver_cancels_the_command() {
+    let command = "sleep 30";
+    let (tx, rx) = mpsc::channel(16);
+    drop(rx);
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        shell::run_command(
+            &shell::shell_argv(command),
+            None,
+            command,
+            limits(Duration::from_secs(30), 1024),
+            Some(tx),
+        ),
+    )
+    .await
+    .expect("command is cancelled promptly")
+    .expect("command runs");
+
+    assert_eq!(result.timed_out, Some(false));
+    assert_eq!(result.exit_code, -1);
+}
+
+#[tokio::test]
+async fn streams_whole_characters_across_reads() {
+    let command = "head -c 8191 /dev/zero | tr '\\0' a; for i in $(seq 200); do printf 'é'; done";
+    let (tx, mut rx) = mpsc::channel(1024);
+    let result = shell::run_command(
+        &shell::shell_argv(command),
+        None,
+        command,
+        limits(Duration::from_secs(10), 1024 * 1024),
+        Some(tx),
+    )
+    .await
+    .expect("command runs");
+
+    let mut streamed = String::new();
+    while let Ok(chunk) = rx.try_recv() {
+        if let ExecOutput::Stdout(text) = chunk {
+            streamed.push_str(&text);
+        }
+    }
+    assert!(!streamed.contains('\u{FFFD}'));
+    assert_eq!(streamed, result.stdout);
+}
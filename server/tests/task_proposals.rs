@@ -0,0 +1,127 @@
+use openapi::models::{TaskCreateInput, TaskProposalAcceptInput, TaskProposalStatus, TaskStatus};
+use server::db::task_history::TaskChangeOrigin;
+use server::db::task_proposal::{self as proposal_db, NewTaskProposal, TaskProposalError};
+use server::db::{self, task, task_dependency, task_group};
+use server::globals;
+
+fn proposal(title: &str, group: &str, dependencies: &[&str]) -> NewTaskProposal {
+    NewTaskProposal {
+        proposer: "ws1".to_string(),
+        source_task_slug: None,
+        title: title.to_string(),
+        description: format!("{title} details"),
+        suggested_group: group.to_string(),
+        dependencies: dependencies.iter().map(|dep| dep.to_string()).collect(),
+    }
+}
+
+// One test, because the pool is global and bound to the runtime that opened it.
+#[tokio::test]
+async fn proposals_accept_into_proposal_groups_once() {
+    let workspace = tempfile::tempdir().expect("temp workspace");
+    assert!(
+        globals::set_project_dir(workspace.path()),
+        "PROJECT_DIR was read before the test configured it"
+    );
+    db::ensure_db().await.expect("database");
+    let origin = TaskChangeOrigin::rest("admin");
+
+    let chores = task_group::get_task_group_by_slug("chores")
+        .await
+        .expect("load group")
+        .expect("chores is built in");
+    let existing = task::create_task(
+        TaskCreateInput::new(
+            chores.id,
+            "chores-tidy-logging".to_string(),
+            "Tidy logging".to_string(),
+            TaskStatus::Ready,
+            "orchestrator".to_string(),
+            "Already tracked".to_string(),
+        ),
+        &origin,
+    )
+    .await
+    .expect("create existing task");
+
+    // Only chores and bugs take proposals; the proposal stays pending.
+    let hotfix = proposal_db::create_proposal(proposal("Patch outage", "hotfix", &[]))
+        .await
+        .expect("create hotfix proposal");
+    let err =
+        proposal_db::accept_proposal(hotfix.id, "admin", TaskProposalAcceptInput::new(), &origin)
+            .await
+            .expect_err("hotfix is not a proposal group");
+    assert!(matches!(err, TaskProposalError::IneligibleGroup(group) if group == "hotfix"));
+    let hotfix = proposal_db::get_proposal(hotfix.id)
+        .await
+        .expect("reload proposal")
+        .expect("proposal exists");
+    assert_eq!(hotfix.status, TaskProposalStatus::Pending);
+
+    // The derived slug collides with the existing task and gets a suffix; the
+    // unknown dependency is skipped.
+    let tidy = proposal_db::create_proposal(proposal(
+        "Tidy logging!",
+        "chores",
+        &["chores-tidy-logging", "no-such-task"],
+    ))
+    .await
+    .expect("create proposal");
+    let accepted =
+        proposal_db::accept_proposal(tidy.id, "admin", TaskProposalAcceptInput::new(), &origin)
+            .await
+            .expect("accept proposal");
+    assert_eq!(accepted.status, TaskProposalStatus::Accepted);
+    assert_eq!(accepted.reviewer.as_deref(), Some("admin"));
+    let task_id = accepted.task_id.expect("accepted proposal links its task");
+    let created = task::get_task(task_id)
+        .await
+        .expect("load task")
+        .expect("task exists");
+    assert_eq!(created.slug, "chores-tidy-logging-2");
+    assert_eq!(created.group_id, chores.id);
+    assert_eq!(
+        task_dependency::list_task_dependencies(task_id)
+            .await
+            .expect("load dependencies"),
+        vec![existing.id]
+    );
+
+    // A reviewed proposal cannot be reviewed again, and reports its real status.
+    let err = proposal_db::accept_proposal(tidy.id, "qa", TaskProposalAcceptInput::new(), &origin)
+        .await
+        .expect_err("second accept");
+    assert!(matches!(
+        err,
+        TaskProposalError::AlreadyReviewed(TaskProposalStatus::Accepted)
+    ));
+    let err = proposal_db::reject_proposal(tidy.id, "qa", None)
+        .await
+        .expect_err("reject after accept");
+    assert!(matches!(
+        err,
+        TaskProposalError::AlreadyReviewed(TaskProposalStatus::Accepted)
+    ));
+
+    let rejected = proposal_db::reject_proposal(hotfix.id, "admin", Some("not ours".to_string()))
+        .await
+        .expect("reject proposal");
+    assert_eq!(rejected.status, TaskProposalStatus::Rejected);
+    assert_eq!(rejected.review_note.as_deref(), Some("not ours"));
+    let err = proposal_db::accept_proposal(
+        hotfix.id,
+        "qa",
+        TaskProposalAcceptInput {
+            group_slug: Some("bugs".to_string()),
+            ..TaskProposalAcceptInput::new()
+        },
+        &origin,
+    )
+    .await
+    .expect_err("accept after reject");
+    assert!(matches!(
+        err,
+        TaskProposalError::AlreadyReviewed(TaskProposalStatus::Rejected)
+    ));
+}